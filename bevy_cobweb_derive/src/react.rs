@@ -9,9 +9,87 @@ use syn::{parse_macro_input, parse_quote, DeriveInput};
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Options set with `#[react(..)]`.
+#[derive(Default)]
+struct ReactAttributes
+{
+    track_previous: bool,
+    track_removed: bool,
+}
+
+/// Parses `#[react(track_previous, track_removed)]`.
+fn parse_react_attributes(ast: &DeriveInput) -> syn::Result<ReactAttributes>
+{
+    let mut attributes = ReactAttributes::default();
+
+    for attr in ast.attrs.iter().filter(|a| a.path().is_ident("react"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("track_previous")
+            {
+                attributes.track_previous = true;
+                return Ok(());
+            }
+            if meta.path.is_ident("track_removed")
+            {
+                attributes.track_removed = true;
+                return Ok(());
+            }
+
+            Err(meta.error("unsupported react attribute, expected `track_previous` or `track_removed`"))
+        })?;
+    }
+
+    Ok(attributes)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Generates the body of a react trait impl.
+///
+/// `track_removed` is only allowed for components.
+fn react_trait_body(ast: &DeriveInput, is_component: bool) -> syn::Result<proc_macro2::TokenStream>
+{
+    let attributes = parse_react_attributes(ast)?;
+    let mut body = quote! {};
+
+    if attributes.track_previous
+    {
+        body.extend(quote! {
+            fn snapshot_previous(&self) -> Option<Self>
+            {
+                Some(::core::clone::Clone::clone(self))
+            }
+        });
+    }
+
+    if attributes.track_removed
+    {
+        if !is_component
+        {
+            return Err(syn::Error::new_spanned(&ast.ident, "`track_removed` is only supported for ReactComponent"));
+        }
+        body.extend(quote! {
+            fn snapshot_removed(&self) -> Option<Self>
+            {
+                Some(::core::clone::Clone::clone(self))
+            }
+        });
+    }
+
+    Ok(body)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 pub(crate) fn derive_react_component_impl(input: TokenStream) -> TokenStream
 {
     let mut ast = parse_macro_input!(input as DeriveInput);
+    let body = match react_trait_body(&ast, true)
+    {
+        Ok(body) => body,
+        Err(err) => return err.to_compile_error().into(),
+    };
     ast.generics
         .make_where_clause()
         .predicates
@@ -20,7 +98,7 @@ pub(crate) fn derive_react_component_impl(input: TokenStream) -> TokenStream
     let struct_name = &ast.ident;
 
     TokenStream::from(quote! {
-        impl #impl_generics ReactComponent for #struct_name #ty_generics #where_clause {}
+        impl #impl_generics ReactComponent for #struct_name #ty_generics #where_clause { #body }
     })
 }
 
@@ -29,6 +107,11 @@ pub(crate) fn derive_react_component_impl(input: TokenStream) -> TokenStream
 pub(crate) fn derive_react_resource_impl(input: TokenStream) -> TokenStream
 {
     let mut ast = parse_macro_input!(input as DeriveInput);
+    let body = match react_trait_body(&ast, false)
+    {
+        Ok(body) => body,
+        Err(err) => return err.to_compile_error().into(),
+    };
     ast.generics
         .make_where_clause()
         .predicates
@@ -37,7 +120,7 @@ pub(crate) fn derive_react_resource_impl(input: TokenStream) -> TokenStream
     let struct_name = &ast.ident;
 
     TokenStream::from(quote! {
-        impl #impl_generics ReactResource for #struct_name #ty_generics #where_clause {}
+        impl #impl_generics ReactResource for #struct_name #ty_generics #where_clause { #body }
     })
 }
 
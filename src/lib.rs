@@ -6,6 +6,8 @@ use crate as bevy_cobweb;
 //module tree
 pub mod ecs;
 pub mod react;
+#[cfg(feature = "testing")]
+pub mod testing;
 
 //API exports
 pub use bevy_cobweb_derive::*;
@@ -0,0 +1,159 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+use core::any::TypeId;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records the reactive state read by the auto-tracked reactor that is currently running.
+///
+/// Reads are recorded by [`Reactive`] and [`ReactRes`].
+#[derive(Resource, Default)]
+pub(crate) struct ReactReadTracker
+{
+    /// True while an auto-tracked reactor is running.
+    active: AtomicBool,
+    /// Mutation triggers for the state read by the running reactor, with the names of the state types.
+    reads: Mutex<Vec<(ReactorType, &'static str)>>,
+}
+
+impl ReactReadTracker
+{
+    /// Starts recording reads.
+    fn start(&mut self)
+    {
+        debug_assert!(!*self.active.get_mut());
+        *self.active.get_mut() = true;
+    }
+
+    /// Stops recording reads and returns the recorded triggers.
+    fn end(&mut self) -> Vec<(ReactorType, &'static str)>
+    {
+        *self.active.get_mut() = false;
+        std::mem::take(self.reads.get_mut().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    fn record(&self, read: ReactorType, type_name: &'static str)
+    {
+        if !self.active.load(Ordering::Relaxed) { return; }
+        let mut reads = self.reads.lock().unwrap_or_else(|e| e.into_inner());
+        if reads.iter().any(|(r, _)| *r == read) { return; }
+        reads.push((read, type_name));
+    }
+
+    /// Records a read of `React<C>` on `entity`.
+    pub(crate) fn record_component<C: ReactComponent>(&self, entity: Entity)
+    {
+        self.record(ReactorType::EntityMutation(entity, TypeId::of::<C>()), std::any::type_name::<C>());
+    }
+
+    /// Records a read of the reactive resource `R`.
+    pub(crate) fn record_resource<R: ReactResource>(&self)
+    {
+        self.record(ReactorType::ResourceMutation(TypeId::of::<R>()), std::any::type_name::<R>());
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The current triggers of an auto-tracked reactor, stored on the reactor's system command entity.
+#[derive(Component, Default)]
+struct AutoTrackedTriggers(Vec<ReactorType>);
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Replaces the triggers of an auto-tracked reactor with triggers for the state it just read.
+fn resubscribe_auto_tracked(world: &mut World, sys_command: SystemCommand, reads: Vec<(ReactorType, &'static str)>)
+{
+    let (reads, type_names): (Vec<ReactorType>, Vec<&'static str>) = reads.into_iter().unzip();
+
+    let Ok(mut entity_mut) = world.get_entity_mut(*sys_command) else { return; };
+    let Some(mut triggers) = entity_mut.get_mut::<AutoTrackedTriggers>() else { return; };
+
+    // Diff the triggers.
+    let removed: Vec<ReactorType> = triggers.0.iter().filter(|t| !reads.contains(t)).cloned().collect();
+    let added: Vec<(ReactorType, &'static str)> = reads
+        .iter()
+        .zip(type_names)
+        .filter(|(t, _)| !triggers.0.contains(t))
+        .map(|(t, name)| (t.clone(), name))
+        .collect();
+    if removed.is_empty() && added.is_empty() { return; }
+    triggers.0 = reads;
+
+    // Revoke stale triggers.
+    if !removed.is_empty()
+    {
+        world.syscall(RevokeToken{ reactors: Arc::from(removed), id: sys_command }, revoke_reactor);
+    }
+
+    // Register new triggers.
+    let handle = ReactorHandle::Persistent(sys_command);
+    for (trigger, type_name) in added
+    {
+        let entry = ReactorEntry::new(handle.clone());
+        match trigger
+        {
+            ReactorType::EntityMutation(entity, component_id) =>
+            {
+                world.resource_mut::<ReactCache>().note_type_name(component_id, type_name);
+                world.syscall((EntityReactionType::Mutation(component_id), entity, entry), register_entity_reactor_by_id);
+            }
+            ReactorType::ResourceMutation(resource_id) =>
+            {
+                world.resource_mut::<ReactCache>().note_type_name(resource_id, type_name);
+                world.syscall((resource_id, entry), register_resource_mutation_reactor_by_id);
+            }
+            _ => unreachable!("auto-tracked reactors only track mutations"),
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawns an auto-tracked reactor. The reactor has no triggers until it runs for the first time.
+pub(crate) fn spawn_auto_tracked_reactor<S, M>(commands: &mut Commands, reactor: S) -> SystemCommand
+where
+    S: IntoSystem<(), (), M> + Send + Sync + 'static,
+{
+    let sys_command = SystemCommand(commands.spawn(AutoTrackedTriggers::default()).id());
+    let mut system = RawCallbackSystem::new(reactor);
+    let callback = SystemCommandCallback::with(
+        move |world: &mut World, cleanup: SystemCommandCleanup|
+        {
+            world.resource_mut::<ReactReadTracker>().start();
+            system.run_with_cleanup(world, (),
+                move |world: &mut World|
+                {
+                    let reads = world.resource_mut::<ReactReadTracker>().end();
+                    cleanup.run(world);
+                    resubscribe_auto_tracked(world, sys_command, reads);
+                }
+            );
+        }
+    ).named(std::any::type_name::<S>());
+    commands.entity(*sys_command).insert(SystemCommandStorage::new(callback));
+
+    sys_command
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Revokes all triggers of an auto-tracked reactor and despawns it.
+pub(crate) fn revoke_auto_tracked_reactor(In(sys_command): In<SystemCommand>, world: &mut World)
+{
+    let Ok(mut entity_mut) = world.get_entity_mut(*sys_command) else { return; };
+    let Some(triggers) = entity_mut.take::<AutoTrackedTriggers>() else { return; };
+    world.syscall(RevokeToken{ reactors: Arc::from(triggers.0), id: sys_command }, revoke_reactor);
+    world.despawn(*sys_command);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
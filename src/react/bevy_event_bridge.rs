@@ -0,0 +1,87 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+
+//standard shortcuts
+use core::any::Any;
+use std::marker::PhantomData;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks Bevy events of type `E` that are forwarded to broadcast reactors.
+#[derive(Resource)]
+pub(crate) struct BevyEventBridge<E: Event>
+{
+    /// Ids of events that were mirrored from broadcasts.
+    ///
+    /// These should not be forwarded back to broadcast reactors. Ids of events dropped from `Events<E>` before they
+    /// were read are pruned when new events are mirrored.
+    mirrored: HashSet<usize>,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Event> Default for BevyEventBridge<E>
+{
+    fn default() -> Self
+    {
+        Self{ mirrored: HashSet::default(), _phantom: PhantomData }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Forwards `Events<E>` to broadcast reactors.
+fn forward_bevy_events<E: Event + Clone>(
+    mut c      : Commands,
+    mut bridge : ResMut<BevyEventBridge<E>>,
+    mut events : EventReader<E>,
+){
+    for (event, id) in events.read_with_id()
+    {
+        if bridge.mirrored.remove(&id.id) { continue; }
+        c.syscall(event.clone(), ReactCache::schedule_bridged_broadcast_reaction::<E>);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Writes a broadcasted event to `Events<E>`.
+pub(crate) fn mirror_broadcast<E: Event + Clone>(event: &dyn Any, commands: &mut Commands)
+{
+    let Some(event) = event.downcast_ref::<E>() else { return; };
+    let event = event.clone();
+
+    commands.queue(
+        move |world: &mut World|
+        {
+            let Some(id) = world.send_event(event) else { return; };
+            let oldest = world.resource::<Events<E>>().oldest_event_count();
+
+            // Don't forward the event back to broadcast reactors.
+            let Some(mut bridge) = world.get_resource_mut::<BevyEventBridge<E>>() else { return; };
+            bridge.mirrored.retain(|id| *id >= oldest);
+            bridge.mirrored.insert(id.id);
+        }
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets up forwarding from `Events<E>` to broadcast reactors in the given schedule.
+pub(crate) fn setup_bevy_event_bridge<E: Event + Clone>(app: &mut App, schedule: impl ScheduleLabel)
+{
+    if app.world().contains_resource::<BevyEventBridge<E>>()
+    {
+        panic!("duplicate bevy event bridges for {:?} are not allowed", std::any::type_name::<E>());
+    }
+
+    app.add_event::<E>()
+        .init_resource::<BevyEventBridge<E>>()
+        .add_systems(schedule, forward_bevy_events::<E>);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
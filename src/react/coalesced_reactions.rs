@@ -0,0 +1,231 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+
+//standard shortcuts
+use core::any::TypeId;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A reaction that was coalesced for a reactor.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub(crate) enum CoalescedReaction
+{
+    /// A reaction to a component insertion or mutation on an entity.
+    Entity
+    {
+        entity: Entity,
+        rtype: EntityReactionType,
+    },
+    /// A reaction to a resource mutation.
+    Resource(TypeId),
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reactions waiting for a reactor to run.
+struct CoalescedBatch
+{
+    reactor: SystemCommand,
+    priority: i32,
+    reactions: Vec<CoalescedReaction>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Coalesced reactions waiting for [`flush_coalesced_reactions`].
+#[derive(Resource, Default)]
+pub(crate) struct CoalescedReactions
+{
+    /// Pending batches in the order their reactors were first triggered.
+    batches: Vec<CoalescedBatch>,
+    /// Pending `(reactor, reaction)` pairs, used to discard duplicates.
+    seen: HashSet<(Entity, CoalescedReaction)>,
+}
+
+impl CoalescedReactions
+{
+    /// Adds a reaction to a reactor's batch.
+    ///
+    /// Does nothing if the reactor already has a pending reaction of the same type for the same source.
+    pub(crate) fn add(&mut self, reactor: SystemCommand, priority: i32, reaction: CoalescedReaction)
+    {
+        if !self.seen.insert((*reactor, reaction)) { return; }
+
+        match self.batches.iter_mut().find(|batch| batch.reactor == reactor)
+        {
+            Some(batch) => batch.reactions.push(reaction),
+            None => self.batches.push(CoalescedBatch{ reactor, priority, reactions: vec![reaction] }),
+        }
+    }
+
+    /// Takes all pending batches, sorted by reactor priority.
+    fn take_batches(&mut self) -> Vec<CoalescedBatch>
+    {
+        self.seen.clear();
+        let mut batches = std::mem::take(&mut self.batches);
+        batches.sort_by_key(|batch| std::cmp::Reverse(batch.priority));
+        batches
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Stores the reactions delivered to a coalesced reactor.
+#[derive(Component)]
+pub(crate) struct CoalescedReactionData
+{
+    reactions: Vec<CoalescedReaction>,
+    /// Unique entities in `reactions`, in the order they were first triggered.
+    entities: Vec<Entity>,
+}
+
+impl CoalescedReactionData
+{
+    fn new(reactions: Vec<CoalescedReaction>) -> Self
+    {
+        let mut seen = HashSet::default();
+        let entities = reactions
+            .iter()
+            .filter_map(
+                |reaction| match reaction
+                {
+                    CoalescedReaction::Entity{ entity, .. } => Some(*entity),
+                    CoalescedReaction::Resource(_)          => None,
+                }
+            )
+            .filter(|entity| seen.insert(*entity))
+            .collect();
+
+        Self{ reactions, entities }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Runs reactors with coalesced reactions (see [`coalesce`]).
+///
+/// Each reactor runs once with all of its pending reactions, which can be read with [`CoalescedEvent`]. Reactors run
+/// in priority order. Coalesced reactions triggered while flushing will wait for the next flush.
+///
+/// This system is automatically run in `Last`. Use [`ReactAppExt::flush_coalesced_reactions_in`] to also run it in
+/// other schedules.
+pub fn flush_coalesced_reactions(world: &mut World)
+{
+    let Some(mut coalesced) = world.get_resource_mut::<CoalescedReactions>() else { return; };
+    if coalesced.batches.is_empty() { return; }
+    let batches = coalesced.take_batches();
+
+    for batch in batches
+    {
+        let data_entity = world.spawn((
+                DataEntityCounter::new(1),
+                CoalescedReactionData::new(batch.reactions)
+            )).id();
+        world.commands().queue(ReactionCommand::Coalesced{ data_entity, reactor: batch.reactor });
+    }
+
+    world.flush();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter for reading the reactions delivered to a coalesced reactor.
+///
+/// Can only be used within [`SystemCommands`](super::SystemCommand).
+///
+/// Use [`coalesce`] to make a trigger that will read these events. Each `(entity, component)` pair and each resource
+/// is reported at most once per flush, no matter how many times it was triggered.
+///
+/*
+```rust
+fn example(mut c: Commands)
+{
+    c.react().on(
+        coalesce(mutation::<Health>()),
+        |event: CoalescedEvent, healths: Reactive<Health>|
+        {
+            for entity in event.mutated::<Health>()
+            {
+                println!("{:?} health is now {}", entity, healths.get(entity).unwrap().0);
+            }
+        }
+    );
+}
+```
+*/
+#[derive(SystemParam)]
+pub struct CoalescedEvent<'w, 's>
+{
+    tracker: Res<'w, EventAccessTracker>,
+    data: Query<'w, 's, &'static CoalescedReactionData>,
+}
+
+impl<'w, 's> CoalescedEvent<'w, 's>
+{
+    /// Gets the reaction data if there is a coalesced reaction.
+    fn get_data(&self) -> Option<&CoalescedReactionData>
+    {
+        if !self.tracker.is_reacting() { return None; }
+        self.data.get(self.tracker.data_entity()).ok()
+    }
+
+    /// Iterates component reactions of type `rtype`.
+    fn iter_entity_reactions(&self, rtype: EntityReactionType) -> impl Iterator<Item = Entity> + '_
+    {
+        self.get_data()
+            .into_iter()
+            .flat_map(|data| data.reactions.iter())
+            .filter_map(
+                move |reaction| match *reaction
+                {
+                    CoalescedReaction::Entity{ entity, rtype: t } if t == rtype => Some(entity),
+                    _ => None,
+                }
+            )
+    }
+
+    /// Iterates all entities with coalesced component reactions.
+    ///
+    /// Each entity is returned once.
+    pub fn entities(&self) -> impl Iterator<Item = Entity> + '_
+    {
+        self.get_data()
+            .into_iter()
+            .flat_map(|data| data.entities.iter().copied())
+    }
+
+    /// Iterates entities where `C` was inserted.
+    pub fn inserted<C: ReactComponent>(&self) -> impl Iterator<Item = Entity> + '_
+    {
+        self.iter_entity_reactions(EntityReactionType::Insertion(TypeId::of::<C>()))
+    }
+
+    /// Iterates entities where `C` was mutated.
+    pub fn mutated<C: ReactComponent>(&self) -> impl Iterator<Item = Entity> + '_
+    {
+        self.iter_entity_reactions(EntityReactionType::Mutation(TypeId::of::<C>()))
+    }
+
+    /// Returns `true` if `R` was mutated.
+    pub fn resource_mutated<R: ReactResource>(&self) -> bool
+    {
+        let Some(data) = self.get_data() else { return false; };
+        data.reactions.contains(&CoalescedReaction::Resource(TypeId::of::<R>()))
+    }
+
+    /// Returns `true` if there is nothing to read.
+    pub fn is_empty(&self) -> bool
+    {
+        self.get_data().is_none()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -11,7 +11,8 @@ use std::any::TypeId;
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn try_cleanup_data_entity(world: &mut World, entity: Entity)
+/// Decrements the data entity counter and despawns the entity if there are no more readers.
+pub(crate) fn try_cleanup_data_entity(world: &mut World, entity: Entity)
 {
     let Some(mut counter) = world.get_mut::<DataEntityCounter>(entity) else { return };
     counter.decrement();
@@ -34,6 +35,12 @@ fn end_system_event(world: &mut World)
     world.despawn(data_entity);
 }
 
+fn end_system_request(world: &mut World)
+{
+    // The request data is despawned after the response is extracted.
+    world.resource_mut::<SystemEventAccessTracker>().end();
+}
+
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
@@ -44,7 +51,8 @@ fn start_entity_reaction(world: &mut World, reactor: SystemCommand)
 
 fn end_entity_reaction(world: &mut World)
 {
-    world.resource_mut::<EntityReactionAccessTracker>().end();
+    let Some(data_entity) = world.resource_mut::<EntityReactionAccessTracker>().end() else { return };
+    try_cleanup_data_entity(world, data_entity);
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -107,6 +115,11 @@ impl DataEntityCounter
         Self{ count }
     }
 
+    pub(crate) fn increment(&mut self, count: usize)
+    {
+        self.count += count;
+    }
+
     fn decrement(&mut self)
     {
         self.count = self.count.saturating_sub(1);
@@ -130,7 +143,7 @@ impl DataEntityCounter
 /// [`commands.spawn_system_command()`](super::ReactCommandsExt::spawn_system_command).
 ///
 /// All reactors are stored as system commands (i.e. systems registered with [`ReactCommands::on`]).
-#[derive(Debug, Copy, Clone, Deref, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Deref, Eq, PartialEq, Hash)]
 pub struct SystemCommand(pub Entity);
 
 impl Command for SystemCommand
@@ -170,6 +183,7 @@ impl Command for EventCommand
     fn apply(self, world: &mut World)
     {
         world.resource_mut::<SystemEventAccessTracker>().prepare(self.system, self.data_entity);
+        prepare_cascade_node(world, ReactionKind::SystemEvent, None);
         syscommand_runner(
             world,
             self.system,
@@ -181,6 +195,40 @@ impl Command for EventCommand
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Sends a system request to a system command and returns the response.
+///
+/// Returns `None` if the system command did not respond, is missing, or is already running.
+pub(crate) fn run_system_request<Req, Resp>(world: &mut World, system: SystemCommand, request: Req) -> Option<Resp>
+where
+    Req: Send + Sync + 'static,
+    Resp: Send + Sync + 'static,
+{
+    // Recursive requests would be deferred by the runner, so they can't respond.
+    if !world.get::<SystemCommandStorage>(*system).is_some_and(|storage| storage.has_callback())
+    {
+        tracing::warn!(?system, "ignoring recursive or missing system request");
+        return None;
+    }
+
+    let data_entity = world.spawn(SystemRequestData::<Req, Resp>::new(request)).id();
+    world.resource_mut::<SystemEventAccessTracker>().prepare(system, data_entity);
+    prepare_cascade_node(world, ReactionKind::SystemEvent, None);
+    syscommand_runner(
+        world,
+        system,
+        SystemCommandSetup::new(system, start_system_event),
+        SystemCommandCleanup::new(end_system_request)
+    );
+
+    // The system command may not have taken the request if it despawned itself.
+    let mut entity_mut = world.get_entity_mut(data_entity).ok()?;
+    let response = entity_mut.get_mut::<SystemRequestData<Req, Resp>>()?.take_response();
+    entity_mut.despawn();
+    response
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// A reaction command.
 ///
 /// Reaction commands are sent by the internals of [`ReactCommands`].
@@ -190,6 +238,8 @@ pub(crate) enum ReactionCommand
     /// A reaction to a resource mutation.
     Resource
     {
+        /// Entity where the previous value of the resource is stored, if it was tracked.
+        data_entity: Option<Entity>,
         /// The system command triggered by this event.
         reactor: SystemCommand,
     },
@@ -200,6 +250,8 @@ pub(crate) enum ReactionCommand
         reaction_source: Entity,
         /// The type of the entity reaction trigger.
         reaction_type: EntityReactionType,
+        /// Entity where extra reaction data is stored (e.g. the previous value of a mutated component).
+        data_entity: Option<Entity>,
         /// The system command triggered by this event.
         reactor: SystemCommand,
     },
@@ -226,6 +278,17 @@ pub(crate) enum ReactionCommand
         /// The system command triggered by this event.
         reactor: SystemCommand,
     },
+    /// A reaction to an expired timer.
+    Timer
+    {
+        /// The system command triggered by this timer.
+        reactor: SystemCommand,
+        /// The reactor's handle.
+        ///
+        /// This will be dropped after the reactor runs, ensuring the reactor will be cleaned up if there are
+        /// no other owners of the handle.
+        handle: ReactorHandle,
+    },
     /// A reaction to a broadcast event.
     BroadcastEvent
     {
@@ -234,21 +297,93 @@ pub(crate) enum ReactionCommand
         /// The system command triggered by this event.
         reactor: SystemCommand,
     },
+    /// A reaction that should be added to the reactor's coalesced batch instead of running immediately.
+    Coalesce
+    {
+        /// The system command triggered by this reaction.
+        reactor: SystemCommand,
+        /// The reactor's priority, used to order coalesced reactors when they are flushed.
+        priority: i32,
+        /// The reaction to coalesce.
+        reaction: CoalescedReaction,
+    },
+    /// A reaction to a batch of coalesced reactions.
+    Coalesced
+    {
+        /// Entity where the coalesced reactions are stored.
+        data_entity: Entity,
+        /// The system command triggered by the reactions.
+        reactor: SystemCommand,
+    },
 }
 
-impl Command for ReactionCommand
+impl ReactionCommand
 {
-    fn apply(self, world: &mut World)
+    /// Gets the [`ReactionKind`] and source entity of this reaction for the [`CascadeRecorder`].
+    fn cascade_node(&self) -> Option<(ReactionKind, Option<Entity>)>
     {
         match self
         {
-            Self::Resource{ reactor } =>
+            Self::Resource{ .. }                   => Some((ReactionKind::ResourceMutation, None)),
+            Self::EntityReaction{ reaction_source, reaction_type, .. } =>
+            {
+                Some(((*reaction_type).into(), Some(*reaction_source)))
+            }
+            Self::Despawn{ reaction_source, .. }   => Some((ReactionKind::Despawn, Some(*reaction_source))),
+            Self::EntityEvent{ target, .. }        => Some((ReactionKind::EntityEvent, Some(*target))),
+            Self::Timer{ .. }                      => Some((ReactionKind::Timer, None)),
+            Self::BroadcastEvent{ .. }             => Some((ReactionKind::Broadcast, None)),
+            Self::Coalesced{ .. }                  => Some((ReactionKind::Coalesced, None)),
+            Self::Coalesce{ .. }                   => None,
+        }
+    }
+
+    /// Gets the reactor that will run for this reaction.
+    ///
+    /// Returns `None` for reactions that are added to a coalesced batch instead of running.
+    #[cfg(feature = "testing")]
+    pub(crate) fn reactor(&self) -> Option<SystemCommand>
+    {
+        match self
+        {
+            Self::Resource{ reactor, .. }       |
+            Self::EntityReaction{ reactor, .. } |
+            Self::Despawn{ reactor, .. }        |
+            Self::EntityEvent{ reactor, .. }    |
+            Self::Timer{ reactor, .. }          |
+            Self::BroadcastEvent{ reactor, .. } |
+            Self::Coalesced{ reactor, .. }      => Some(*reactor),
+            Self::Coalesce{ .. }                => None,
+        }
+    }
+
+    /// Runs the reaction.
+    pub(crate) fn run(self, world: &mut World)
+    {
+        if let Some((kind, source)) = self.cascade_node()
+        {
+            prepare_cascade_node(world, kind, source);
+        }
+
+        match self
+        {
+            Self::Resource{ data_entity: None, reactor } =>
             {
                 syscommand_runner(world, reactor, SystemCommandSetup::default(), SystemCommandCleanup::default());
             }
-            Self::EntityReaction{ reaction_source, reaction_type, reactor } =>
+            Self::Resource{ data_entity: Some(data_entity), reactor } =>
             {
-                world.resource_mut::<EntityReactionAccessTracker>().prepare(reactor, reaction_source, reaction_type);
+                world.resource_mut::<EventAccessTracker>().prepare(reactor, data_entity);
+                syscommand_runner(world,
+                    reactor,
+                    SystemCommandSetup::new(reactor, start_broadcast_event),
+                    SystemCommandCleanup::new(end_broadcast_event)
+                );
+            }
+            Self::EntityReaction{ reaction_source, reaction_type, data_entity, reactor } =>
+            {
+                world.resource_mut::<EntityReactionAccessTracker>()
+                    .prepare(reactor, reaction_source, reaction_type, data_entity);
                 syscommand_runner(
                     world,
                     reactor,
@@ -272,6 +407,7 @@ impl Command for ReactionCommand
                     reactor,
                     target,
                     EntityReactionType::Event(TypeId::of::<()>()),
+                    None,
                 );
                 world.resource_mut::<EventAccessTracker>().prepare(reactor, data_entity);
                 syscommand_runner(world,
@@ -280,7 +416,13 @@ impl Command for ReactionCommand
                     SystemCommandCleanup::new(end_entity_event)
                 );
             }
-            Self::BroadcastEvent{ data_entity, reactor } =>
+            Self::Timer{ reactor, handle } =>
+            {
+                syscommand_runner(world, reactor, SystemCommandSetup::default(), SystemCommandCleanup::default());
+                drop(handle);
+            }
+            Self::BroadcastEvent{ data_entity, reactor } |
+            Self::Coalesced{ data_entity, reactor } =>
             {
                 world.resource_mut::<EventAccessTracker>().prepare(reactor, data_entity);
                 syscommand_runner(world,
@@ -289,8 +431,25 @@ impl Command for ReactionCommand
                     SystemCommandCleanup::new(end_broadcast_event)
                 );
             }
+            Self::Coalesce{ reactor, priority, reaction } =>
+            {
+                world.resource_mut::<CoalescedReactions>().add(reactor, priority, reaction);
+            }
         }
     }
 }
 
+impl Command for ReactionCommand
+{
+    fn apply(self, world: &mut World)
+    {
+        #[cfg(feature = "testing")]
+        let Some(command) = crate::testing::intercept_reaction(world, self) else { return; };
+        #[cfg(not(feature = "testing"))]
+        let command = self;
+
+        command.run(world);
+    }
+}
+
 //-------------------------------------------------------------------------------------------------------------------
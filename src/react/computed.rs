@@ -0,0 +1,137 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+
+//standard shortcuts
+use core::any::TypeId;
+use core::ops::Deref;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Stores a newly computed value, triggering mutation reactions only if the value changed.
+fn update_computed<T: PartialEq + Send + Sync + 'static>(
+    In(value) : In<T>,
+    mut c     : Commands,
+    computed  : Option<ResMut<ReactResInner<Computed<T>>>>,
+){
+    match computed
+    {
+        Some(mut computed) => { (*computed).set_if_neq(&mut c, Computed{ value }); }
+        None => { c.insert_react_resource(Computed{ value }); }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reactive resource that holds a value derived from other reactive state.
+///
+/// Computed values are added with [`ReactAppExt::add_computed`], which takes a system that computes the value and a
+/// set of dependency triggers. When a dependency triggers, the value is recomputed and compared with the current
+/// value. Mutation reactions for `Computed<T>` (i.e. [`resource_mutation::<Computed<T>>()`](resource_mutation)) only
+/// run if the value changed, so computed values can depend on each other to form a reactive graph.
+///
+/// Read the value with [`ReactRes<Computed<T>>`](ReactRes). The initial value is computed in `PostStartup`, so it is
+/// not available while the app is being built or in `Startup` (unless a dependency triggers before then).
+///
+/*
+```rust
+#[derive(PartialEq)]
+struct TotalWeight(f32);
+
+app.add_computed(
+    mutation::<Item>(),
+    |items: Query<&React<Item>>| TotalWeight(items.iter().map(|i| i.weight).sum())
+);
+app.add_reactor(
+    resource_mutation::<Computed<TotalWeight>>(),
+    |weight: ReactRes<Computed<TotalWeight>>| println!("total weight: {}", weight.0)
+);
+```
+*/
+#[derive(Debug, PartialEq)]
+pub struct Computed<T: PartialEq + Send + Sync + 'static>
+{
+    value: T,
+}
+
+impl<T: PartialEq + Send + Sync + 'static> Computed<T>
+{
+    /// Gets the computed value.
+    pub fn get(&self) -> &T
+    {
+        &self.value
+    }
+}
+
+impl<T: PartialEq + Send + Sync + 'static> Deref for Computed<T>
+{
+    type Target = T;
+
+    fn deref(&self) -> &T
+    {
+        &self.value
+    }
+}
+
+impl<T: PartialEq + Send + Sync + 'static> ReactResource for Computed<T> {}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks registered computed values and the initial computations that have not run yet.
+#[derive(Resource, Default)]
+struct ComputedValues
+{
+    registered: HashSet<TypeId>,
+    pending: Vec<SystemCommand>,
+}
+
+/// Computes the initial values of computed values in registration order.
+///
+/// Runs in `PostStartup` so dependencies inserted while building the app or in `Startup` are available.
+fn compute_initial_values(mut c: Commands, mut computed: ResMut<ComputedValues>)
+{
+    for sys_command in computed.pending.drain(..)
+    {
+        c.queue(sys_command);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawns the reactor that maintains a [`Computed<T>`] and schedules its initial computation.
+pub(crate) fn add_computed_reactor<T, M>(
+    app      : &mut App,
+    triggers : impl ReactionTriggerBundle,
+    compute  : impl IntoSystem<(), T, M> + Send + Sync + 'static,
+)
+where
+    T: PartialEq + Send + Sync + 'static
+{
+    if !app.is_plugin_added::<ReactPlugin>()
+    {
+        panic!("failed adding computed value {:?}, ReactPlugin is missing", std::any::type_name::<T>());
+    }
+    if !app.world().contains_resource::<ComputedValues>()
+    {
+        app.init_resource::<ComputedValues>()
+            .add_systems(PostStartup, compute_initial_values);
+    }
+    if !app.world_mut().resource_mut::<ComputedValues>().registered.insert(TypeId::of::<Computed<T>>())
+    {
+        panic!("duplicate computed values of type {:?} are not allowed", std::any::type_name::<T>());
+    }
+
+    let world = app.world_mut();
+    let sys_command = world.spawn_system_command(compute.pipe(update_computed::<T>));
+    world.react(|rc| rc.with(triggers, sys_command, ReactorMode::Persistent));
+
+    // The initial value is computed in `PostStartup`, after all dependencies have had a chance to be inserted.
+    world.resource_mut::<ComputedValues>().pending.push(sys_command);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,101 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+use std::any::Any;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Collects the entities an entity event will visit, in order.
+fn propagation_path(world: &World, target: Entity, propagation: EventPropagation) -> Vec<Entity>
+{
+    let mut path = vec![target];
+    if propagation == EventPropagation::TargetOnly { return path; }
+
+    let mut current = target;
+    while let Some(parent) = world.get::<Parent>(current)
+    {
+        current = parent.get();
+        path.push(current);
+    }
+
+    if propagation == EventPropagation::Tunnel { path.reverse(); }
+    path
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sends an entity event that visits multiple entities in the entity hierarchy.
+///
+/// Reactors of each entity run before the event moves to the next entity. The event will stop propagating if a
+/// reactor calls [`EntityEvent::stop_propagation`].
+///
+/// Entity-agnostic reactors (see [`any_entity_event`]) only run once, when the event visits the first entity.
+pub(crate) fn propagate_entity_event<E: Send + Sync + 'static>(
+    world       : &mut World,
+    target      : Entity,
+    event       : E,
+    propagation : EventPropagation,
+){
+    let path = propagation_path(world, target, propagation);
+
+    // The extra count keeps the data entity alive until propagation is done.
+    let data_entity = world.spawn((DataEntityCounter::new(1), EntityEventData::new(target, event))).id();
+    let mut reactors = Vec::default();
+
+    for (idx, entity) in path.into_iter().enumerate()
+    {
+        // prep data for this entity
+        let Some(mut data) = world.get_mut::<EntityEventData<E>>(data_entity) else { break; };
+        if data.is_stopped() { break; }
+        data.set_current(entity);
+
+        // collect reactors that accept the event
+        let Some(cache) = world.get_resource::<ReactCache>() else { break; };
+        let Some(data) = world.get::<EntityEventData<E>>(data_entity) else { break; };
+        cache.collect_entity_event_reactors::<E>(
+            world.get::<EntityReactors>(entity),
+            Some(data.data() as &dyn Any),
+            idx == 0,
+            &mut reactors
+        );
+        if reactors.is_empty() { continue; }
+
+        // run reactors
+        if let Some(mut counter) = world.get_mut::<DataEntityCounter>(data_entity)
+        {
+            counter.increment(reactors.len());
+        }
+
+        for reactor in reactors.drain(..)
+        {
+            ReactionCommand::EntityEvent{ target: entity, data_entity, reactor }.apply(world);
+        }
+    }
+
+    try_cleanup_data_entity(world, data_entity);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Controls which entities an entity event will visit.
+///
+/// See [`ReactCommands::propagate_entity_event`].
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum EventPropagation
+{
+    /// The event only visits the target entity.
+    #[default]
+    TargetOnly,
+    /// The event visits the target entity, then its parent, and so on up to the root of the hierarchy.
+    Bubble,
+    /// The event visits the root of the target's hierarchy, then each child down to the target entity.
+    Tunnel,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -15,14 +15,14 @@ use std::marker::PhantomData;
 
 //todo: switch to ComponentId when observers are implemented
 //(cannot do so yet because component ids are not available when reactions are triggered, only type ids)
-struct ReactComponentId<T: ReactComponent>
+struct ReactComponentId<T: Send + Sync + 'static>
 {
     //id: ComponentId,
     id: TypeId,
     p: PhantomData<T>,
 }
 
-impl<T: ReactComponent> ReactComponentId<T>
+impl<T: Send + Sync + 'static> ReactComponentId<T>
 {
     fn id(&self) -> TypeId
     {
@@ -30,7 +30,7 @@ impl<T: ReactComponent> ReactComponentId<T>
     }
 }
 
-impl<T: ReactComponent> FromWorld for ReactComponentId<T>
+impl<T: Send + Sync + 'static> FromWorld for ReactComponentId<T>
 {
     fn from_world(_world: &mut World) -> Self
     {
@@ -57,40 +57,51 @@ pub(crate) struct EntityReactionAccessTracker
     reaction_source: Entity,
     /// The type of the most recent entity reaction trigger.
     reaction_type: EntityReactionType,
+    /// Entity where extra data for the most recent entity reaction is stored.
+    data_entity: Option<Entity>,
 
     /// Reaction information cached for when the reaction system actually runs.
-    prepared: Vec<(SystemCommand, Entity, EntityReactionType)>,
+    prepared: Vec<(SystemCommand, Entity, EntityReactionType, Option<Entity>)>,
 }
 
 impl EntityReactionAccessTracker
 {
     /// Caches metadata for an entity reaction.
-    pub(crate) fn prepare(&mut self, system: SystemCommand, source: Entity, reaction: EntityReactionType)
-    {
-        self.prepared.push((system, source, reaction));
+    pub(crate) fn prepare(
+        &mut self,
+        system      : SystemCommand,
+        source      : Entity,
+        reaction    : EntityReactionType,
+        data_entity : Option<Entity>,
+    ){
+        self.prepared.push((system, source, reaction, data_entity));
     }
 
     /// Sets metadata for the current entity reaction.
     pub(crate) fn start(&mut self, reactor: SystemCommand)
     {
-        let Some(pos) = self.prepared.iter().position(|(s, _, _)| *s == reactor) else {
+        let Some(pos) = self.prepared.iter().position(|(s, _, _, _)| *s == reactor) else {
             tracing::error!("prepared entity reaction is missing {:?}", reactor);
             debug_assert!(false);
             return;
         };
-        let (system, source, reaction) = self.prepared.swap_remove(pos);
+        let (system, source, reaction, data_entity) = self.prepared.swap_remove(pos);
 
         debug_assert!(!self.currently_reacting);
         self.currently_reacting = true;
         self.system = system;
         self.reaction_source = source;
         self.reaction_type = reaction;
+        self.data_entity = data_entity;
     }
 
     /// Unsets the 'is reacting' flag.
-    pub(crate) fn end(&mut self)
+    ///
+    /// Returns the data entity so it can be cleaned up. It should only be despawned after the *last* reader is done.
+    pub(crate) fn end(&mut self) -> Option<Entity>
     {
         self.currently_reacting = false;
+        self.data_entity.take()
     }
 
     /// Returns `true` if an entity reaction is currently being processed.
@@ -116,6 +127,12 @@ impl EntityReactionAccessTracker
     {
         self.reaction_type
     }
+
+    /// Returns the data entity of the most recent entity reaction.
+    fn data_entity(&self) -> Option<Entity>
+    {
+        self.data_entity
+    }
 }
 
 impl Default for EntityReactionAccessTracker
@@ -127,6 +144,7 @@ impl Default for EntityReactionAccessTracker
             system: SystemCommand(Entity::PLACEHOLDER),
             reaction_source: Entity::PLACEHOLDER,
             reaction_type: EntityReactionType::Insertion(TypeId::of::<()>()),
+            data_entity: None,
             prepared: Vec::default(),
         }
     }
@@ -134,6 +152,30 @@ impl Default for EntityReactionAccessTracker
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Stores extra data for an entity reaction (e.g. the previous value of a mutated component).
+#[derive(Component)]
+pub(crate) struct EntityReactionData<T: Send + Sync + 'static>
+{
+    data: T,
+}
+
+impl<T: Send + Sync + 'static> EntityReactionData<T>
+{
+    /// Makes a new entity reaction data.
+    pub(crate) fn new(data: T) -> Self
+    {
+        Self{ data }
+    }
+
+    /// Reads the reaction data.
+    fn read(&self) -> &T
+    {
+        &self.data
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// System parameter for reading entity component insertion events in systems that react to those events.
 ///
 /// Can only be used within [`SystemCommands`](super::SystemCommand).
@@ -207,6 +249,9 @@ impl<'w, 's, T: ReactComponent> InsertionEvent<'w, 's, T>
 ///
 /// Use [`entity_mutation`] or [`mutation`] to make a trigger that will read these events.
 ///
+/// If the component tracks previous values (e.g. with `#[react(track_previous)]` on the derive), then
+/// [`MutationEvent::previous`] will return the value from before the mutation.
+///
 /*
 ```rust
 fn example(mut c: Commands, query: Query<&mut React<A>>)
@@ -231,6 +276,7 @@ pub struct MutationEvent<'w, 's, T: ReactComponent>
 {
     component_id: Local<'s, ReactComponentId<T>>,
     tracker: Res<'w, EntityReactionAccessTracker>,
+    previous: Query<'w, 's, &'static EntityReactionData<T>>,
 }
 
 impl<'w, 's, T: ReactComponent> MutationEvent<'w, 's, T>
@@ -256,6 +302,17 @@ impl<'w, 's, T: ReactComponent> MutationEvent<'w, 's, T>
         Some(self.tracker.source())
     }
 
+    /// Returns the value of `T` from before the mutation that the system is reacting to.
+    ///
+    /// Returns `None` if `T` does not track previous values (see [`ReactComponent::snapshot_previous`]), or if the
+    /// mutation was triggered manually with [`React::trigger_mutation`].
+    pub fn previous(&self) -> Option<&T>
+    {
+        self.get()?;
+        let data_entity = self.tracker.data_entity()?;
+        self.previous.get(data_entity).ok().map(EntityReactionData::read)
+    }
+
     /// Returns `true` if there is nothing to read.
     ///
     /// Equivalent to `event.get().is_none()`.
@@ -275,6 +332,9 @@ impl<'w, 's, T: ReactComponent> MutationEvent<'w, 's, T>
 ///
 /// Use [`entity_removal`] or [`removal`] to make a trigger that will read these events.
 ///
+/// The removed component value can be read with [`RemovalEvent::value`]. It is shared by all reactors of that
+/// removal.
+///
 /*
 ```rust
 fn example(mut c: Commands, query: Query<Entity, With<React<A>>>)
@@ -283,9 +343,9 @@ fn example(mut c: Commands, query: Query<Entity, With<React<A>>>)
         removal::<A>(),  // entity-specific: entity_removal::<A>(target_entity)
         |event: RemovalEvent<A>|
         {
-            if let Some(entity) = event.get()
+            if let Some((entity, value)) = event.get().zip(event.value())
             {
-                println!("'A' was removed from {:?}", entity);
+                println!("'A' ({:?}) was removed from {:?}", value, entity);
             }
         }
     );
@@ -299,6 +359,7 @@ pub struct RemovalEvent<'w, 's, T: ReactComponent>
 {
     component_id: Local<'s, ReactComponentId<T>>,
     tracker: Res<'w, EntityReactionAccessTracker>,
+    value: Query<'w, 's, &'static EntityReactionData<T>>,
 }
 
 impl<'w, 's, T: ReactComponent> RemovalEvent<'w, 's, T>
@@ -324,6 +385,150 @@ impl<'w, 's, T: ReactComponent> RemovalEvent<'w, 's, T>
         Some(self.tracker.source())
     }
 
+    /// Returns the removed `T` value that the system is reacting to.
+    ///
+    /// Values are only captured for components with `#[react(track_removed)]` (see [`ReactComponent`]). Returns `None`
+    /// if the value was not captured, which can also happen if the removal occurred before any removal reactors for `T`
+    /// were registered.
+    pub fn value(&self) -> Option<&T>
+    {
+        self.get()?;
+        let data_entity = self.tracker.data_entity()?;
+        self.value.get(data_entity).ok().map(EntityReactionData::read)
+    }
+
+    /// Returns `true` if there is nothing to read.
+    ///
+    /// Equivalent to `event.get().is_none()`.
+    pub fn is_empty(&self) -> bool
+    {
+        self.get().is_none()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter for reading plain component addition events in systems that react to those events.
+///
+/// Can only be used within [`SystemCommands`](super::SystemCommand).
+///
+/// Use [`added`] to make a trigger that will read these events.
+///
+/*
+```rust
+fn example(mut c: Commands)
+{
+    c.react().on(
+        added::<Transform>(),
+        |event: AddedEvent<Transform>|
+        {
+            if let Some(entity) = event.get()
+            {
+                println!("'Transform' was added to {:?}", entity);
+            }
+        }
+    );
+
+    c.spawn(Transform::default());
+}
+```
+*/
+#[derive(SystemParam)]
+pub struct AddedEvent<'w, 's, T: Component>
+{
+    component_id: Local<'s, ReactComponentId<T>>,
+    tracker: Res<'w, EntityReactionAccessTracker>,
+}
+
+impl<'w, 's, T: Component> AddedEvent<'w, 's, T>
+{
+    /// Returns the entity that received a `T` component that the system is reacting to.
+    ///
+    /// This will return at most one unique entity each time a reactor runs.
+    ///
+    /// Panics if the system is not reacting to an added event for `T`.
+    pub fn entity(&self) -> Entity
+    {
+        self.get()
+            .unwrap_or_else(|| panic!("failed reading added event for {}, there is no event", type_name::<T>()))
+    }
+
+    /// See [`Self::entity`].
+    pub fn get(&self) -> Option<Entity>
+    {
+        if !self.tracker.is_reacting() { return None; }
+        let EntityReactionType::Added(component_id) = self.tracker.reaction_type() else { return None; };
+        if component_id != self.component_id.id() { return None; }
+
+        Some(self.tracker.source())
+    }
+
+    /// Returns `true` if there is nothing to read.
+    ///
+    /// Equivalent to `event.get().is_none()`.
+    pub fn is_empty(&self) -> bool
+    {
+        self.get().is_none()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter for reading plain component change events in systems that react to those events.
+///
+/// Can only be used within [`SystemCommands`](super::SystemCommand).
+///
+/// Use [`entity_changed`] or [`changed`] to make a trigger that will read these events.
+///
+/*
+```rust
+fn example(mut c: Commands, mut query: Query<&mut Transform>)
+{
+    c.react().on(
+        changed::<Transform>(),  // entity-specific: entity_changed::<Transform>(target_entity)
+        |event: ChangedEvent<Transform>|
+        {
+            if let Some(entity) = event.get()
+            {
+                println!("'Transform' was changed on {:?}", entity);
+            }
+        }
+    );
+
+    query.single_mut().translation.x += 1.0;  //triggers changed reactions when changes are scanned
+}
+```
+*/
+#[derive(SystemParam)]
+pub struct ChangedEvent<'w, 's, T: Component>
+{
+    component_id: Local<'s, ReactComponentId<T>>,
+    tracker: Res<'w, EntityReactionAccessTracker>,
+}
+
+impl<'w, 's, T: Component> ChangedEvent<'w, 's, T>
+{
+    /// Returns the entity on which a `T` component was changed that the system is reacting to.
+    ///
+    /// This will return at most one unique entity each time a reactor runs.
+    ///
+    /// Panics if the system is not reacting to a changed event for `T`.
+    pub fn entity(&self) -> Entity
+    {
+        self.get()
+            .unwrap_or_else(|| panic!("failed reading changed event for {}, there is no event", type_name::<T>()))
+    }
+
+    /// See [`Self::entity`].
+    pub fn get(&self) -> Option<Entity>
+    {
+        if !self.tracker.is_reacting() { return None; }
+        let EntityReactionType::Changed(component_id) = self.tracker.reaction_type() else { return None; };
+        if component_id != self.component_id.id() { return None; }
+
+        Some(self.tracker.source())
+    }
+
     /// Returns `true` if there is nothing to read.
     ///
     /// Equivalent to `event.get().is_none()`.
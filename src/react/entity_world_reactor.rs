@@ -121,6 +121,10 @@ pub trait EntityWorldReactor: Send + Sync + 'static
     type Triggers: EntityTriggerBundle + ReactionTriggerBundle;
     /// Data that is 'local' to a specific entity that triggers the reactor.
     type Local: Send + Sync + 'static;
+    /// Priority of the reactor relative to other reactors of the same triggers.
+    ///
+    /// Reactors with higher priority run first. See [`ReactCommands::with_priority`].
+    const PRIORITY: i32 = 0;
 
     /// Consumes `Self` and returns the reactor system.
     ///
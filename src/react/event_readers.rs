@@ -1,5 +1,5 @@
 //local shortcuts
-use crate::prelude::SystemCommand;
+use crate::prelude::{ReactResource, SystemCommand};
 
 //third-party shortcuts
 use bevy::ecs::system::SystemParam;
@@ -7,6 +7,7 @@ use bevy::prelude::*;
 
 //standard shortcuts
 use std::any::type_name;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 //-------------------------------------------------------------------------------------------------------------------
 
@@ -56,13 +57,13 @@ impl EventAccessTracker
     }
 
     /// Returns `true` if an reactive event is currently being processed.
-    fn is_reacting(&self) -> bool
+    pub(crate) fn is_reacting(&self) -> bool
     {
         self.currently_reacting
     }
 
     /// Returns the data entity of the most recent reactive event.
-    fn data_entity(&self) -> Entity
+    pub(crate) fn data_entity(&self) -> Entity
     {
         self.data_entity
     }
@@ -98,7 +99,7 @@ impl<T: Send + Sync + 'static> BroadcastEventData<T>
     }
 
     /// Reads the event data.
-    fn read(&self) -> &T
+    pub(crate) fn read(&self) -> &T
     {
         &self.data
     }
@@ -110,7 +111,12 @@ impl<T: Send + Sync + 'static> BroadcastEventData<T>
 #[derive(Component)]
 pub(crate) struct EntityEventData<T: Send + Sync + 'static>
 {
-    entity: Entity,
+    /// The entity the event was originally sent to.
+    target: Entity,
+    /// The entity the event is currently visiting (differs from `target` while propagating).
+    current: Entity,
+    /// Set by reactors to prevent the event from propagating further.
+    stopped: AtomicBool,
     data: T,
 }
 
@@ -119,13 +125,67 @@ impl<T: Send + Sync + 'static> EntityEventData<T>
     /// Makes a new entity event data.
     pub(crate) fn new(target_entity: Entity, data: T) -> Self
     {
-        Self{ entity: target_entity, data }
+        Self{ target: target_entity, current: target_entity, stopped: AtomicBool::new(false), data }
     }
 
     /// Reads the event data.
     fn read(&self) -> (Entity, &T)
     {
-        (self.entity, &self.data)
+        (self.current, &self.data)
+    }
+
+    /// Gets the raw event data.
+    pub(crate) fn data(&self) -> &T
+    {
+        &self.data
+    }
+
+    /// Gets the entity the event was originally sent to.
+    fn target(&self) -> Entity
+    {
+        self.target
+    }
+
+    /// Sets the entity the event is currently visiting.
+    pub(crate) fn set_current(&mut self, entity: Entity)
+    {
+        self.current = entity;
+    }
+
+    /// Prevents the event from propagating to more entities.
+    fn stop(&self)
+    {
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if propagation was stopped.
+    pub(crate) fn is_stopped(&self) -> bool
+    {
+        self.stopped.load(Ordering::Relaxed)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Stores the previous value of a mutated reactive resource.
+#[derive(Component)]
+pub(crate) struct ResourceMutationData<R: ReactResource>
+{
+    previous: R,
+}
+
+impl<R: ReactResource> ResourceMutationData<R>
+{
+    /// Makes a new resource mutation data.
+    pub(crate) fn new(previous: R) -> Self
+    {
+        Self{ previous }
+    }
+
+    /// Reads the previous value.
+    fn read(&self) -> &R
+    {
+        &self.previous
     }
 }
 
@@ -234,6 +294,9 @@ impl<'w, 's, T: Send + Sync + 'static> EntityEvent<'w, 's, T>
 {
     /// Reads entity event data.
     ///
+    /// Returns the entity the event is currently visiting, which is the original target unless the event is
+    /// propagating (see [`EventPropagation`](crate::prelude::EventPropagation)).
+    ///
     /// This will return at most one unique `T` each time a system runs.
     ///
     /// Panics if there is no data to read.
@@ -246,13 +309,17 @@ impl<'w, 's, T: Send + Sync + 'static> EntityEvent<'w, 's, T>
     /// See [`Self::read`].
     pub fn try_read(&self) -> Option<(Entity, &T)>
     {
-        if !self.tracker.is_reacting() { return None; }
-        let Ok(data) = self.data.get(self.tracker.data_entity()) else { return None; };
+        self.get_data().map(EntityEventData::read)
+    }
 
-        Some(data.read())
+    /// Gets the event data if there is an event.
+    fn get_data(&self) -> Option<&EntityEventData<T>>
+    {
+        if !self.tracker.is_reacting() { return None; }
+        self.data.get(self.tracker.data_entity()).ok()
     }
 
-    /// Gets the target entity of the event.
+    /// Gets the entity the event is currently visiting.
     ///
     /// Panics if there is no event.
     pub fn entity(&self) -> Entity
@@ -266,6 +333,31 @@ impl<'w, 's, T: Send + Sync + 'static> EntityEvent<'w, 's, T>
         self.try_read().map(|(e, _)| e)
     }
 
+    /// Gets the entity the event was originally sent to.
+    ///
+    /// Panics if there is no event.
+    pub fn original_target(&self) -> Entity
+    {
+        self.get_original_target()
+            .unwrap_or_else(|| panic!("failed reading entity event for {}, there is no event", type_name::<T>()))
+    }
+
+    /// See [`Self::original_target`].
+    pub fn get_original_target(&self) -> Option<Entity>
+    {
+        self.get_data().map(EntityEventData::target)
+    }
+
+    /// Prevents the event from propagating to more entities.
+    ///
+    /// Reactors of the entity the event is currently visiting will still run.
+    ///
+    /// Does nothing if there is no event.
+    pub fn stop_propagation(&self)
+    {
+        if let Some(data) = self.get_data() { data.stop(); }
+    }
+
     /// Returns `true` if there is nothing to read.
     ///
     /// Equivalent to `event.try_read().is_none()`.
@@ -276,3 +368,60 @@ impl<'w, 's, T: Send + Sync + 'static> EntityEvent<'w, 's, T>
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter for reading resource mutation data in systems that react to resource mutations.
+///
+/// Can only be used within [`SystemCommands`](super::SystemCommand).
+///
+/// Use [`resource_mutation`](crate::prelude::resource_mutation) to make a trigger that will read these events.
+///
+/// Previous values are only available if the resource tracks them (e.g. with `#[react(track_previous)]` on the
+/// derive).
+///
+/*
+```rust
+#[derive(ReactResource, Clone)]
+#[react(track_previous)]
+struct Counter(usize);
+
+fn example(mut c: Commands, mut counter: ReactResMut<Counter>)
+{
+    c.react().on(
+        resource_mutation::<Counter>(),
+        |event: ResourceMutationEvent<Counter>, counter: ReactRes<Counter>|
+        {
+            if let Some(previous) = event.previous()
+            {
+                println!("counter changed from {} to {}", previous.0, counter.0);
+            }
+        }
+    );
+
+    counter.get_mut(&mut c).0 += 1;
+}
+```
+*/
+#[derive(SystemParam)]
+pub struct ResourceMutationEvent<'w, 's, R: ReactResource>
+{
+    tracker: Res<'w, EventAccessTracker>,
+    data: Query<'w, 's, &'static ResourceMutationData<R>>,
+}
+
+impl<'w, 's, R: ReactResource> ResourceMutationEvent<'w, 's, R>
+{
+    /// Returns the value of `R` from before the mutation that the system is reacting to.
+    ///
+    /// Returns `None` if `R` does not track previous values (see [`ReactResource::snapshot_previous`]), or if the
+    /// mutation was triggered manually with
+    /// [`ReactCommands::trigger_resource_mutation`](crate::prelude::ReactCommands::trigger_resource_mutation).
+    pub fn previous(&self) -> Option<&R>
+    {
+        if !self.tracker.is_reacting() { return None; }
+        let Ok(data) = self.data.get(self.tracker.data_entity()) else { return None; };
+
+        Some(data.read())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
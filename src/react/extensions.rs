@@ -3,8 +3,11 @@ use crate::prelude::*;
 
 //third-party shortcuts
 use bevy::prelude::*;
+use bevy::ecs::schedule::ScheduleLabel;
 use bevy::ecs::system::EntityCommands;
 use bevy::ecs::world::Command;
+#[cfg(feature = "reflect_recording")]
+use bevy::reflect::GetTypeRegistration;
 
 //standard shortcuts
 
@@ -41,6 +44,111 @@ pub trait ReactAppExt
     ///
     /// The reactor can be accessed with the [`EntityReactor`] system param.
     fn add_entity_reactor<R: EntityWorldReactor>(&mut self, reactor: R) -> &mut Self;
+    /// Forwards Bevy events of type `E` to broadcast reactors in `PostUpdate`.
+    ///
+    /// Reactors can listen for the events with the [`bevy_event()`] trigger.
+    ///
+    /// See [`Self::bridge_bevy_event_in`] to forward events in a different schedule.
+    fn bridge_bevy_event<E: Event + Clone>(&mut self) -> &mut Self;
+    /// Forwards Bevy events of type `E` to broadcast reactors in the given schedule.
+    ///
+    /// Panics if `E` is already bridged.
+    fn bridge_bevy_event_in<E: Event + Clone>(&mut self, schedule: impl ScheduleLabel) -> &mut Self;
+    /// Writes events sent with [`ReactCommands::broadcast`] to `Events<E>`.
+    ///
+    /// If `E` is also bridged with [`Self::bridge_bevy_event`], then mirrored events will not be forwarded back to
+    /// broadcast reactors.
+    fn mirror_broadcast_to_bevy_event<E: Event + Clone>(&mut self) -> &mut Self;
+    /// Scans for changes to plain components in the given schedule, in addition to the default scan in `Last`.
+    ///
+    /// See [`schedule_change_reactors`].
+    fn scan_component_changes_in(&mut self, schedule: impl ScheduleLabel) -> &mut Self;
+    /// Dispatches expired timers in the given schedule, in addition to the default dispatch in `PreUpdate`.
+    ///
+    /// See [`schedule_timer_reactors`].
+    fn drain_react_timers_in(&mut self, schedule: impl ScheduleLabel) -> &mut Self;
+    /// Flushes coalesced reactions in the given schedule, in addition to the default flush in `Last`.
+    ///
+    /// See [`flush_coalesced_reactions`].
+    fn flush_coalesced_reactions_in(&mut self, schedule: impl ScheduleLabel) -> &mut Self;
+    /// Polls woken async tasks in the given schedule, in addition to the default poll in `Last`.
+    ///
+    /// See [`poll_react_tasks`].
+    fn poll_react_tasks_in(&mut self, schedule: impl ScheduleLabel) -> &mut Self;
+    /// Adds a [`Computed<T>`] resource that is recomputed with `compute` whenever `triggers` fire.
+    ///
+    /// The initial value is computed in `PostStartup`, so dependencies can be inserted later while building the app or
+    /// in `Startup`. Initial values are computed in the order computed values were added. Mutation reactions for
+    /// `Computed<T>` only run if a recomputed value differs from the current value.
+    ///
+    /// Panics if [`ReactPlugin`] was not added or if `Computed<T>` was already added.
+    fn add_computed<T, M>(
+        &mut self,
+        triggers: impl ReactionTriggerBundle,
+        compute: impl IntoSystem<(), T, M> + Send + Sync + 'static
+    ) -> &mut Self
+    where
+        T: PartialEq + Send + Sync + 'static;
+    /// Spawns a [`SystemCommand`] and registers it in the [`SystemCommandRegistry`] with `name`.
+    ///
+    /// Panics if `name` is already registered.
+    fn add_named_command<M>(
+        &mut self,
+        name: impl Into<SystemCommandName>,
+        system: impl IntoSystem<(), (), M> + Send + Sync + 'static
+    ) -> &mut Self;
+    /// Registers an existing [`SystemCommand`] in the [`SystemCommandRegistry`] with `name`.
+    ///
+    /// Panics if `name` is already registered.
+    fn register_named_command(&mut self, name: impl Into<SystemCommandName>, command: SystemCommand) -> &mut Self;
+    /// Enables per-reactor profiling with the [`ReactorStats`] resource.
+    ///
+    /// Stats of despawned system commands are removed in `Last`. If the `bevy_diagnostic` feature is enabled, stats are
+    /// also recorded as Bevy diagnostics in `Last`, summed per system.
+    ///
+    /// Panics if reactor stats are already enabled.
+    fn enable_reactor_stats(&mut self, mode: ReactorStatsMode) -> &mut Self;
+    /// Enables recording of the last `max_cascades` reaction cascades with the [`CascadeRecorder`] resource.
+    ///
+    /// Panics if the cascade recorder is already enabled.
+    fn enable_cascade_recorder(&mut self, max_cascades: usize) -> &mut Self;
+    /// Registers an event type to be recorded by the [`ReactInputRecorder`] and replayed by the
+    /// [`ReactInputReplayer`].
+    ///
+    /// Applies to broadcasts, entity events, and system events. The `encode` and `decode` callbacks convert events to
+    /// and from the text stored in recordings, so any format can be used (e.g. a `serde` format). Events that can't
+    /// be decoded are skipped with a warning.
+    ///
+    /// See `Self::register_recorded_reflect_event` (requires the `reflect_recording` feature) for `Reflect` types.
+    fn register_recorded_event<E: Send + Sync + 'static>(
+        &mut self,
+        encode : impl Fn(&E) -> String + Send + Sync + 'static,
+        decode : impl Fn(&str) -> Option<E> + Send + Sync + 'static
+    ) -> &mut Self;
+    /// Registers a [`ReactComponent`] to be recorded by the [`ReactInputRecorder`] and replayed by the
+    /// [`ReactInputReplayer`].
+    ///
+    /// Applies to insertions and mutations. See [`Self::register_recorded_event`].
+    fn register_recorded_component<C: ReactComponent>(
+        &mut self,
+        encode : impl Fn(&C) -> String + Send + Sync + 'static,
+        decode : impl Fn(&str) -> Option<C> + Send + Sync + 'static
+    ) -> &mut Self;
+    /// Registers a `Reflect` event type to be recorded by the [`ReactInputRecorder`] and replayed by the
+    /// [`ReactInputReplayer`].
+    ///
+    /// The type is added to the [`AppTypeRegistry`], and events are serialized as RON with the registry. See
+    /// [`Self::register_recorded_event`].
+    #[cfg(feature = "reflect_recording")]
+    fn register_recorded_reflect_event<E: FromReflect + TypePath + GetTypeRegistration>(&mut self) -> &mut Self;
+    /// Registers a `Reflect` [`ReactComponent`] to be recorded by the [`ReactInputRecorder`] and replayed by the
+    /// [`ReactInputReplayer`].
+    ///
+    /// See [`Self::register_recorded_reflect_event`].
+    #[cfg(feature = "reflect_recording")]
+    fn register_recorded_reflect_component<C: ReactComponent + FromReflect + TypePath + GetTypeRegistration>(
+        &mut self
+    ) -> &mut Self;
     /// Provides access to [`ReactCommands`].
     fn react<T>(&mut self, callback: impl FnOnce(&mut ReactCommands) -> T) -> &mut Self;
 }
@@ -73,6 +181,7 @@ impl ReactAppExt for App
             panic!("duplicate world reactors of type {:?} are not allowed", std::any::type_name::<R>());
         }
         let sys_command = self.world_mut().spawn_system_command_from(reactor.reactor());
+        self.world_mut().entity_mut(*sys_command).insert(ReactorPriority(R::PRIORITY));
         self.world_mut().insert_resource(WorldReactorRes::<R>::new(sys_command));
         self
     }
@@ -84,6 +193,7 @@ impl ReactAppExt for App
             panic!("duplicate world reactors of type {:?} are not allowed", std::any::type_name::<R>());
         }
         let sys_command = self.world_mut().spawn_system_command_from(reactor.reactor());
+        self.world_mut().entity_mut(*sys_command).insert(ReactorPriority(R::PRIORITY));
         self.world_mut().insert_resource(WorldReactorRes::<R>::new(sys_command));
 
         // Make sure app is ready to use ReactCommands.
@@ -110,10 +220,169 @@ impl ReactAppExt for App
             panic!("duplicate entity world reactors of type {:?} are not allowed", std::any::type_name::<R>());
         }
         let sys_command = self.world_mut().spawn_system_command_from(reactor.reactor());
+        self.world_mut().entity_mut(*sys_command).insert(ReactorPriority(R::PRIORITY));
         self.world_mut().insert_resource(EntityWorldReactorRes::<R>::new(sys_command));
         self
     }
 
+    fn bridge_bevy_event<E: Event + Clone>(&mut self) -> &mut Self
+    {
+        self.bridge_bevy_event_in::<E>(PostUpdate)
+    }
+
+    fn bridge_bevy_event_in<E: Event + Clone>(&mut self, schedule: impl ScheduleLabel) -> &mut Self
+    {
+        // Make sure app is ready to use ReactCommands.
+        if !self.world().contains_resource::<ReactCache>()
+        {
+            self.init_resource::<ReactCache>();
+        }
+        self.setup_auto_despawn();
+
+        setup_bevy_event_bridge::<E>(self, schedule);
+        self
+    }
+
+    fn mirror_broadcast_to_bevy_event<E: Event + Clone>(&mut self) -> &mut Self
+    {
+        if !self.world().contains_resource::<ReactCache>()
+        {
+            self.init_resource::<ReactCache>();
+        }
+
+        self.add_event::<E>();
+        self.world_mut().resource_mut::<ReactCache>().register_broadcast_mirror::<E>();
+        self
+    }
+
+    fn scan_component_changes_in(&mut self, schedule: impl ScheduleLabel) -> &mut Self
+    {
+        self.add_systems(schedule, schedule_change_reactors)
+    }
+
+    fn drain_react_timers_in(&mut self, schedule: impl ScheduleLabel) -> &mut Self
+    {
+        self.add_systems(schedule, schedule_timer_reactors)
+    }
+
+    fn flush_coalesced_reactions_in(&mut self, schedule: impl ScheduleLabel) -> &mut Self
+    {
+        self.add_systems(schedule, flush_coalesced_reactions)
+    }
+
+    fn poll_react_tasks_in(&mut self, schedule: impl ScheduleLabel) -> &mut Self
+    {
+        self.add_systems(schedule, poll_react_tasks)
+    }
+
+    fn add_computed<T, M>(
+        &mut self,
+        triggers: impl ReactionTriggerBundle,
+        compute: impl IntoSystem<(), T, M> + Send + Sync + 'static
+    ) -> &mut Self
+    where
+        T: PartialEq + Send + Sync + 'static
+    {
+        add_computed_reactor(self, triggers, compute);
+        self
+    }
+
+    fn add_named_command<M>(
+        &mut self,
+        name: impl Into<SystemCommandName>,
+        system: impl IntoSystem<(), (), M> + Send + Sync + 'static
+    ) -> &mut Self
+    {
+        let command = self.world_mut().spawn_system_command(system);
+        self.register_named_command(name, command)
+    }
+
+    fn register_named_command(&mut self, name: impl Into<SystemCommandName>, command: SystemCommand) -> &mut Self
+    {
+        let name = name.into();
+        if let Err(existing) = register_named_command(self.world_mut(), name.clone(), command)
+        {
+            panic!("duplicate named system command {:?} (registered: {:?}, new: {:?})", name, existing, command);
+        }
+        self
+    }
+
+    fn enable_reactor_stats(&mut self, mode: ReactorStatsMode) -> &mut Self
+    {
+        if self.world().contains_resource::<ReactorStats>()
+        {
+            panic!("reactor stats are already enabled");
+        }
+        self.insert_resource(ReactorStats::new(mode))
+            .add_systems(First, reset_reactor_stats);
+
+        #[cfg(not(feature = "bevy_diagnostic"))]
+        self.add_systems(Last, prune_reactor_stats.after(poll_react_tasks));
+        #[cfg(feature = "bevy_diagnostic")]
+        self.add_systems(Last, (record_reactor_diagnostics, prune_reactor_stats).chain().after(poll_react_tasks));
+
+        self
+    }
+
+    fn enable_cascade_recorder(&mut self, max_cascades: usize) -> &mut Self
+    {
+        if self.world().contains_resource::<CascadeRecorder>()
+        {
+            panic!("the cascade recorder is already enabled");
+        }
+        self.insert_resource(CascadeRecorder::new(max_cascades))
+    }
+
+    fn register_recorded_event<E: Send + Sync + 'static>(
+        &mut self,
+        encode : impl Fn(&E) -> String + Send + Sync + 'static,
+        decode : impl Fn(&str) -> Option<E> + Send + Sync + 'static
+    ) -> &mut Self
+    {
+        self.init_resource::<ReactInputCodecs>();
+        self.world_mut().resource_mut::<ReactInputCodecs>().register_event(move |e| Some(encode(e)), decode);
+        self
+    }
+
+    fn register_recorded_component<C: ReactComponent>(
+        &mut self,
+        encode : impl Fn(&C) -> String + Send + Sync + 'static,
+        decode : impl Fn(&str) -> Option<C> + Send + Sync + 'static
+    ) -> &mut Self
+    {
+        self.init_resource::<ReactInputCodecs>();
+        self.world_mut().resource_mut::<ReactInputCodecs>().register_component(move |c| Some(encode(c)), decode);
+        self
+    }
+
+    #[cfg(feature = "reflect_recording")]
+    fn register_recorded_reflect_event<E: FromReflect + TypePath + GetTypeRegistration>(&mut self) -> &mut Self
+    {
+        self.register_type::<E>()
+            .init_resource::<ReactInputCodecs>();
+        let registry = self.world().resource::<AppTypeRegistry>().clone();
+        self.world_mut().resource_mut::<ReactInputCodecs>().register_event(
+            reflect_encoder::<E>(registry.clone()),
+            reflect_decoder::<E>(registry)
+        );
+        self
+    }
+
+    #[cfg(feature = "reflect_recording")]
+    fn register_recorded_reflect_component<C: ReactComponent + FromReflect + TypePath + GetTypeRegistration>(
+        &mut self
+    ) -> &mut Self
+    {
+        self.register_type::<C>()
+            .init_resource::<ReactInputCodecs>();
+        let registry = self.world().resource::<AppTypeRegistry>().clone();
+        self.world_mut().resource_mut::<ReactInputCodecs>().register_component(
+            reflect_encoder::<C>(registry.clone()),
+            reflect_decoder::<C>(registry)
+        );
+        self
+    }
+
     fn react<T>(&mut self, callback: impl FnOnce(&mut ReactCommands) -> T) -> &mut Self
     {
         // Ignore returned value.
@@ -137,12 +406,38 @@ pub trait ReactWorldExt
     where
         S: IntoSystem<(), (), M> + Send + Sync + 'static;
 
+    /// Schedules a [`SystemCommand`] to be spawned from a system that can fail.
+    ///
+    /// Errors are passed to the [`ReactErrorHandler`]. See [`SystemCommandCallback::new_fallible`].
+    fn spawn_fallible_system_command<S, M, E>(&mut self, system: S) -> SystemCommand
+    where
+        S: IntoSystem<(), Result<(), E>, M> + Send + Sync + 'static,
+        E: Into<ReactError> + Send + Sync + 'static;
+
     /// Schedules a [`SystemCommand`] to be spawned from a pre-defined callback.
     ///
     /// Returns the system command id that will eventually reference the spawned system.
     /// To run the system, schedule it with `commands.queue(system_command)`.
     fn spawn_system_command_from(&mut self, callback: SystemCommandCallback) -> SystemCommand;
 
+    /// Spawns a [`SystemCommandWith`] that takes `In<I>` and returns `O`.
+    ///
+    /// Systems are not initialized until they are first run.
+    fn spawn_system_command_with<I, O, S, M>(&mut self, system: S) -> SystemCommandWith<I, O>
+    where
+        I: Send + Sync + 'static,
+        O: Send + Sync + 'static,
+        S: IntoSystem<In<I>, O, M> + Send + Sync + 'static;
+
+    /// Runs a [`SystemCommandWith`] and returns its output.
+    ///
+    /// Returns `None` if the system command doesn't exist, or if it is already running (i.e. it was called
+    /// recursively).
+    fn run_system_command_with<I, O>(&mut self, command: SystemCommandWith<I, O>, input: I) -> Option<O>
+    where
+        I: Send + Sync + 'static,
+        O: Send + Sync + 'static;
+
     /// Provides access to [`ReactCommands`].
     fn react<T>(&mut self, callback: impl FnOnce(&mut ReactCommands) -> T) -> T;
 
@@ -154,6 +449,17 @@ pub trait ReactWorldExt
     /// processed within the already-running reaction tree.
     fn send_system_event<T: Send + Sync + 'static>(&mut self, command: SystemCommand, event: T);
 
+    /// Sends a system request to a given [`SystemCommand`] and returns the response.
+    ///
+    /// The target system can take the request and respond to it with the [`SystemRequest`] system parameter.
+    ///
+    /// Returns `None` if the target system did not respond, if it doesn't exist, or if it is already running (i.e.
+    /// it sent a request to itself).
+    fn send_system_request<Req, Resp>(&mut self, command: SystemCommand, request: Req) -> Option<Resp>
+    where
+        Req: Send + Sync + 'static,
+        Resp: Send + Sync + 'static;
+
     /// Sends a broadcasted event.
     /// - Reactors can listen for the event with the [`broadcast()`] trigger.
     /// - Reactors can read the event with the [`BroadcastEvent`] system parameter.
@@ -162,7 +468,18 @@ pub trait ReactWorldExt
     /// Sends an entity-targeted event.
     /// - Reactors can listen for the event with the [`entity_event()`] trigger.
     /// - Reactors can read the event with the [`EntityEvent`] system parameter.
+    /// - The event only visits `entity`. Use [`Self::propagate_entity_event`] to also visit its ancestors.
     fn entity_event<E: Send + Sync + 'static>(&mut self, entity: Entity, event: E);
+
+    /// Sends an entity-targeted event that visits multiple entities in the entity hierarchy.
+    ///
+    /// See [`ReactCommands::propagate_entity_event`].
+    fn propagate_entity_event<E: Send + Sync + 'static>(
+        &mut self,
+        entity      : Entity,
+        event       : E,
+        propagation : EventPropagation,
+    );
 }
 
 impl ReactWorldExt for World
@@ -174,11 +491,36 @@ impl ReactWorldExt for World
         self.spawn_system_command_from(SystemCommandCallback::new(system))
     }
 
+    fn spawn_fallible_system_command<S, M, E>(&mut self, system: S) -> SystemCommand
+    where
+        S: IntoSystem<(), Result<(), E>, M> + Send + Sync + 'static,
+        E: Into<ReactError> + Send + Sync + 'static,
+    {
+        self.spawn_system_command_from(SystemCommandCallback::new_fallible(system))
+    }
+
     fn spawn_system_command_from(&mut self, callback: SystemCommandCallback) -> SystemCommand
     {
         SystemCommand(self.spawn(SystemCommandStorage::new(callback)).id())
     }
 
+    fn spawn_system_command_with<I, O, S, M>(&mut self, system: S) -> SystemCommandWith<I, O>
+    where
+        I: Send + Sync + 'static,
+        O: Send + Sync + 'static,
+        S: IntoSystem<In<I>, O, M> + Send + Sync + 'static
+    {
+        spawn_typed_system_command(self, system)
+    }
+
+    fn run_system_command_with<I, O>(&mut self, command: SystemCommandWith<I, O>, input: I) -> Option<O>
+    where
+        I: Send + Sync + 'static,
+        O: Send + Sync + 'static
+    {
+        run_typed_system_command(self, command, input)
+    }
+
     fn react<T>(&mut self, callback: impl FnOnce(&mut ReactCommands) -> T) -> T
     {
         let mut c = self.commands();
@@ -190,10 +532,19 @@ impl ReactWorldExt for World
 
     fn send_system_event<T: Send + Sync + 'static>(&mut self, command: SystemCommand, event: T)
     {
+        record_react_input_in_world(self, ReactInputKind::SystemEvent, Some(command.0), &event);
         let data_entity = self.spawn(SystemEventData::new(event)).id();
         EventCommand{ system: command, data_entity }.apply(self);
     }
 
+    fn send_system_request<Req, Resp>(&mut self, command: SystemCommand, request: Req) -> Option<Resp>
+    where
+        Req: Send + Sync + 'static,
+        Resp: Send + Sync + 'static
+    {
+        run_system_request(self, command, request)
+    }
+
     fn broadcast<E: Send + Sync + 'static>(&mut self, event: E)
     {
         self.syscall(event, ReactCache::schedule_broadcast_reaction::<E>);
@@ -203,6 +554,15 @@ impl ReactWorldExt for World
     {
         self.syscall((entity, event), ReactCache::schedule_entity_event_reaction::<E>);
     }
+
+    fn propagate_entity_event<E: Send + Sync + 'static>(
+        &mut self,
+        entity      : Entity,
+        event       : E,
+        propagation : EventPropagation,
+    ){
+        propagate_entity_event(self, entity, event, propagation);
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -223,12 +583,48 @@ pub trait ReactCommandsExt
     where
         S: IntoSystem<(), (), M> + Send + Sync + 'static;
 
+    /// Schedules a [`SystemCommand`] to be spawned from a system that can fail.
+    ///
+    /// Errors are passed to the [`ReactErrorHandler`]. See [`SystemCommandCallback::new_fallible`].
+    fn spawn_fallible_system_command<S, M, E>(&mut self, system: S) -> SystemCommand
+    where
+        S: IntoSystem<(), Result<(), E>, M> + Send + Sync + 'static,
+        E: Into<ReactError> + Send + Sync + 'static;
+
     /// Schedules a [`SystemCommand`] to be spawned from a pre-defined callback.
     ///
     /// Returns the system command id that will eventually reference the spawned system.
     /// To run the system, schedule it with `commands.queue(system_command)`.
     fn spawn_system_command_from(&mut self, callback: SystemCommandCallback) -> SystemCommand;
 
+    /// Schedules a [`SystemCommandWith`] to be spawned.
+    ///
+    /// Systems are not initialized until they are first run.
+    fn spawn_system_command_with<I, O, S, M>(&mut self, system: S) -> SystemCommandWith<I, O>
+    where
+        I: Send + Sync + 'static,
+        O: Send + Sync + 'static,
+        S: IntoSystem<In<I>, O, M> + Send + Sync + 'static;
+
+    /// Schedules a [`SystemCommandWith`] to run. The output is discarded.
+    fn run_system_command_with<I, O>(&mut self, command: SystemCommandWith<I, O>, input: I)
+    where
+        I: Send + Sync + 'static,
+        O: Send + Sync + 'static;
+
+    /// Schedules a [`SystemCommandWith`] to run, then passes its output to `continuation`.
+    ///
+    /// The continuation runs once as a system command right after `command`. It does not run if `command` doesn't
+    /// produce an output (see [`ReactWorldExt::run_system_command_with`]).
+    ///
+    /// Continuations that don't capture anything (e.g. function items) are cached by type, so their system is only
+    /// initialized once and keeps its `Local`s between runs. Capturing closures are initialized on every call.
+    fn run_system_command_then<I, O, S, M>(&mut self, command: SystemCommandWith<I, O>, input: I, continuation: S)
+    where
+        I: Send + Sync + 'static,
+        O: Send + Sync + 'static,
+        S: IntoSystem<In<O>, (), M> + Send + Sync + 'static;
+
     /// Schedules a system event targeting a given [`SystemCommand`].
     ///
     /// The target system can consume the event with the [`SystemEvent`] system parameter.
@@ -236,13 +632,37 @@ pub trait ReactCommandsExt
     /// If scheduled from user-land, this will cause a [`reaction_tree()`] to execute, otherwise it will be
     /// processed within the already-running reaction tree.
     fn send_system_event<T: Send + Sync + 'static>(&mut self, command: SystemCommand, event: T);
+
+    /// Schedules a [`SystemCommand`] to be registered in the [`SystemCommandRegistry`] with `name`.
+    ///
+    /// Logs an error if `name` is already registered.
+    fn register_named_command(&mut self, name: impl Into<SystemCommandName>, command: SystemCommand);
+
+    /// Schedules the [`SystemCommand`] registered with `name` to run.
+    ///
+    /// Logs a warning if `name` is not registered.
+    fn run_named_command(&mut self, name: impl Into<SystemCommandName>);
+
+    /// Schedules a system request targeting a given [`SystemCommand`], then passes the response to `callback`.
+    ///
+    /// The target system can take the request and respond to it with the [`SystemRequest`] system parameter.
+    /// The callback runs once as a system command right after the target system. It does not run if there is no
+    /// response (see [`ReactWorldExt::send_system_request`]).
+    ///
+    /// Like [`Self::run_system_command_then`], non-capturing callbacks are cached by type and capturing closures are
+    /// initialized on every call.
+    fn send_system_request<Req, Resp, S, M>(&mut self, command: SystemCommand, request: Req, callback: S)
+    where
+        Req: Send + Sync + 'static,
+        Resp: Send + Sync + 'static,
+        S: IntoSystem<In<Resp>, (), M> + Send + Sync + 'static;
 }
 
 impl<'w, 's> ReactCommandsExt for Commands<'w, 's>
 {
     fn react(&mut self) -> ReactCommands<'_, '_>
     {
-        ReactCommands{ commands: self.reborrow() }
+        ReactCommands{ commands: self.reborrow(), priority: None }
     }
 
     fn spawn_system_command<S, M>(&mut self, system: S) -> SystemCommand
@@ -252,15 +672,81 @@ impl<'w, 's> ReactCommandsExt for Commands<'w, 's>
         self.spawn_system_command_from(SystemCommandCallback::new(system))
     }
 
+    fn spawn_fallible_system_command<S, M, E>(&mut self, system: S) -> SystemCommand
+    where
+        S: IntoSystem<(), Result<(), E>, M> + Send + Sync + 'static,
+        E: Into<ReactError> + Send + Sync + 'static,
+    {
+        self.spawn_system_command_from(SystemCommandCallback::new_fallible(system))
+    }
+
     fn spawn_system_command_from(&mut self, callback: SystemCommandCallback) -> SystemCommand
     {
         SystemCommand(self.spawn(SystemCommandStorage::new(callback)).id())
     }
 
+    fn spawn_system_command_with<I, O, S, M>(&mut self, system: S) -> SystemCommandWith<I, O>
+    where
+        I: Send + Sync + 'static,
+        O: Send + Sync + 'static,
+        S: IntoSystem<In<I>, O, M> + Send + Sync + 'static
+    {
+        spawn_typed_system_command_deferred(self, system)
+    }
+
+    fn run_system_command_with<I, O>(&mut self, command: SystemCommandWith<I, O>, input: I)
+    where
+        I: Send + Sync + 'static,
+        O: Send + Sync + 'static
+    {
+        self.queue(move |world: &mut World| { run_typed_system_command(world, command, input); });
+    }
+
+    fn run_system_command_then<I, O, S, M>(&mut self, command: SystemCommandWith<I, O>, input: I, continuation: S)
+    where
+        I: Send + Sync + 'static,
+        O: Send + Sync + 'static,
+        S: IntoSystem<In<O>, (), M> + Send + Sync + 'static
+    {
+        self.queue(move |world: &mut World| run_typed_system_command_then(world, command, input, continuation));
+    }
+
     fn send_system_event<T: Send + Sync + 'static>(&mut self, command: SystemCommand, event: T)
     {
-        let data_entity = self.spawn(SystemEventData::new(event)).id();
-        self.queue(EventCommand{ system: command, data_entity });
+        self.queue(move |world: &mut World| world.send_system_event(command, event));
+    }
+
+    fn register_named_command(&mut self, name: impl Into<SystemCommandName>, command: SystemCommand)
+    {
+        let name = name.into();
+        self.queue(
+            move |world: &mut World|
+            {
+                let Err(existing) = register_named_command(world, name.clone(), command) else { return; };
+                tracing::error!(?name, ?existing, ?command, "failed registering duplicate named system command");
+            }
+        );
+    }
+
+    fn run_named_command(&mut self, name: impl Into<SystemCommandName>)
+    {
+        let name = name.into();
+        self.queue(move |world: &mut World| run_named_command(world, name));
+    }
+
+    fn send_system_request<Req, Resp, S, M>(&mut self, command: SystemCommand, request: Req, callback: S)
+    where
+        Req: Send + Sync + 'static,
+        Resp: Send + Sync + 'static,
+        S: IntoSystem<In<Resp>, (), M> + Send + Sync + 'static
+    {
+        self.queue(
+            move |world: &mut World|
+            {
+                let Some(response) = run_system_request(world, command, request) else { return; };
+                run_continuation(world, callback, response);
+            }
+        );
     }
 }
 
@@ -280,7 +766,7 @@ impl<'a> ReactEntityCommandsExt for EntityCommands<'a>
 {
     fn react(&mut self) -> ReactCommands<'_, '_>
     {
-        ReactCommands{ commands: self.commands() }
+        ReactCommands{ commands: self.commands(), priority: None }
     }
 
     fn add_world_reactor<T: EntityWorldReactor>(&mut self, data: T::Local)
@@ -5,43 +5,81 @@
 use crate as bevy_cobweb;
 
 //module tree
+mod auto_tracking;
+mod bevy_event_bridge;
+mod coalesced_reactions;
 mod command_queue;
 mod commands;
+mod computed;
 mod despawn_reader;
+mod entity_event_propagation;
 mod entity_reaction_readers;
 mod entity_world_reactor;
 mod event_readers;
 mod extensions;
+mod named_system_commands;
 mod plugin;
 mod react_cache;
+mod react_collections;
 mod react_commands;
 mod react_component;
+mod react_errors;
+mod react_input_replay;
+mod react_inspector;
 mod react_resource;
+mod react_tasks;
+mod react_timers;
+mod reaction_cascades;
+mod reaction_limits;
 mod reaction_trigger;
 mod reaction_triggers_impl;
+mod reactor_stats;
+#[cfg(feature = "bevy_state")]
+mod state_reactions;
 mod syscommand_runner;
 mod system_command_spawning;
 mod system_event_reader;
+mod system_request_reader;
+mod typed_system_commands;
 mod utils;
 mod world_reactor;
 
 //API exports
+pub(crate) use auto_tracking::*;
+pub(crate) use bevy_event_bridge::*;
+pub use coalesced_reactions::*;
 pub(crate) use command_queue::*;
 pub use commands::*;
+pub use computed::*;
 pub use despawn_reader::*;
+pub use entity_event_propagation::*;
 pub use entity_reaction_readers::*;
 pub use entity_world_reactor::*;
 pub use event_readers::*;
 pub use extensions::*;
+pub use named_system_commands::*;
 pub use plugin::*;
 pub(crate) use react_cache::*;
+pub use react_collections::*;
 pub use react_commands::*;
 pub use react_component::*;
+pub use react_errors::*;
+pub use react_input_replay::*;
+pub use react_inspector::*;
 pub use react_resource::*;
+pub use react_tasks::*;
+pub use react_timers::*;
+pub use reaction_cascades::*;
+pub use reaction_limits::*;
 pub use reaction_trigger::*;
 pub use reaction_triggers_impl::*;
+pub use reactor_stats::*;
+#[cfg(feature = "bevy_state")]
+pub use state_reactions::*;
 pub(crate) use syscommand_runner::*;
 pub use system_command_spawning::*;
 pub use system_event_reader::*;
+pub use system_request_reader::*;
+pub use typed_system_commands::*;
 pub use utils::*;
 pub use world_reactor::*;
@@ -0,0 +1,183 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::ecs::component::{ComponentHooks, ComponentId, StorageType};
+use bevy::ecs::world::DeferredWorld;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn on_remove_named_command(mut world: DeferredWorld, entity: Entity, _: ComponentId)
+{
+    let Some(mut named) = world.get_mut::<NamedSystemCommand>(entity) else { return; };
+    let names = std::mem::take(&mut named.names);
+    let Some(mut registry) = world.get_resource_mut::<SystemCommandRegistry>() else { return; };
+    for name in names
+    {
+        registry.remove_if_eq(&name, SystemCommand(entity));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records the names of a registered system command so they can be unregistered when it is despawned.
+struct NamedSystemCommand
+{
+    names: Vec<SystemCommandName>,
+}
+
+impl Component for NamedSystemCommand
+{
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks)
+    {
+        hooks.on_remove(on_remove_named_command);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The name of a system command in the [`SystemCommandRegistry`].
+///
+/// Can be made from strings (e.g. `"ui/refresh"`) or from a [`SysName`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SystemCommandName
+{
+    /// A string label.
+    Label(String),
+    /// A [`SysName`].
+    SysName(SysName),
+}
+
+impl From<&str> for SystemCommandName
+{
+    fn from(label: &str) -> Self
+    {
+        Self::Label(String::from(label))
+    }
+}
+
+impl From<String> for SystemCommandName
+{
+    fn from(label: String) -> Self
+    {
+        Self::Label(label)
+    }
+}
+
+impl From<SysName> for SystemCommandName
+{
+    fn from(sys_name: SysName) -> Self
+    {
+        Self::SysName(sys_name)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that maps [`SystemCommandNames`](SystemCommandName) to [`SystemCommands`](SystemCommand).
+///
+/// Commands are registered with [`ReactAppExt::add_named_command`], [`ReactAppExt::register_named_command`], or
+/// [`commands.register_named_command()`](ReactCommandsExt::register_named_command), and can be run with
+/// [`commands.run_named_command()`](ReactCommandsExt::run_named_command).
+///
+/// Names are unregistered automatically when their system command is despawned (e.g. when the last
+/// [`AutoDespawnSignal`] of a ref-counted system command is dropped).
+#[derive(Resource, Default, Debug)]
+pub struct SystemCommandRegistry
+{
+    commands: HashMap<SystemCommandName, SystemCommand>,
+}
+
+impl SystemCommandRegistry
+{
+    /// Gets the system command registered with `name`.
+    pub fn get(&self, name: impl Into<SystemCommandName>) -> Option<SystemCommand>
+    {
+        self.commands.get(&name.into()).copied()
+    }
+
+    /// Returns `true` if a system command is registered with `name`.
+    pub fn contains(&self, name: impl Into<SystemCommandName>) -> bool
+    {
+        self.commands.contains_key(&name.into())
+    }
+
+    /// Iterates over all registered names.
+    pub fn iter(&self) -> impl Iterator<Item = (&SystemCommandName, SystemCommand)> + '_
+    {
+        self.commands.iter().map(|(name, command)| (name, *command))
+    }
+
+    /// Unregisters `name`. The system command is not despawned.
+    ///
+    /// Returns the system command that was registered with `name`.
+    pub fn unregister(&mut self, name: impl Into<SystemCommandName>) -> Option<SystemCommand>
+    {
+        self.commands.remove(&name.into())
+    }
+
+    fn remove_if_eq(&mut self, name: &SystemCommandName, command: SystemCommand)
+    {
+        if self.commands.get(name) != Some(&command) { return; }
+        self.commands.remove(name);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Registers a system command in the [`SystemCommandRegistry`].
+///
+/// Returns the already-registered command if `name` is taken. Does nothing if the system command doesn't exist.
+pub(crate) fn register_named_command(
+    world   : &mut World,
+    name    : SystemCommandName,
+    command : SystemCommand,
+) -> Result<(), SystemCommand>
+{
+    let registry = world.get_resource_or_init::<SystemCommandRegistry>();
+    if let Some(existing) = registry.commands.get(&name)
+    {
+        return Err(*existing);
+    }
+
+    let Ok(mut entity_mut) = world.get_entity_mut(*command)
+    else
+    {
+        tracing::warn!(?name, ?command, "ignoring named command registration for missing system command");
+        return Ok(());
+    };
+    match entity_mut.get_mut::<NamedSystemCommand>()
+    {
+        Some(mut named) => named.names.push(name.clone()),
+        None => { entity_mut.insert(NamedSystemCommand{ names: vec![name.clone()] }); }
+    }
+    world.resource_mut::<SystemCommandRegistry>().commands.insert(name, command);
+
+    Ok(())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Runs the system command registered with `name`.
+pub(crate) fn run_named_command(world: &mut World, name: SystemCommandName)
+{
+    let Some(command) = world.get_resource::<SystemCommandRegistry>().and_then(|r| r.commands.get(&name).copied())
+    else
+    {
+        tracing::warn!(?name, "named system command is not registered");
+        return;
+    };
+    command.apply(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
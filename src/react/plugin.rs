@@ -19,6 +19,10 @@ pub(crate) struct SyscommandCounter(usize);
 
 /// Prepares the react framework so that reactors may be registered with [`ReactCommands`].
 /// - Un-handled removals and despawns will be automatically processed in `Last`.
+/// - Changes to plain components with change-detection reactors will be automatically processed in `Last`.
+/// - Expired timers will be automatically processed in `PreUpdate`.
+/// - Coalesced reactions will be automatically flushed in `Last`.
+/// - Woken async tasks will be automatically polled in `Last`.
 pub struct ReactPlugin;
 
 impl Plugin for ReactPlugin
@@ -35,8 +39,32 @@ impl Plugin for ReactPlugin
             .init_resource::<EntityReactionAccessTracker>()
             .init_resource::<EventAccessTracker>()
             .init_resource::<DespawnAccessTracker>()
+            .init_resource::<ReactTimers>()
+            .init_resource::<CoalescedReactions>()
+            .init_resource::<ReactReadTracker>()
+            .init_resource::<ReactTasks>()
+            .init_resource::<SystemCommandRegistry>()
+            .init_resource::<ContinuationCache>()
+            .init_resource::<ReactionLimits>()
+            .init_resource::<ReactionLoopGuard>()
+            .init_resource::<PendingReactionKind>()
+            .init_resource::<ReactErrorHandler>()
             .setup_auto_despawn()
-            .add_systems(Last, schedule_removal_and_despawn_reactors.after(AutoDespawnSet));
+            .add_systems(First, advance_react_timers)
+            .add_systems(PreUpdate, schedule_timer_reactors)
+            .add_systems(Last,
+                (
+                    schedule_change_reactors,
+                    schedule_removal_and_despawn_reactors,
+                    flush_coalesced_reactions,
+                    poll_react_tasks,
+                )
+                    .chain()
+                    .after(AutoDespawnSet)
+            );
+
+        #[cfg(feature = "bevy_state")]
+        app.add_systems(Last, install_state_bridges);
     }
 }
 
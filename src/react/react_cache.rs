@@ -5,9 +5,10 @@ use crate::prelude::*;
 use bevy::prelude::*;
 use bevy::utils::{HashMap, HashSet};
 use crossbeam::channel::{Receiver, Sender};
+use itertools::Itertools;
 
 //standard shortcuts
-use core::any::TypeId;
+use core::any::{Any, TypeId};
 use std::vec::Vec;
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -15,9 +16,11 @@ use std::vec::Vec;
 
 struct ComponentReactors
 {
-    insertion_callbacks : Vec<ReactorHandle>,
-    mutation_callbacks  : Vec<ReactorHandle>,
-    removal_callbacks   : Vec<ReactorHandle>,
+    insertion_callbacks : Vec<ReactorEntry>,
+    mutation_callbacks  : Vec<ReactorEntry>,
+    removal_callbacks   : Vec<ReactorEntry>,
+    added_callbacks     : Vec<ReactorEntry>,
+    changed_callbacks   : Vec<ReactorEntry>,
 }
 
 impl ComponentReactors
@@ -26,7 +29,9 @@ impl ComponentReactors
     {
         self.insertion_callbacks.is_empty() &&
         self.mutation_callbacks.is_empty()  &&
-        self.removal_callbacks.is_empty()
+        self.removal_callbacks.is_empty()   &&
+        self.added_callbacks.is_empty()     &&
+        self.changed_callbacks.is_empty()
     }
 }
 
@@ -38,6 +43,8 @@ impl Default for ComponentReactors
             insertion_callbacks : Vec::new(),
             mutation_callbacks  : Vec::new(),
             removal_callbacks   : Vec::new(),
+            added_callbacks     : Vec::new(),
+            changed_callbacks   : Vec::new(),
         }
     }
 }
@@ -62,10 +69,41 @@ fn collect_component_removals<C: ReactComponent>(
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Removed component values captured by the `React<C>` removal hook.
+struct RemovedValues<C: ReactComponent>
+{
+    values: Vec<(Entity, C)>,
+}
+
+/// Type-erased [`RemovedValues`] for all components with tracked removals.
+type RemovedValuesMap = HashMap<TypeId, Box<dyn Any + Send + Sync>>;
+
+/// Takes the oldest captured removal value of `C` for `entity`.
+///
+/// If `num_reactors` is non-zero, the value is moved to a new data entity shared by the reactors.
+/// The data entity is spawned with `commands` so the world is not flushed while the react cache is checked out.
+fn spawn_removed_value<C: ReactComponent>(
+    removed_values : &mut RemovedValuesMap,
+    commands       : &mut Commands,
+    entity         : Entity,
+    num_reactors   : usize,
+) -> Option<Entity>
+{
+    let removed = removed_values.get_mut(&TypeId::of::<C>())?.downcast_mut::<RemovedValues<C>>()?;
+    let pos = removed.values.iter().position(|(e, _)| *e == entity)?;
+    let (_, value) = removed.values.remove(pos);
+    if num_reactors == 0 { return None; }
+
+    Some(commands.spawn((DataEntityCounter::new(num_reactors), EntityReactionData::new(value))).id())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 struct RemovalChecker
 {
     component_id : TypeId,
-    checker      : SysCall<(), Vec<Entity>, Vec<Entity>>
+    checker      : SysCall<(), Vec<Entity>, Vec<Entity>>,
+    value_taker  : fn(&mut RemovedValuesMap, &mut Commands, Entity, usize) -> Option<Entity>,
 }
 
 impl RemovalChecker
@@ -75,6 +113,47 @@ impl RemovalChecker
         Self{
             component_id : TypeId::of::<C>(),
             checker      : SysCall::new(|world, buffer| syscall(world, buffer, collect_component_removals::<C>)),
+            value_taker  : spawn_removed_value::<C>,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Collects changes to a plain component and queues reactions to them.
+///
+/// If `prime` is true then no reactions will be queued. This is used to ignore changes that occurred before the
+/// component's changes were tracked.
+fn collect_component_changes<C: Component>(
+    In(prime)    : In<bool>,
+    mut cache    : ResMut<ReactCache>,
+    mut commands : Commands,
+    changed      : Query<(Entity, Ref<C>, Option<&EntityReactors>), Changed<C>>,
+){
+    if prime { return; }
+    let cache = &mut *cache;
+
+    for (entity, component, entity_reactors) in changed.iter()
+    {
+        let rtype = match component.is_added()
+        {
+            true  => EntityReactionType::Added(TypeId::of::<C>()),
+            false => EntityReactionType::Changed(TypeId::of::<C>()),
+        };
+        let data = Some(&*component as &dyn Any);
+
+        schedule_entity_reaction_impl(
+            &mut cache.reaction_commands_buffer,
+            entity,
+            rtype,
+            entity_reactors,
+            component_callbacks(&cache.component_reactors, rtype),
+            data
+        );
+
+        for command in cache.reaction_commands_buffer.drain(..) {
+            commands.queue(command);
         }
     }
 }
@@ -82,23 +161,87 @@ impl RemovalChecker
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Collects changes to a plain component and queues reactions to them.
+#[derive(Copy, Clone)]
+pub(crate) struct ChangeChecker
+{
+    checker: fn(&mut World),
+}
+
+impl ChangeChecker
+{
+    fn new<C: Component>() -> Self
+    {
+        Self{ checker: |world| syscall(world, false, collect_component_changes::<C>) }
+    }
+
+    pub(crate) fn run(&self, world: &mut World)
+    {
+        (self.checker)(world);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Gets the entity-agnostic reactors for a component reaction.
+fn component_callbacks(
+    component_reactors : &HashMap<TypeId, ComponentReactors>,
+    rtype              : EntityReactionType,
+) -> &[ReactorEntry]
+{
+    let (id, callbacks): (TypeId, fn(&ComponentReactors) -> &Vec<ReactorEntry>) = match rtype
+    {
+        EntityReactionType::Insertion(id) => (id, |r| &r.insertion_callbacks),
+        EntityReactionType::Mutation(id)  => (id, |r| &r.mutation_callbacks),
+        EntityReactionType::Removal(id)   => (id, |r| &r.removal_callbacks),
+        EntityReactionType::Added(id)     => (id, |r| &r.added_callbacks),
+        EntityReactionType::Changed(id)   => (id, |r| &r.changed_callbacks),
+        EntityReactionType::Event(_)      => return &[],
+    };
+    component_reactors.get(&id).map(|r| callbacks(r).as_slice()).unwrap_or_default()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Schedules reactions to an entity mutation.
+///
+/// Entity-specific and entity-agnostic reactors are merged in priority order. Reactors with trigger filters will only
+/// be scheduled if their filters allow `data`. Coalesced reactors are added to their coalesced batches.
 fn schedule_entity_reaction_impl(
-    buffer          : &mut Vec<ReactionCommand>,
-    reaction_source : Entity,
-    reaction_type   : EntityReactionType,
-    entity_reactors : &EntityReactors
+    buffer             : &mut Vec<ReactionCommand>,
+    reaction_source    : Entity,
+    reaction_type      : EntityReactionType,
+    entity_reactors    : Option<&EntityReactors>,
+    component_reactors : &[ReactorEntry],
+    data               : Option<&dyn Any>,
 ){
     if let EntityReactionType::Event(id) = reaction_type
     { tracing::error!(?id, "tried queuing entity event as entity reaction"); return; }
 
-    for reactor in entity_reactors.iter_rtype(reaction_type)
+    let entity_specific = entity_reactors.into_iter().flat_map(|r| r.iter_allowed(reaction_type, data));
+    let entity_agnostic = component_reactors.iter().filter(|e| e.allows(data));
+
+    for entry in entity_specific.merge_by(entity_agnostic, |a, b| a.priority() >= b.priority())
     {
+        if entry.is_coalesced()
+        {
+            buffer.push(
+                    ReactionCommand::Coalesce{
+                        reactor: entry.sys_command(),
+                        priority: entry.priority(),
+                        reaction: CoalescedReaction::Entity{ entity: reaction_source, rtype: reaction_type },
+                    }
+                );
+            continue;
+        }
+
         buffer.push(
                 ReactionCommand::EntityReaction{
                     reaction_source,
                     reaction_type,
-                    reactor,
+                    data_entity: None,
+                    reactor: entry.sys_command(),
                 }
             );
     }
@@ -112,6 +255,8 @@ pub(crate) struct ReactCache
 {
     /// Cached buffer for collecting reaction commands.
     reaction_commands_buffer: Vec<ReactionCommand>,
+    /// Cached buffer for collecting reactors that passed their trigger filters.
+    allowed_reactors_buffer: Vec<SystemCommand>,
 
     /// Per-component reactors
     component_reactors: HashMap<TypeId, ComponentReactors>,
@@ -122,6 +267,13 @@ pub(crate) struct ReactCache
     removal_checkers: Vec<RemovalChecker>,
     /// Removal checker buffer (cached for reuse)
     removal_buffer: Option<Vec<Entity>>,
+    /// Values of removed components, waiting for their removals to be detected
+    removed_values: RemovedValuesMap,
+
+    /// Plain components with change-detection reactors (cached to prevent duplicate insertion)
+    tracked_changes: HashSet<TypeId>,
+    /// Plain component change checkers (as a vec for efficient iteration)
+    change_checkers: Vec<ChangeChecker>,
 
     // Entity despawn reactors
     despawn_reactors: HashMap<Entity, Vec<ReactorHandle>>,
@@ -131,13 +283,18 @@ pub(crate) struct ReactCache
     despawn_receiver: Receiver<Entity>,
 
     /// Any entity event reactors
-    any_entity_event_reactors: HashMap<TypeId, Vec<ReactorHandle>>,
+    any_entity_event_reactors: HashMap<TypeId, Vec<ReactorEntry>>,
 
     /// Resource mutation reactors
-    resource_reactors: HashMap<TypeId, Vec<ReactorHandle>>,
+    resource_reactors: HashMap<TypeId, Vec<ReactorEntry>>,
 
     /// Broadcast event reactors
-    broadcast_reactors: HashMap<TypeId, Vec<ReactorHandle>>,
+    broadcast_reactors: HashMap<TypeId, Vec<ReactorEntry>>,
+    /// Callbacks that mirror broadcast events to Bevy `Events`
+    broadcast_mirrors: HashMap<TypeId, fn(&dyn Any, &mut Commands)>,
+
+    /// Names of the types referenced by reaction triggers, for introspection
+    type_names: HashMap<TypeId, &'static str>,
 }
 
 impl ReactCache
@@ -147,63 +304,212 @@ impl ReactCache
         self.despawn_sender.clone()
     }
 
+    /// Records the name of a type referenced by a reaction trigger and returns its id.
+    pub(crate) fn note_type<T: 'static>(&mut self) -> TypeId
+    {
+        let id = TypeId::of::<T>();
+        self.note_type_name(id, std::any::type_name::<T>());
+        id
+    }
+
+    /// Records the name of a type referenced by a reaction trigger.
+    pub(crate) fn note_type_name(&mut self, id: TypeId, name: &'static str)
+    {
+        self.type_names.entry(id).or_insert(name);
+    }
+
+    /// Gets the name of a type referenced by a reaction trigger.
+    pub(crate) fn type_name(&self, id: TypeId) -> Option<&'static str>
+    {
+        self.type_names.get(&id).copied()
+    }
+
+    /// Iterates components whose removals are tracked.
+    pub(crate) fn iter_tracked_removals(&self) -> impl Iterator<Item = TypeId> + '_
+    {
+        self.tracked_removals.iter().copied()
+    }
+
+    /// Iterates entity-agnostic reactors and resource/broadcast reactors.
+    pub(crate) fn iter_reactors(&self) -> impl Iterator<Item = (ReactorType, &ReactorEntry)> + '_
+    {
+        let component_reactors = self.component_reactors
+            .iter()
+            .flat_map(
+                |(id, reactors)|
+                {
+                    let id = *id;
+                    reactors.insertion_callbacks.iter().map(move |e| (ReactorType::ComponentInsertion(id), e))
+                        .chain(reactors.mutation_callbacks.iter().map(move |e| (ReactorType::ComponentMutation(id), e)))
+                        .chain(reactors.removal_callbacks.iter().map(move |e| (ReactorType::ComponentRemoval(id), e)))
+                        .chain(reactors.added_callbacks.iter().map(move |e| (ReactorType::ComponentAdded(id), e)))
+                        .chain(reactors.changed_callbacks.iter().map(move |e| (ReactorType::ComponentChanged(id), e)))
+                }
+            );
+        let any_entity_event_reactors = self.any_entity_event_reactors
+            .iter()
+            .flat_map(|(id, entries)| entries.iter().map(|e| (ReactorType::AnyEntityEvent(*id), e)));
+        let resource_reactors = self.resource_reactors
+            .iter()
+            .flat_map(|(id, entries)| entries.iter().map(|e| (ReactorType::ResourceMutation(*id), e)));
+        let broadcast_reactors = self.broadcast_reactors
+            .iter()
+            .flat_map(|(id, entries)| entries.iter().map(|e| (ReactorType::Broadcast(*id), e)));
+
+        component_reactors
+            .chain(any_entity_event_reactors)
+            .chain(resource_reactors)
+            .chain(broadcast_reactors)
+    }
+
+    /// Iterates despawn reactors.
+    pub(crate) fn iter_despawn_reactors(&self) -> impl Iterator<Item = (Entity, &ReactorHandle)> + '_
+    {
+        self.despawn_reactors
+            .iter()
+            .flat_map(|(entity, handles)| handles.iter().map(|h| (*entity, h)))
+    }
+
     pub(crate) fn track_removals<C: ReactComponent>(&mut self)
     {
         // track removals of this component if untracked
-        if self.tracked_removals.contains(&TypeId::of::<C>()) { return; };
-        self.tracked_removals.insert(TypeId::of::<C>());
+        let id = self.note_type::<C>();
+        if self.tracked_removals.contains(&id) { return; };
+        self.tracked_removals.insert(id);
         self.removal_checkers.push(RemovalChecker::new::<C>());
     }
 
-    pub(crate) fn register_insertion_reactor<C: ReactComponent>(&mut self, handle: ReactorHandle)
+    /// Returns `true` if removals of `C` are tracked.
+    pub(crate) fn is_tracking_removals<C: ReactComponent>(&self) -> bool
     {
-        self.component_reactors
-            .entry(TypeId::of::<C>())
-            .or_default()
-            .insertion_callbacks
-            .push(handle);
+        self.tracked_removals.contains(&TypeId::of::<C>())
     }
 
-    pub(crate) fn register_mutation_reactor<C: ReactComponent>(&mut self, handle: ReactorHandle)
+    /// Saves the value of a removed component so it can be read by removal reactors.
+    pub(crate) fn push_removed_value<C: ReactComponent>(&mut self, entity: Entity, value: C)
     {
-        self.component_reactors
+        let Some(removed) = self.removed_values
             .entry(TypeId::of::<C>())
+            .or_insert_with(|| Box::new(RemovedValues::<C>{ values: Vec::default() }))
+            .downcast_mut::<RemovedValues<C>>()
+        else { return; };
+        removed.values.push((entity, value));
+    }
+
+    /// Tracks changes to plain component `C`.
+    ///
+    /// Changes that occurred before `C` was tracked will be ignored.
+    pub(crate) fn track_changes<C: Component>(&mut self, commands: &mut Commands)
+    {
+        // track changes of this component if untracked
+        if self.tracked_changes.contains(&TypeId::of::<C>()) { return; };
+        self.tracked_changes.insert(TypeId::of::<C>());
+        self.change_checkers.push(ChangeChecker::new::<C>());
+
+        // prime the checker
+        commands.queue(|world: &mut World| syscall(world, true, collect_component_changes::<C>));
+    }
+
+    /// Takes the current change checkers so they can be run with world access.
+    pub(crate) fn take_change_checkers(&mut self) -> Vec<ChangeChecker>
+    {
+        std::mem::take(&mut self.change_checkers)
+    }
+
+    /// Returns change checkers taken with [`Self::take_change_checkers`].
+    ///
+    /// Checkers added while the checkers were taken are preserved.
+    pub(crate) fn return_change_checkers(&mut self, mut checkers: Vec<ChangeChecker>)
+    {
+        checkers.append(&mut self.change_checkers);
+        self.change_checkers = checkers;
+    }
+
+    pub(crate) fn register_insertion_reactor<C: ReactComponent>(&mut self, entry: ReactorEntry)
+    {
+        let id = self.note_type::<C>();
+        let entries = &mut self.component_reactors
+            .entry(id)
             .or_default()
-            .mutation_callbacks
-            .push(handle);
+            .insertion_callbacks;
+        insert_by_priority(entries, entry);
     }
 
-    pub(crate) fn register_removal_reactor<C: ReactComponent>(&mut self, handle: ReactorHandle)
+    pub(crate) fn register_mutation_reactor<C: ReactComponent>(&mut self, entry: ReactorEntry)
     {
-        self.component_reactors
-            .entry(TypeId::of::<C>())
+        let id = self.note_type::<C>();
+        let entries = &mut self.component_reactors
+            .entry(id)
             .or_default()
-            .removal_callbacks
-            .push(handle);
+            .mutation_callbacks;
+        insert_by_priority(entries, entry);
     }
 
-    pub(crate) fn register_any_entity_event_reactor<E: 'static>(&mut self, handle: ReactorHandle)
+    pub(crate) fn register_removal_reactor<C: ReactComponent>(&mut self, entry: ReactorEntry)
     {
-        self.any_entity_event_reactors
-            .entry(TypeId::of::<E>())
+        let id = self.note_type::<C>();
+        let entries = &mut self.component_reactors
+            .entry(id)
             .or_default()
-            .push(handle);
+            .removal_callbacks;
+        insert_by_priority(entries, entry);
     }
 
-    pub(crate) fn register_resource_mutation_reactor<R: ReactResource>(&mut self, handle: ReactorHandle)
+    pub(crate) fn register_added_reactor<C: Component>(&mut self, entry: ReactorEntry)
     {
-        self.resource_reactors
-            .entry(TypeId::of::<R>())
+        let id = self.note_type::<C>();
+        let entries = &mut self.component_reactors
+            .entry(id)
             .or_default()
-            .push(handle);
+            .added_callbacks;
+        insert_by_priority(entries, entry);
     }
 
-    pub(crate) fn register_broadcast_reactor<E: 'static>(&mut self, handle: ReactorHandle)
+    pub(crate) fn register_changed_reactor<C: Component>(&mut self, entry: ReactorEntry)
     {
-        self.broadcast_reactors
-            .entry(TypeId::of::<E>())
+        let id = self.note_type::<C>();
+        let entries = &mut self.component_reactors
+            .entry(id)
             .or_default()
-            .push(handle);
+            .changed_callbacks;
+        insert_by_priority(entries, entry);
+    }
+
+    pub(crate) fn register_any_entity_event_reactor<E: 'static>(&mut self, entry: ReactorEntry)
+    {
+        let id = self.note_type::<E>();
+        let entries = &mut self.any_entity_event_reactors
+            .entry(id)
+            .or_default();
+        insert_by_priority(entries, entry);
+    }
+
+    pub(crate) fn register_resource_mutation_reactor<R: ReactResource>(&mut self, entry: ReactorEntry)
+    {
+        let id = self.note_type::<R>();
+        self.register_resource_mutation_reactor_by_id(id, entry);
+    }
+
+    pub(crate) fn register_resource_mutation_reactor_by_id(&mut self, resource_id: TypeId, entry: ReactorEntry)
+    {
+        let entries = &mut self.resource_reactors
+            .entry(resource_id)
+            .or_default();
+        insert_by_priority(entries, entry);
+    }
+
+    pub(crate) fn register_broadcast_reactor<E: 'static>(&mut self, entry: ReactorEntry)
+    {
+        let id = self.note_type::<E>();
+        let entries = &mut self.broadcast_reactors
+            .entry(id)
+            .or_default();
+        insert_by_priority(entries, entry);
+    }
+
+    pub(crate) fn register_broadcast_mirror<E: Event + Clone>(&mut self)
+    {
+        self.broadcast_mirrors.insert(TypeId::of::<E>(), mirror_broadcast::<E>);
     }
 
     pub(crate) fn register_despawn_reactor(&mut self, entity: Entity, handle: ReactorHandle)
@@ -223,6 +529,8 @@ impl ReactCache
             EntityReactionType::Insertion(comp_id) => (comp_id, self.component_reactors.get_mut(&comp_id)),
             EntityReactionType::Mutation(comp_id)  => (comp_id, self.component_reactors.get_mut(&comp_id)),
             EntityReactionType::Removal(comp_id)   => (comp_id, self.component_reactors.get_mut(&comp_id)),
+            EntityReactionType::Added(comp_id)     => (comp_id, self.component_reactors.get_mut(&comp_id)),
+            EntityReactionType::Changed(comp_id)   => (comp_id, self.component_reactors.get_mut(&comp_id)),
             EntityReactionType::Event(_)           => unreachable!(),
         };
         let Some(reactors) = reactors else { return; };
@@ -231,13 +539,15 @@ impl ReactCache
             EntityReactionType::Insertion(_) => &mut reactors.insertion_callbacks,
             EntityReactionType::Mutation(_)  => &mut reactors.mutation_callbacks,
             EntityReactionType::Removal(_)   => &mut reactors.removal_callbacks,
+            EntityReactionType::Added(_)     => &mut reactors.added_callbacks,
+            EntityReactionType::Changed(_)   => &mut reactors.changed_callbacks,
             EntityReactionType::Event(_)     => unreachable!(),
         };
 
         // revoke reactor
-        for (idx, handle) in callbacks.iter().enumerate()
+        for (idx, entry) in callbacks.iter().enumerate()
         {
-            if handle.sys_command() != reactor_id { continue; }
+            if entry.sys_command() != reactor_id { continue; }
             let _ = callbacks.remove(idx);
 
             break;
@@ -255,9 +565,9 @@ impl ReactCache
         let Some(callbacks) = self.any_entity_event_reactors.get_mut(&event_id) else { return; };
 
         // revoke reactor
-        for (idx, handle) in callbacks.iter().enumerate()
+        for (idx, entry) in callbacks.iter().enumerate()
         {
-            if handle.sys_command() != reactor_id { continue; }
+            if entry.sys_command() != reactor_id { continue; }
             let _ = callbacks.remove(idx);
             break;
         }
@@ -274,9 +584,9 @@ impl ReactCache
         let Some(callbacks) = self.resource_reactors.get_mut(&resource_id) else { return; };
 
         // revoke reactor
-        for (idx, handle) in callbacks.iter().enumerate()
+        for (idx, entry) in callbacks.iter().enumerate()
         {
-            if handle.sys_command() != reactor_id { continue; }
+            if entry.sys_command() != reactor_id { continue; }
             let _ = callbacks.remove(idx);
             break;
         }
@@ -293,9 +603,9 @@ impl ReactCache
         let Some(callbacks) = self.broadcast_reactors.get_mut(&event_id) else { return; };
 
         // revoke reactor
-        for (idx, handle) in callbacks.iter().enumerate()
+        for (idx, entry) in callbacks.iter().enumerate()
         {
-            if handle.sys_command() != reactor_id { continue; }
+            if entry.sys_command() != reactor_id { continue; }
             let _ = callbacks.remove(idx);
             break;
         }
@@ -330,67 +640,81 @@ impl ReactCache
         mut cache       : ResMut<ReactCache>,
         mut commands    : Commands,
         entity_reactors : Query<&EntityReactors>,
+        components      : Query<&React<C>>,
+        mut recording   : ReactInputRecording,
     ){
+        let cache = &mut *cache;
         let rtype = EntityReactionType::Insertion(TypeId::of::<C>());
-
-        // entity-specific reactors
-        if let Ok(entity_reactors) = entity_reactors.get(entity)
+        let data = components.get(entity).ok().map(|c| c.get() as &dyn Any);
+        if let Ok(component) = components.get(entity)
         {
-            let _ = schedule_entity_reaction_impl(&mut cache.reaction_commands_buffer, entity, rtype, &entity_reactors);
+            recording.record(ReactInputKind::Insertion, Some(entity), component.get());
         }
 
+        schedule_entity_reaction_impl(
+            &mut cache.reaction_commands_buffer,
+            entity,
+            rtype,
+            entity_reactors.get(entity).ok(),
+            component_callbacks(&cache.component_reactors, rtype),
+            data
+        );
+
         for command in cache.reaction_commands_buffer.drain(..) {
             commands.queue(command);
         }
-
-        // entity-agnostic component reactors
-        if let Some(handlers) = cache.component_reactors.get(&TypeId::of::<C>())
-        {
-            for handle in handlers.insertion_callbacks.iter()
-            {
-                commands.queue(
-                        ReactionCommand::EntityReaction{
-                            reaction_source : entity,
-                            reaction_type   : rtype,
-                            reactor         : handle.sys_command(),
-                        }
-                    );
-            }
-        }
     }
 
     /// Queues reactions to a component mutation on an entity.
+    ///
+    /// If `previous` is set, it will be stored on a data entity shared by all reactors of the mutation.
     pub(crate) fn schedule_mutation_reaction<C: ReactComponent>(
-        In(entity)      : In<Entity>,
-        mut cache       : ResMut<ReactCache>,
-        mut commands    : Commands,
-        entity_reactors : Query<&EntityReactors>,
+        In((entity, previous)) : In<(Entity, Option<C>)>,
+        mut cache              : ResMut<ReactCache>,
+        mut commands           : Commands,
+        entity_reactors        : Query<&EntityReactors>,
+        components             : Query<&React<C>>,
+        mut recording          : ReactInputRecording,
     ){
+        let cache = &mut *cache;
         let rtype = EntityReactionType::Mutation(TypeId::of::<C>());
-
-        // entity-specific reactors
-        if let Ok(entity_reactors) = entity_reactors.get(entity)
+        let data = components.get(entity).ok().map(|c| c.get() as &dyn Any);
+        if let Ok(component) = components.get(entity)
         {
-            let _ = schedule_entity_reaction_impl(&mut cache.reaction_commands_buffer, entity, rtype, &entity_reactors);
+            recording.record(ReactInputKind::Mutation, Some(entity), component.get());
         }
 
-        for command in cache.reaction_commands_buffer.drain(..) {
-            commands.queue(command);
-        }
+        schedule_entity_reaction_impl(
+            &mut cache.reaction_commands_buffer,
+            entity,
+            rtype,
+            entity_reactors.get(entity).ok(),
+            component_callbacks(&cache.component_reactors, rtype),
+            data
+        );
+
+        // prep previous value
+        // - Coalesced reactors don't read the previous value.
+        let num = cache.reaction_commands_buffer
+            .iter()
+            .filter(|c| matches!(c, ReactionCommand::EntityReaction{ .. }))
+            .count();
+        let previous_entity = match previous
+        {
+            Some(previous) if num > 0 =>
+            {
+                Some(commands.spawn((DataEntityCounter::new(num), EntityReactionData::new(previous))).id())
+            }
+            _ => None,
+        };
 
-        // entity-agnostic component reactors
-        if let Some(handlers) = cache.component_reactors.get(&TypeId::of::<C>())
+        for mut command in cache.reaction_commands_buffer.drain(..)
         {
-            for handle in handlers.mutation_callbacks.iter()
+            if let ReactionCommand::EntityReaction{ data_entity, .. } = &mut command
             {
-                commands.queue(
-                        ReactionCommand::EntityReaction{
-                            reaction_source : entity,
-                            reaction_type   : rtype,
-                            reactor         : handle.sys_command(),
-                        }
-                    );
+                *data_entity = previous_entity;
             }
+            commands.queue(command);
         }
     }
 
@@ -412,66 +736,94 @@ impl ReactCache
             let rtype = EntityReactionType::Removal(checker.component_id);
             for entity in buffer.iter()
             {
-                // entity-specific component reactors
-                if let Some(entity_reactors) = world.get_mut::<EntityReactors>(*entity)
-                {
-                    schedule_entity_reaction_impl(
-                            &mut commands_buff,
-                            *entity,
-                            rtype,
-                            &entity_reactors
-                        );
-                }
+                schedule_entity_reaction_impl(
+                        &mut commands_buff,
+                        *entity,
+                        rtype,
+                        world.get::<EntityReactors>(*entity),
+                        component_callbacks(&self.component_reactors, rtype),
+                        None
+                    );
 
-                // Need to do this in a separate step due to borrow checker on world mut access.
-                for command in commands_buff.drain(..) {
-                    world.commands().queue(command);
-                }
+                // prep removed value
+                // - The data entity is queued ahead of the reactions that read it.
+                let mut commands = world.commands();
+                let value_entity =
+                    (checker.value_taker)(&mut self.removed_values, &mut commands, *entity, commands_buff.len());
 
-                // entity-agnostic component reactors
-                let Some(reactors) = self.component_reactors.get(&checker.component_id) else { continue; };
-                for handle in reactors.removal_callbacks.iter()
+                // Need to do this in a separate step due to borrow checker on world mut access.
+                for mut command in commands_buff.drain(..)
                 {
-                    world.commands().queue(
-                            ReactionCommand::EntityReaction{
-                                reaction_source : *entity,
-                                reaction_type   : rtype,
-                                reactor         : handle.sys_command(),
-                            }
-                        );
+                    if let ReactionCommand::EntityReaction{ data_entity, .. } = &mut command
+                    {
+                        *data_entity = value_entity;
+                    }
+                    commands.queue(command);
                 }
             }
         }
 
+        // discard values of removals that were not detected
+        // - All captured removals should be detected above, this just guards against stale values piling up.
+        self.removed_values.clear();
+
         // return cached
         self.removal_buffer = Some(buffer);
         self.reaction_commands_buffer = commands_buff;
     }
 
+    /// Collects reactors to entity event `E` whose trigger filters allow `data`.
+    ///
+    /// `entity_reactors` should be the reactors of the entity the event is visiting. Entity-agnostic reactors (see
+    /// [`any_entity_event`]) are only collected if `include_any` is true.
+    pub(crate) fn collect_entity_event_reactors<E: Send + Sync + 'static>(
+        &self,
+        entity_reactors : Option<&EntityReactors>,
+        data            : Option<&dyn Any>,
+        include_any     : bool,
+        buffer          : &mut Vec<SystemCommand>,
+    ){
+        let reaction_type = EntityReactionType::Event(TypeId::of::<E>());
+        let entity_specific = entity_reactors.into_iter().flat_map(|r| r.iter_allowed(reaction_type, data));
+        let entity_agnostic = self.any_entity_event_reactors
+            .get(&TypeId::of::<E>())
+            .filter(|_| include_any)
+            .map(|h| h.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .filter(|e| e.allows(data));
+
+        buffer.extend(
+            entity_specific
+                .merge_by(entity_agnostic, |a, b| a.priority() >= b.priority())
+                .map(|e| e.sys_command())
+        );
+    }
+
     /// Queues reactions to an entity event.
     pub(crate) fn schedule_entity_event_reaction<E: Send + Sync + 'static>(
         In((target, event)) : In<(Entity, E)>,
         mut commands        : Commands,
-        cache               : Res<ReactCache>,
+        mut cache           : ResMut<ReactCache>,
         entity_reactors     : Query<&EntityReactors>,
+        mut recording       : ReactInputRecording,
     ){
-        // get reactors
-        let entity_reactors = entity_reactors.get(target);
-        let handlers = cache.any_entity_event_reactors.get(&TypeId::of::<E>());
-
-        // if there are no handlers, just drop the event data
-        let reaction_type = EntityReactionType::Event(TypeId::of::<E>());
-        let num = entity_reactors.map(|e| e.count(reaction_type)).unwrap_or_default()
-            + handlers.map(|h| h.len()).unwrap_or_default();
-        if num == 0 { return; }
+        recording.record(ReactInputKind::EntityEvent, Some(target), &event);
 
-        // prep entity data
-        let data_entity = commands.spawn((DataEntityCounter::new(num), EntityEventData::new(target, event))).id();
+        // collect reactors that accept the event
+        let mut buffer = std::mem::take(&mut cache.allowed_reactors_buffer);
+        buffer.clear();
+        cache.collect_entity_event_reactors::<E>(entity_reactors.get(target).ok(), Some(&event), true, &mut buffer);
 
-        // entity-specific reactors
-        if let Ok(entity_reactors) = entity_reactors
+        // if there are no handlers, just drop the event data
+        let num = buffer.len();
+        if num > 0
         {
-            for reactor in entity_reactors.iter_rtype(reaction_type)
+            // prep entity data
+            let data_entity = commands.spawn((DataEntityCounter::new(num), EntityEventData::new(target, event))).id();
+
+            // queue reactors
+            for reactor in buffer.drain(..)
             {
                 commands.queue(
                         ReactionCommand::EntityEvent{
@@ -483,21 +835,7 @@ impl ReactCache
             }
         }
 
-        // Entity-agnostic reactors
-        if let Some(handlers) = cache.any_entity_event_reactors.get(&TypeId::of::<E>())
-        {
-            // queue reactors
-            for handle in handlers.iter()
-            {
-                commands.queue(
-                    ReactionCommand::EntityEvent{
-                        target,
-                        data_entity,
-                        reactor: handle.sys_command(),
-                    }
-                );
-            }
-        }
+        cache.allowed_reactors_buffer = buffer;
     }
 
     /// Queues reactions to tracked despawns.
@@ -522,41 +860,103 @@ impl ReactCache
     }
 
     /// Queues reactions to a resource mutation.
+    ///
+    /// If `previous` is set, it will be stored on a data entity shared by all reactors of the mutation.
     pub(crate) fn schedule_resource_mutation_reaction<R: ReactResource>(
-        cache        : Res<ReactCache>,
+        In(previous) : In<Option<R>>,
+        mut cache    : ResMut<ReactCache>,
         mut commands : Commands,
+        resource     : Option<Res<ReactResInner<R>>>,
     ){
+        let cache = &mut *cache;
         let Some(handlers) = cache.resource_reactors.get(&TypeId::of::<R>()) else { return; };
+        let data = resource.as_ref().map(|r| &***r as &dyn Any);
+
+        // collect reactors that accept the mutation
+        // - Coalesced reactors are added to their coalesced batches.
+        cache.allowed_reactors_buffer.clear();
+        for entry in handlers.iter().filter(|e| e.allows(data))
+        {
+            if entry.is_coalesced()
+            {
+                commands.queue(
+                    ReactionCommand::Coalesce{
+                        reactor: entry.sys_command(),
+                        priority: entry.priority(),
+                        reaction: CoalescedReaction::Resource(TypeId::of::<R>()),
+                    }
+                );
+                continue;
+            }
+            cache.allowed_reactors_buffer.push(entry.sys_command());
+        }
+
+        // prep previous value
+        let num = cache.allowed_reactors_buffer.len();
+        if num == 0 { return; }
+        let data_entity = previous.map(
+            |previous| commands.spawn((DataEntityCounter::new(num), ResourceMutationData::new(previous))).id()
+        );
 
         // queue reactors
-        for handle in handlers.iter()
+        for reactor in cache.allowed_reactors_buffer.drain(..)
         {
             commands.queue(
-                ReactionCommand::Resource{ reactor: handle.sys_command() }
+                ReactionCommand::Resource{ data_entity, reactor }
             );
         }
     }
 
     /// Queues reactions to a broadcasted event.
     pub(crate) fn schedule_broadcast_reaction<E: Send + Sync + 'static>(
+        In(event)     : In<E>,
+        mut cache     : ResMut<ReactCache>,
+        mut commands  : Commands,
+        mut recording : ReactInputRecording,
+    ){
+        recording.record(ReactInputKind::Broadcast, None, &event);
+
+        // mirror to bevy events
+        if let Some(mirror) = cache.broadcast_mirrors.get(&TypeId::of::<E>())
+        {
+            (mirror)(&event, &mut commands);
+        }
+
+        cache.schedule_broadcast_reaction_impl(event, &mut commands);
+    }
+
+    /// Queues reactions to a broadcasted event that was forwarded from Bevy `Events`.
+    ///
+    /// The event is not mirrored back to Bevy `Events`.
+    pub(crate) fn schedule_bridged_broadcast_reaction<E: Send + Sync + 'static>(
         In(event)    : In<E>,
-        cache        : Res<ReactCache>,
+        mut cache    : ResMut<ReactCache>,
         mut commands : Commands,
     ){
-        let Some(handlers) = cache.broadcast_reactors.get(&TypeId::of::<E>()) else { return; };
+        cache.schedule_broadcast_reaction_impl(event, &mut commands);
+    }
+
+    fn schedule_broadcast_reaction_impl<E: Send + Sync + 'static>(&mut self, event: E, commands: &mut Commands)
+    {
+        let Some(handlers) = self.broadcast_reactors.get(&TypeId::of::<E>()) else { return; };
+
+        // collect reactors that accept the event
+        let data = Some(&event as &dyn Any);
+        self.allowed_reactors_buffer.clear();
+        self.allowed_reactors_buffer.extend(handlers.iter().filter(|e| e.allows(data)).map(|e| e.sys_command()));
 
         // if there are no handlers, just drop the event data
-        let num = handlers.len();
+        let num = self.allowed_reactors_buffer.len();
         if num == 0 { return; }
 
         // prep event data
         let data_entity = commands.spawn((DataEntityCounter::new(num), BroadcastEventData::new(event))).id();
 
         // queue reactors
-        for handle in handlers.iter()
+        for reactor in self.allowed_reactors_buffer.drain(..)
         {
             commands.queue(
-                ReactionCommand::BroadcastEvent{ data_entity, reactor: handle.sys_command() }
+                ReactionCommand::BroadcastEvent{ data_entity, reactor }
             );
         }
     }
@@ -571,16 +971,22 @@ impl Default for ReactCache
 
         Self{
             reaction_commands_buffer : Vec::default(),
+            allowed_reactors_buffer  : Vec::default(),
             component_reactors    : HashMap::default(),
             tracked_removals      : HashSet::default(),
             removal_checkers      : Vec::new(),
             removal_buffer        : None,
+            removed_values        : HashMap::default(),
+            tracked_changes       : HashSet::default(),
+            change_checkers       : Vec::new(),
             despawn_reactors      : HashMap::new(),
             despawn_sender,
             despawn_receiver,
             any_entity_event_reactors : HashMap::new(),
             resource_reactors         : HashMap::new(),
             broadcast_reactors        : HashMap::new(),
+            broadcast_mirrors         : HashMap::new(),
+            type_names                : HashMap::new(),
         }
     }
 }
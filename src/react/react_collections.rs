@@ -0,0 +1,633 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+//standard shortcuts
+use core::hash::Hash;
+use core::any::type_name;
+use core::ops::Deref;
+use std::marker::PhantomData;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Trait for reactive collections that send element-level reactions.
+///
+/// See [`ReactVec`] and [`ReactMap`].
+pub trait ReactCollection: ReactResource + ReactComponent
+{
+    /// The key used to identify elements (e.g. the index of a [`ReactVec`] element).
+    type Key: Clone + PartialEq + Send + Sync + 'static;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The kind of change that occurred to elements of a [`ReactCollection`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ElementChange
+{
+    /// Elements were inserted.
+    Inserted,
+    /// Elements were removed.
+    Removed,
+    /// Elements were mutated in place.
+    Changed,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Broadcast event sent when elements of a [`ReactCollection`] change.
+///
+/// Use [`ElementEvent`] to read these events in reactors of element triggers.
+pub struct CollectionEvent<Col: ReactCollection>
+{
+    /// The entity that owns the collection, or `None` if the collection is a resource.
+    owner: Option<Entity>,
+    change: ElementChange,
+    keys: Vec<Col::Key>,
+}
+
+impl<Col: ReactCollection> CollectionEvent<Col>
+{
+    /// Gets the entity that owns the collection, or `None` if the collection is a resource.
+    pub fn owner(&self) -> Option<Entity>
+    {
+        self.owner
+    }
+
+    /// Gets the kind of change.
+    pub fn change(&self) -> ElementChange
+    {
+        self.change
+    }
+
+    /// Gets the keys of the affected elements.
+    pub fn keys(&self) -> &[Col::Key]
+    {
+        &self.keys
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Broadcasts a [`CollectionEvent`].
+///
+/// If the collection has an owner, the event is also sent to the owner as an entity event.
+fn send_collection_event<Col: ReactCollection>(
+    c      : &mut Commands,
+    owner  : Option<Entity>,
+    change : ElementChange,
+    keys   : Vec<Col::Key>,
+){
+    if keys.is_empty() { return; }
+    if let Some(owner) = owner
+    {
+        c.react().entity_event(owner, CollectionEvent::<Col>{ owner: Some(owner), change, keys: keys.clone() });
+    }
+    c.react().broadcast(CollectionEvent::<Col>{ owner, change, keys });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reactive vector that sends element-level reactions.
+///
+/// Can be used as a [`ReactResource`] or as a [`ReactComponent`]. Mutating the vector through its own methods triggers
+/// [`element_inserted`], [`element_removed`], and [`element_changed`] reactions with the indices of the affected
+/// elements. Use `get_noreact` on [`ReactResMut`], [`React`], or [`ReactiveMut`] to access the vector
+/// without also triggering whole resource or component mutation reactions.
+///
+/// Indices refer to positions at the time of each change. For example, removing an element shifts the indices of
+/// later elements without sending reactions for them.
+///
+/*
+```rust
+fn setup(mut c: Commands)
+{
+    c.insert_react_resource(ReactVec::<u32>::default());
+    c.react().on(element_inserted::<ReactVec<u32>>(),
+        |event: ElementEvent<ReactVec<u32>>, list: ReactRes<ReactVec<u32>>|
+        {
+            for index in event.keys()
+            {
+                println!("inserted {} at {}", list[*index], index);
+            }
+        }
+    );
+}
+
+fn add_value(mut c: Commands, mut list: ReactResMut<ReactVec<u32>>)
+{
+    list.get_noreact().push(&mut c, 42);
+}
+```
+*/
+pub struct ReactVec<T: Send + Sync + 'static>
+{
+    owner: Option<Entity>,
+    values: Vec<T>,
+}
+
+impl<T: Send + Sync + 'static> ReactVec<T>
+{
+    /// Makes a new empty vector.
+    pub fn new() -> Self
+    {
+        Self{ owner: None, values: Vec::new() }
+    }
+
+    fn send(&self, c: &mut Commands, change: ElementChange, keys: Vec<usize>)
+    {
+        send_collection_event::<Self>(c, self.owner, change, keys);
+    }
+
+    /// Appends an element and triggers [`element_inserted`] reactions.
+    pub fn push(&mut self, c: &mut Commands, value: T)
+    {
+        self.values.push(value);
+        self.send(c, ElementChange::Inserted, vec![self.values.len() - 1]);
+    }
+
+    /// Appends elements and triggers one [`element_inserted`] reaction for all of them.
+    pub fn extend(&mut self, c: &mut Commands, values: impl IntoIterator<Item = T>)
+    {
+        let start = self.values.len();
+        self.values.extend(values);
+        self.send(c, ElementChange::Inserted, (start..self.values.len()).collect());
+    }
+
+    /// Inserts an element at `index` and triggers [`element_inserted`] reactions.
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, c: &mut Commands, index: usize, value: T)
+    {
+        self.values.insert(index, value);
+        self.send(c, ElementChange::Inserted, vec![index]);
+    }
+
+    /// Removes the last element and triggers [`element_removed`] reactions if there was one.
+    pub fn pop(&mut self, c: &mut Commands) -> Option<T>
+    {
+        let value = self.values.pop()?;
+        self.send(c, ElementChange::Removed, vec![self.values.len()]);
+        Some(value)
+    }
+
+    /// Removes the element at `index` and triggers [`element_removed`] reactions.
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, c: &mut Commands, index: usize) -> T
+    {
+        let value = self.values.remove(index);
+        self.send(c, ElementChange::Removed, vec![index]);
+        value
+    }
+
+    /// Removes all elements and triggers one [`element_removed`] reaction for all of them.
+    pub fn clear(&mut self, c: &mut Commands)
+    {
+        let len = self.values.len();
+        self.values.clear();
+        self.send(c, ElementChange::Removed, (0..len).collect());
+    }
+
+    /// Mutably accesses the element at `index` and triggers [`element_changed`] reactions if it exists.
+    pub fn get_mut(&mut self, c: &mut Commands, index: usize) -> Option<&mut T>
+    {
+        if index >= self.values.len() { return None; }
+        self.send(c, ElementChange::Changed, vec![index]);
+        self.values.get_mut(index)
+    }
+
+    /// Mutably accesses the element at `index` without triggering reactions.
+    pub fn get_noreact(&mut self, index: usize) -> Option<&mut T>
+    {
+        self.values.get_mut(index)
+    }
+}
+
+impl<T: Send + Sync + 'static> Default for ReactVec<T>
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<T: Send + Sync + 'static> From<Vec<T>> for ReactVec<T>
+{
+    fn from(values: Vec<T>) -> Self
+    {
+        Self{ owner: None, values }
+    }
+}
+
+impl<T: Send + Sync + 'static> Deref for ReactVec<T>
+{
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Vec<T>
+    {
+        &self.values
+    }
+}
+
+impl<T: Send + Sync + 'static> ReactResource for ReactVec<T> {}
+
+impl<T: Send + Sync + 'static> ReactComponent for ReactVec<T>
+{
+    fn set_react_owner(&mut self, owner: ReactOwner)
+    {
+        self.owner = owner.0;
+    }
+}
+
+impl<T: Send + Sync + 'static> ReactCollection for ReactVec<T>
+{
+    type Key = usize;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reactive map that sends element-level reactions.
+///
+/// Can be used as a [`ReactResource`] or as a [`ReactComponent`]. Mutating the map through its own methods triggers
+/// [`element_inserted`], [`element_removed`], and [`element_changed`] reactions with the keys of the affected
+/// elements. Use `get_noreact` on [`ReactResMut`], [`React`], or [`ReactiveMut`] to access the map
+/// without also triggering whole resource or component mutation reactions.
+pub struct ReactMap<K, V>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+    V: Send + Sync + 'static
+{
+    owner: Option<Entity>,
+    values: HashMap<K, V>,
+}
+
+impl<K, V> ReactMap<K, V>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+    V: Send + Sync + 'static
+{
+    /// Makes a new empty map.
+    pub fn new() -> Self
+    {
+        Self{ owner: None, values: HashMap::default() }
+    }
+
+    fn send(&self, c: &mut Commands, change: ElementChange, keys: Vec<K>)
+    {
+        send_collection_event::<Self>(c, self.owner, change, keys);
+    }
+
+    /// Inserts an element.
+    ///
+    /// Triggers [`element_changed`] reactions if the key was already present, or [`element_inserted`] reactions
+    /// otherwise. Returns the previous value.
+    pub fn insert(&mut self, c: &mut Commands, key: K, value: V) -> Option<V>
+    {
+        let previous = self.values.insert(key.clone(), value);
+        let change = match previous.is_some()
+        {
+            true  => ElementChange::Changed,
+            false => ElementChange::Inserted,
+        };
+        self.send(c, change, vec![key]);
+        previous
+    }
+
+    /// Removes an element and triggers [`element_removed`] reactions if it was present.
+    pub fn remove(&mut self, c: &mut Commands, key: &K) -> Option<V>
+    {
+        let (key, value) = self.values.remove_entry(key)?;
+        self.send(c, ElementChange::Removed, vec![key]);
+        Some(value)
+    }
+
+    /// Removes all elements and triggers one [`element_removed`] reaction for all of them.
+    pub fn clear(&mut self, c: &mut Commands)
+    {
+        let keys = self.values.drain().map(|(key, _)| key).collect();
+        self.send(c, ElementChange::Removed, keys);
+    }
+
+    /// Mutably accesses an element and triggers [`element_changed`] reactions if it exists.
+    pub fn get_mut(&mut self, c: &mut Commands, key: &K) -> Option<&mut V>
+    {
+        if !self.values.contains_key(key) { return None; }
+        self.send(c, ElementChange::Changed, vec![key.clone()]);
+        self.values.get_mut(key)
+    }
+
+    /// Mutably accesses an element without triggering reactions.
+    pub fn get_noreact(&mut self, key: &K) -> Option<&mut V>
+    {
+        self.values.get_mut(key)
+    }
+}
+
+impl<K, V> Default for ReactMap<K, V>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+    V: Send + Sync + 'static
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<K, V> Deref for ReactMap<K, V>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+    V: Send + Sync + 'static
+{
+    type Target = HashMap<K, V>;
+
+    fn deref(&self) -> &HashMap<K, V>
+    {
+        &self.values
+    }
+}
+
+impl<K, V> ReactResource for ReactMap<K, V>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+    V: Send + Sync + 'static
+{}
+
+impl<K, V> ReactComponent for ReactMap<K, V>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+    V: Send + Sync + 'static
+{
+    fn set_react_owner(&mut self, owner: ReactOwner)
+    {
+        self.owner = owner.0;
+    }
+}
+
+impl<K, V> ReactCollection for ReactMap<K, V>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+    V: Send + Sync + 'static
+{
+    type Key = K;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter for reading element changes in reactors of element triggers.
+///
+/// Can only be used within [`SystemCommands`](super::SystemCommand).
+///
+/// Use [`element_inserted`], [`element_removed`], [`element_changed`], or [`any_element_changed`] (or their `resource_`
+/// and `entity_` variants) to make a trigger that will read these events.
+#[derive(SystemParam)]
+pub struct ElementEvent<'w, 's, Col: ReactCollection>
+{
+    event: BroadcastEvent<'w, 's, CollectionEvent<Col>>,
+    entity_event: EntityEvent<'w, 's, CollectionEvent<Col>>,
+}
+
+impl<'w, 's, Col: ReactCollection> ElementEvent<'w, 's, Col>
+{
+    /// Reads the collection event.
+    ///
+    /// Panics if there is no event.
+    pub fn read(&self) -> &CollectionEvent<Col>
+    {
+        self.try_read()
+            .unwrap_or_else(|| panic!("failed reading element event for {}, there is no event", type_name::<Col>()))
+    }
+
+    /// See [`Self::read`].
+    pub fn try_read(&self) -> Option<&CollectionEvent<Col>>
+    {
+        self.event
+            .try_read()
+            .or_else(|| self.entity_event.try_read().map(|(_, event)| event))
+    }
+
+    /// Gets the keys of the affected elements.
+    ///
+    /// Returns an empty slice if there is no event.
+    pub fn keys(&self) -> &[Col::Key]
+    {
+        self.try_read().map(CollectionEvent::keys).unwrap_or_default()
+    }
+
+    /// Gets the entity that owns the collection, or `None` if the collection is a resource or there is no event.
+    pub fn owner(&self) -> Option<Entity>
+    {
+        self.try_read().and_then(CollectionEvent::owner)
+    }
+
+    /// Returns `true` if there is nothing to read.
+    pub fn is_empty(&self) -> bool
+    {
+        self.try_read().is_none()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Which collections an element trigger reacts to.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum ElementScope
+{
+    /// Collections in resources and on all entities.
+    Any,
+    /// Collections in resources.
+    Resource,
+    /// The collection on a specific entity.
+    Entity(Entity),
+}
+
+impl ElementScope
+{
+    fn reactor_type<Col: ReactCollection>(&self) -> ReactorType
+    {
+        match *self
+        {
+            Self::Any | Self::Resource => broadcast::<CollectionEvent<Col>>().reactor_type(),
+            Self::Entity(entity)       => entity_event::<CollectionEvent<Col>>(entity).reactor_type(),
+        }
+    }
+
+    fn register<Col: ReactCollection>(
+        &self,
+        commands : &mut Commands,
+        handle   : &ReactorHandle,
+        filter   : impl Fn(&CollectionEvent<Col>) -> bool + Send + Sync + 'static
+    ){
+        match *self
+        {
+            Self::Any =>
+            {
+                broadcast::<CollectionEvent<Col>>().register_filtered(commands, handle, TriggerFilter::new(filter));
+            }
+            Self::Resource =>
+            {
+                let filter = TriggerFilter::new(
+                    move |event: &CollectionEvent<Col>| event.owner.is_none() && filter(event)
+                );
+                broadcast::<CollectionEvent<Col>>().register_filtered(commands, handle, filter);
+            }
+            Self::Entity(entity) =>
+            {
+                let filter = TriggerFilter::new(filter);
+                entity_event::<CollectionEvent<Col>>(entity).register_filtered(commands, handle, filter);
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reaction trigger for element changes in a [`ReactCollection`].
+///
+/// Use [`element_inserted`], [`element_removed`], or [`any_element_changed`] to react to collections of type `Col` in
+/// resources and on all entities. Use the `resource_` variants (e.g. [`resource_element_inserted`]) to only react to
+/// resource collections, and the `entity_` variants (e.g. [`entity_element_inserted`]) to only react to the
+/// collection on one entity.
+pub struct ElementTrigger<Col: ReactCollection>(ElementChange, ElementScope, PhantomData<Col>);
+impl<Col: ReactCollection> Clone for ElementTrigger<Col> { fn clone(&self) -> Self { *self } }
+impl<Col: ReactCollection> Copy for ElementTrigger<Col> {}
+
+impl<Col: ReactCollection> ReactionTrigger for ElementTrigger<Col>
+{
+    fn reactor_type(&self) -> ReactorType
+    {
+        self.1.reactor_type::<Col>()
+    }
+
+    fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        let change = self.0;
+        self.1.register(commands, handle, move |event: &CollectionEvent<Col>| event.change == change);
+    }
+}
+
+/// Returns an [`ElementTrigger`] reaction trigger for element insertions.
+pub fn element_inserted<Col: ReactCollection>() -> ElementTrigger<Col>
+{
+    ElementTrigger(ElementChange::Inserted, ElementScope::Any, PhantomData)
+}
+
+/// Returns an [`ElementTrigger`] reaction trigger for element removals.
+pub fn element_removed<Col: ReactCollection>() -> ElementTrigger<Col>
+{
+    ElementTrigger(ElementChange::Removed, ElementScope::Any, PhantomData)
+}
+
+/// Returns an [`ElementTrigger`] reaction trigger for mutations of any element.
+pub fn any_element_changed<Col: ReactCollection>() -> ElementTrigger<Col>
+{
+    ElementTrigger(ElementChange::Changed, ElementScope::Any, PhantomData)
+}
+
+/// Returns an [`ElementTrigger`] reaction trigger for element insertions in resource collections.
+pub fn resource_element_inserted<Col: ReactCollection>() -> ElementTrigger<Col>
+{
+    ElementTrigger(ElementChange::Inserted, ElementScope::Resource, PhantomData)
+}
+
+/// Returns an [`ElementTrigger`] reaction trigger for element removals in resource collections.
+pub fn resource_element_removed<Col: ReactCollection>() -> ElementTrigger<Col>
+{
+    ElementTrigger(ElementChange::Removed, ElementScope::Resource, PhantomData)
+}
+
+/// Returns an [`ElementTrigger`] reaction trigger for mutations of any element in resource collections.
+pub fn resource_any_element_changed<Col: ReactCollection>() -> ElementTrigger<Col>
+{
+    ElementTrigger(ElementChange::Changed, ElementScope::Resource, PhantomData)
+}
+
+/// Returns an [`ElementTrigger`] reaction trigger for element insertions in the collection on `entity`.
+pub fn entity_element_inserted<Col: ReactCollection>(entity: Entity) -> ElementTrigger<Col>
+{
+    ElementTrigger(ElementChange::Inserted, ElementScope::Entity(entity), PhantomData)
+}
+
+/// Returns an [`ElementTrigger`] reaction trigger for element removals in the collection on `entity`.
+pub fn entity_element_removed<Col: ReactCollection>(entity: Entity) -> ElementTrigger<Col>
+{
+    ElementTrigger(ElementChange::Removed, ElementScope::Entity(entity), PhantomData)
+}
+
+/// Returns an [`ElementTrigger`] reaction trigger for mutations of any element in the collection on `entity`.
+pub fn entity_any_element_changed<Col: ReactCollection>(entity: Entity) -> ElementTrigger<Col>
+{
+    ElementTrigger(ElementChange::Changed, ElementScope::Entity(entity), PhantomData)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reaction trigger for mutations of a specific element in a [`ReactCollection`].
+///
+/// Triggers must be `Copy`, so this trigger is only available for collections with `Copy` keys. Use
+/// [`any_element_changed`] for other collections.
+///
+/// Use [`element_changed`], [`resource_element_changed`], or [`entity_element_changed`] to make this trigger. They are
+/// scoped like the [`ElementTrigger`] constructors.
+pub struct ElementChangedTrigger<Col: ReactCollection>(Col::Key, ElementScope, PhantomData<Col>) where Col::Key: Copy;
+
+impl<Col: ReactCollection> Clone for ElementChangedTrigger<Col> where Col::Key: Copy
+{
+    fn clone(&self) -> Self { *self }
+}
+impl<Col: ReactCollection> Copy for ElementChangedTrigger<Col> where Col::Key: Copy {}
+
+impl<Col: ReactCollection> ReactionTrigger for ElementChangedTrigger<Col>
+where
+    Col::Key: Copy
+{
+    fn reactor_type(&self) -> ReactorType
+    {
+        self.1.reactor_type::<Col>()
+    }
+
+    fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        let key = self.0;
+        self.1.register(commands, handle,
+            move |event: &CollectionEvent<Col>| event.change == ElementChange::Changed && event.keys.contains(&key)
+        );
+    }
+}
+
+/// Returns an [`ElementChangedTrigger`] reaction trigger for mutations of the element at `key`.
+pub fn element_changed<Col: ReactCollection>(key: Col::Key) -> ElementChangedTrigger<Col>
+where
+    Col::Key: Copy
+{
+    ElementChangedTrigger(key, ElementScope::Any, PhantomData)
+}
+
+/// Returns an [`ElementChangedTrigger`] reaction trigger for mutations of the element at `key` in resource
+/// collections.
+pub fn resource_element_changed<Col: ReactCollection>(key: Col::Key) -> ElementChangedTrigger<Col>
+where
+    Col::Key: Copy
+{
+    ElementChangedTrigger(key, ElementScope::Resource, PhantomData)
+}
+
+/// Returns an [`ElementChangedTrigger`] reaction trigger for mutations of the element at `key` in the collection on
+/// `entity`.
+pub fn entity_element_changed<Col: ReactCollection>(entity: Entity, key: Col::Key) -> ElementChangedTrigger<Col>
+where
+    Col::Key: Copy
+{
+    ElementChangedTrigger(key, ElementScope::Entity(entity), PhantomData)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -5,6 +5,8 @@ use crate::prelude::*;
 use bevy::prelude::*;
 
 //standard shortcuts
+use core::future::Future;
+use std::time::Duration;
 
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -31,6 +33,13 @@ fn register_reactors<T: ReactionTriggerBundle>(
     despawner: Res<AutoDespawner>,
 ){
     let handle = mode.prepare(&despawner, syscommand);
+    if mode != ReactorMode::Persistent
+    {
+        if let Some(mut entity_commands) = commands.get_entity(*syscommand)
+        {
+            entity_commands.insert(RegisteredReactorMode(mode));
+        }
+    }
     triggers.register_triggers(&mut commands, &handle);
 }
 
@@ -50,10 +59,12 @@ fn revoke_entity_reactor(
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn revoke_reactor(
+pub(crate) fn revoke_reactor(
     In(token)    : In<RevokeToken>,
     mut cache    : ResMut<ReactCache>,
     mut reactors : Query<&mut EntityReactors>,
+    mut timers   : Option<ResMut<ReactTimers>>,
+    mut commands : Commands,
 ){
     let id = token.id;
 
@@ -73,6 +84,10 @@ fn revoke_reactor(
             {
                 revoke_entity_reactor(entity, EntityReactionType::Removal(comp_id), id, &mut reactors);
             }
+            ReactorType::EntityChanged(entity, comp_id) =>
+            {
+                revoke_entity_reactor(entity, EntityReactionType::Changed(comp_id), id, &mut reactors);
+            }
             ReactorType::EntityEvent(entity, event_id) =>
             {
                 revoke_entity_reactor(entity, EntityReactionType::Event(event_id), id, &mut reactors);
@@ -93,6 +108,14 @@ fn revoke_reactor(
             {
                 cache.revoke_component_reactor(EntityReactionType::Removal(comp_id), id);
             }
+            ReactorType::ComponentAdded(comp_id) =>
+            {
+                cache.revoke_component_reactor(EntityReactionType::Added(comp_id), id);
+            }
+            ReactorType::ComponentChanged(comp_id) =>
+            {
+                cache.revoke_component_reactor(EntityReactionType::Changed(comp_id), id);
+            }
             ReactorType::ResourceMutation(res_id) =>
             {
                 cache.revoke_resource_mutation_reactor(res_id, id);
@@ -105,6 +128,11 @@ fn revoke_reactor(
             {
                 cache.revoke_despawn_reactor(entity, id);
             }
+            ReactorType::Timer =>
+            {
+                let Some(timers) = &mut timers else { continue; };
+                if timers.revoke_timer(id) { commands.entity(*id).despawn(); }
+            }
         }
     }
 }
@@ -147,6 +175,12 @@ impl ReactorMode
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Records the [`ReactorMode`] of a non-persistent reactor on its system command entity, for introspection.
+#[derive(Component, Debug, Copy, Clone)]
+pub(crate) struct RegisteredReactorMode(pub(crate) ReactorMode);
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Struct that drives reactivity.
 ///
 /// Obtained via [`Commands::react`](ReactCommandsExt::react).
@@ -158,6 +192,8 @@ impl ReactorMode
 pub struct ReactCommands<'w, 's>
 {
     pub(crate) commands: Commands<'w, 's>,
+    /// Priority assigned to reactors registered with these commands.
+    pub(crate) priority: Option<i32>,
 }
 
 impl<'w, 's> ReactCommands<'w, 's>
@@ -171,16 +207,44 @@ impl<'w, 's> ReactCommands<'w, 's>
     /// Returns a reborrow of `self` with a shorter lifetime.
     pub fn reborrow(&mut self) -> ReactCommands<'_, '_>
     {
-        ReactCommands{ commands: self.commands() }
+        let priority = self.priority;
+        ReactCommands{ commands: self.commands(), priority }
+    }
+
+    /// Returns a reborrow of `self` that assigns `priority` to reactors it registers.
+    ///
+    /// When multiple reactors react to the same trigger, reactors with higher priority run first. Reactors with equal
+    /// priority run in registration order. The default priority is `0`.
+    ///
+    /// The priority is stored on the reactor's [`SystemCommand`], so it also applies to triggers added to the reactor
+    /// later. Triggers that were registered before the priority was set are not re-sorted.
+    ///
+    /// Example:
+    /// ```no_run
+    /// // The save reactor will see mutations before the UI reactor.
+    /// rcommands.with_priority(10).on(mutation::<Inventory>(), save_inventory);
+    /// rcommands.on(mutation::<Inventory>(), refresh_inventory_ui);
+    /// ```
+    pub fn with_priority(&mut self, priority: i32) -> ReactCommands<'_, '_>
+    {
+        ReactCommands{ commands: self.commands(), priority: Some(priority) }
+    }
+
+    /// Assigns the current priority to a reactor.
+    fn apply_priority(&mut self, sys_command: SystemCommand)
+    {
+        let Some(priority) = self.priority else { return; };
+        let Some(mut entity_commands) = self.commands.get_entity(*sys_command) else { return; };
+        entity_commands.try_insert(ReactorPriority(priority));
     }
 
     /// Inserts a [`ReactComponent`] to the specified entity. It can be queried with [`React<C>`].
     /// - Does nothing if the entity does not exist.
     pub fn insert<C: ReactComponent>(&mut self, entity: Entity, component: C)
     {
+        self.commands.queue(validate_rc);
         let Some(mut entity_commands) = self.commands.get_entity(entity) else { return; };
-        entity_commands.try_insert( React{ entity, component } );
-        self.commands.syscall_with_validation(entity, ReactCache::schedule_insertion_reaction::<C>, validate_rc);
+        entity_commands.try_insert(React::new(component));
     }
 
     /// Sends a broadcasted event.
@@ -194,6 +258,7 @@ impl<'w, 's> ReactCommands<'w, 's>
     /// Sends an entity-targeted event.
     /// - Reactors can listen for the event with the [`entity_event()`] trigger.
     /// - Reactors can read the event with the [`EntityEvent`] system parameter.
+    /// - The event only visits `entity`. Use [`Self::propagate_entity_event`] to also visit its ancestors.
     pub fn entity_event<E: Send + Sync + 'static>(&mut self, entity: Entity, event: E)
     {
         self.commands.syscall_with_validation(
@@ -203,12 +268,72 @@ impl<'w, 's> ReactCommands<'w, 's>
         );
     }
 
+    /// Sends an entity-targeted event that visits multiple entities in the entity hierarchy.
+    /// - Reactors of each entity run before the event moves to the next entity.
+    /// - Reactors can read the original target and the entity currently visited with the [`EntityEvent`] system
+    ///   parameter, and can stop the event from visiting more entities with [`EntityEvent::stop_propagation`].
+    /// - [`any_entity_event()`] reactors run once, when the event visits the first entity.
+    pub fn propagate_entity_event<E: Send + Sync + 'static>(
+        &mut self,
+        entity      : Entity,
+        event       : E,
+        propagation : EventPropagation,
+    ){
+        self.commands.queue(validate_rc);
+        self.commands.queue(move |world: &mut World| propagate_entity_event(world, entity, event, propagation));
+    }
+
+    /// Sends a broadcasted event after a delay.
+    /// - The delay is measured with [`Time::elapsed`], see [`schedule_timer_reactors`].
+    /// - The event will be sent as if by [`Self::broadcast`] when the delay expires.
+    ///
+    /// Returns a token that cancels the event if passed to [`Self::revoke`] before the delay expires.
+    pub fn broadcast_delayed<E: Send + Sync + 'static>(&mut self, event: E, delay: Duration) -> RevokeToken
+    {
+        let callback: TimerCallback = Box::new(move |world: &mut World| world.broadcast(event));
+        self.register_delayed_callback(delay, callback)
+    }
+
+    /// Sends an entity-targeted event after a delay.
+    /// - The delay is measured with [`Time::elapsed`], see [`schedule_timer_reactors`].
+    /// - The event will be sent as if by [`Self::entity_event`] when the delay expires.
+    ///
+    /// Returns a token that cancels the event if passed to [`Self::revoke`] before the delay expires.
+    pub fn entity_event_delayed<E: Send + Sync + 'static>(
+        &mut self,
+        entity : Entity,
+        event  : E,
+        delay  : Duration
+    ) -> RevokeToken
+    {
+        let callback: TimerCallback = Box::new(move |world: &mut World| world.entity_event(entity, event));
+        self.register_delayed_callback(delay, callback)
+    }
+
+    /// Schedules a delayed callback and returns a token for revoking it.
+    fn register_delayed_callback(&mut self, delay: Duration, callback: TimerCallback) -> RevokeToken
+    {
+        let id = SystemCommand(self.commands.spawn_empty().id());
+        self.commands.syscall_with_validation((delay, id, callback), register_delayed_callback, validate_rc);
+        RevokeToken::new_timer(id)
+    }
+
     /// Triggers resource mutation reactions.
     ///
     /// Useful for initializing state after a reactor is registered.
     pub fn trigger_resource_mutation<R: ReactResource + Send + Sync + 'static>(&mut self)
     {
-        self.commands.syscall_with_validation((), ReactCache::schedule_resource_mutation_reaction::<R>, validate_rc);
+        self.trigger_resource_mutation_with_previous::<R>(None);
+    }
+
+    /// Triggers resource mutation reactions that can read `previous` with [`ResourceMutationEvent::previous`].
+    pub(crate) fn trigger_resource_mutation_with_previous<R: ReactResource>(&mut self, previous: Option<R>)
+    {
+        self.commands.syscall_with_validation(
+            previous,
+            ReactCache::schedule_resource_mutation_reaction::<R>,
+            validate_rc
+        );
     }
 
     /// Revokes a reactor.
@@ -217,6 +342,42 @@ impl<'w, 's> ReactCommands<'w, 's>
         self.commands.syscall_with_validation(token, revoke_reactor, validate_rc);
     }
 
+    /// Registers a reactor whose triggers are tracked automatically.
+    ///
+    /// The reactor runs once immediately. Each time it runs, it records the [`React<C>`] components it reads with
+    /// [`Reactive`] and the reactive resources it reads with [`ReactRes`], then replaces its triggers with mutation
+    /// triggers for exactly that state. Reads with other system parameters (e.g. [`ReactiveMut`]) are not tracked.
+    ///
+    /// The reactor is persistent. Use [`Self::revoke_auto_tracked`] to remove it.
+    ///
+    /// Example:
+    /// ```no_run
+    /// // Reruns when the player's health or the difficulty changes.
+    /// rcommands.on_auto_tracked(
+    ///     move |healths: Reactive<Health>, difficulty: ReactRes<Difficulty>|
+    ///     {
+    ///         println!("{}", healths.get(player).unwrap().0 * difficulty.scale);
+    ///     }
+    /// );
+    /// ```
+    pub fn on_auto_tracked<M>(
+        &mut self,
+        reactor: impl IntoSystem<(), (), M> + Send + Sync + 'static
+    ) -> SystemCommand
+    {
+        self.commands.queue(validate_rc);
+        let sys_command = spawn_auto_tracked_reactor(&mut self.commands, reactor);
+        self.apply_priority(sys_command);
+        self.commands.queue(sys_command);
+        sys_command
+    }
+
+    /// Revokes a reactor registered with [`Self::on_auto_tracked`] and despawns it.
+    pub fn revoke_auto_tracked(&mut self, sys_command: SystemCommand)
+    {
+        self.commands.syscall_with_validation(sys_command, revoke_auto_tracked_reactor, validate_rc);
+    }
+
     /// Registers a reactor triggered by ECS changes.
     ///
     /// You can tie a reactor to multiple reaction triggers.
@@ -241,6 +402,24 @@ impl<'w, 's> ReactCommands<'w, 's>
         let _ = self.with(triggers, sys_command, ReactorMode::Cleanup);
     }
 
+    /// Registers a reactor that can fail, triggered by ECS changes.
+    ///
+    /// Errors are passed to the [`ReactErrorHandler`]. Use [`ReactCommandsExt::spawn_fallible_system_command`] and
+    /// [`Self::with`] for other [`ReactorModes`](ReactorMode).
+    ///
+    /// See [`Self::on`].
+    pub fn on_fallible<M, E>(
+        &mut self,
+        triggers : impl ReactionTriggerBundle,
+        reactor  : impl IntoSystem<(), Result<(), E>, M> + Send + Sync + 'static
+    )
+    where
+        E: Into<ReactError> + Send + Sync + 'static
+    {
+        let sys_command = self.commands.spawn_fallible_system_command(reactor);
+        let _ = self.with(triggers, sys_command, ReactorMode::Cleanup);
+    }
+
     /// Registers a reactor triggered by ECS changes using [`ReactorMode::Persistent`].
     ///
     /// See [`Self::on`].
@@ -297,6 +476,7 @@ impl<'w, 's> ReactCommands<'w, 's>
         mode        : ReactorMode,
     ) -> Option<RevokeToken>
     {
+        self.apply_priority(sys_command);
         self.commands.syscall_with_validation((triggers, sys_command, mode), register_reactors, validate_rc);
         match mode
         {
@@ -327,6 +507,7 @@ impl<'w, 's> ReactCommands<'w, 's>
         let syscommand = SystemCommand(entity);
         let mode = ReactorMode::Revokable;
         let revoke_token = RevokeToken::new_from(syscommand, triggers);
+        self.apply_priority(syscommand);
         self.commands.syscall_with_validation((triggers, syscommand, mode), register_reactors, validate_rc);
 
         // wrap reactor in a system that will be called once, then clean itself up
@@ -342,10 +523,62 @@ impl<'w, 's> ReactCommands<'w, 's>
         {
             if let Some(reactor) = once_reactor.take() { (reactor)(world, cleanup); };
         };
-        self.commands.entity(entity).try_insert(SystemCommandStorage::new(SystemCommandCallback::with(once_system)));
+        self.commands.entity(entity).try_insert(SystemCommandStorage::new(
+            SystemCommandCallback::with(once_system).named(std::any::type_name::<S>())
+        ));
 
         revoke_token
     }
+
+    /// Returns a future that resolves the first time one of the triggers fires.
+    ///
+    /// The trigger is registered with [`Self::once`], so it stays registered until it fires even if the future is
+    /// dropped. Use [`Self::wait_for_with`] to read data from the reaction (e.g. the event that triggered it).
+    ///
+    /// Example:
+    /// ```no_run
+    /// rcommands.spawn_async(move |world: AsyncWorld| async move {
+    ///     world.with(move |w| w.react(|rc| rc.entity_event(entity, PlayAnimation))).await;
+    ///     world.wait_for(entity_event::<AnimationDone>(entity)).await;
+    ///     world.with(move |w| { w.spawn(Loot); }).await;
+    /// });
+    /// ```
+    pub fn wait_for(&mut self, triggers: impl ReactionTriggerBundle) -> ReactionFuture<()>
+    {
+        self.wait_for_with(triggers, || ())
+    }
+
+    /// Returns a future that resolves with the output of `reader` the first time one of the triggers fires.
+    ///
+    /// The reader runs as a reactor, so it can read reaction data with system parameters like [`BroadcastEvent`].
+    ///
+    /// Example:
+    /// ```no_run
+    /// let damage = rcommands.wait_for_with(
+    ///     entity_event::<Damage>(entity),
+    ///     |event: EntityEvent<Damage>| event.read().1.0
+    /// );
+    /// ```
+    pub fn wait_for_with<O: Send + 'static, M>(
+        &mut self,
+        triggers : impl ReactionTriggerBundle,
+        reader   : impl IntoSystem<(), O, M> + Send + Sync + 'static
+    ) -> ReactionFuture<O>
+    {
+        wait_for_reaction(self, triggers, reader)
+    }
+
+    /// Spawns an async task that can access the world between awaits with [`AsyncWorld`].
+    ///
+    /// The task is polled immediately, then whenever it is woken (see [`poll_react_tasks`]).
+    pub fn spawn_async<F, Fut>(&mut self, task: F)
+    where
+        F: FnOnce(AsyncWorld) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.commands.queue(validate_rc);
+        self.commands.queue(move |world: &mut World| spawn_react_task(world, task));
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
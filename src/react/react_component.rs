@@ -3,32 +3,121 @@ use crate::prelude::*;
 
 //third-party shortcuts
 use bevy::prelude::*;
+use bevy::ecs::component::{ComponentHooks, ComponentId, StorageType};
 use bevy::ecs::system::SystemParam;
+use bevy::ecs::world::DeferredWorld;
 
 //standard shortcuts
 use core::ops::Deref;
 
 //-------------------------------------------------------------------------------------------------------------------
 
+mod private
+{
+    use bevy::prelude::Entity;
+
+    /// The entity that owns a [`React`](super::React) component. Can't be constructed outside this crate.
+    pub struct ReactOwner(pub(crate) Option<Entity>);
+}
+
+pub(crate) use private::ReactOwner;
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Tag trait for reactive components.
 ///
 /// It is not recommended to add `ReactComponent` and `Component` to the same struct, as it will likely cause confusion.
-pub trait ReactComponent: Send + Sync + 'static {}
+///
+/// Add `#[react(track_previous)]` to the derive to expose pre-mutation values in [`MutationEvent::previous`], and
+/// `#[react(track_removed)]` to expose removed values in [`RemovalEvent::value`]. The component must implement `Clone`.
+pub trait ReactComponent: Send + Sync + 'static
+{
+    /// Snapshots the component before it is mutated with [`React::get_mut`] or [`React::set_if_neq`].
+    ///
+    /// The snapshot is readable by all reactors of that mutation with [`MutationEvent::previous`]. Returns `None` by
+    /// default (no snapshot is taken).
+    fn snapshot_previous(&self) -> Option<Self> where Self: Sized { None }
+
+    /// Snapshots the component when its [`React<Self>`] is removed from an entity.
+    ///
+    /// The snapshot is readable by all reactors of that removal with [`RemovalEvent::value`]. It is only taken if there
+    /// are removal reactors for `Self`. Returns `None` by default (no snapshot is taken).
+    fn snapshot_removed(&self) -> Option<Self> where Self: Sized { None }
+
+    /// Records the entity that owns the component when its [`React<Self>`] is inserted or removed.
+    ///
+    /// Only used by this crate's reactive collections.
+    #[doc(hidden)]
+    fn set_react_owner(&mut self, _owner: ReactOwner) {}
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component hook that binds a `React` to its entity and schedules insertion reactions.
+fn on_insert_react<C: ReactComponent>(mut world: DeferredWorld, entity: Entity, _: ComponentId)
+{
+    if let Some(mut react) = world.get_mut::<React<C>>(entity)
+    {
+        let react = react.bypass_change_detection();
+        react.entity = entity;
+        react.component.set_react_owner(ReactOwner(Some(entity)));
+    }
+
+    // Reactions can only be scheduled if the ReactPlugin was added.
+    if !world.contains_resource::<ReactCache>() { return; }
+    world.commands().syscall(entity, ReactCache::schedule_insertion_reaction::<C>);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Component hook that unbinds a removed `React` from its entity and saves its value for removal reactors.
+fn on_remove_react<C: ReactComponent>(mut world: DeferredWorld, entity: Entity, _: ComponentId)
+{
+    let Some(mut react) = world.get_mut::<React<C>>(entity) else { return };
+    react.bypass_change_detection().component.set_react_owner(ReactOwner(None));
+
+    // Only save the value if removal reactors can read it.
+    if !world.get_resource::<ReactCache>().is_some_and(|cache| cache.is_tracking_removals::<C>()) { return; }
+    let Some(react) = world.get::<React<C>>(entity) else { return };
+    let Some(value) = react.component.snapshot_removed() else { return };
+    world.resource_mut::<ReactCache>().push_removed_value(entity, value);
+}
 
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Component wrapper that enables reacting to component mutations.
-/// - WARNING: It is possible to remove a `React` from one entity and manually insert it to another entity. That WILL
-///            break the react framework. Instead use `react_commands.insert(new_entity, react_component.take());`.
-#[derive(Component)]
+///
+/// Insertion reactions are scheduled whenever a `React<C>` is inserted, regardless of whether it was added with
+/// [`ReactCommands::insert`], spawned in a bundle, or inserted directly into the world. With direct world access, the
+/// reactions will run the next time the world's commands are flushed. Removals are detected by polling Bevy's removal
+/// events (see [`schedule_removal_and_despawn_reactors`]).
 pub struct React<C: ReactComponent>
 {
     pub(crate) entity    : Entity,
     pub(crate) component : C,
 }
 
+impl<C: ReactComponent> Component for React<C>
+{
+    const STORAGE_TYPE: StorageType = StorageType::Table;
+
+    fn register_component_hooks(hooks: &mut ComponentHooks)
+    {
+        hooks.on_insert(on_insert_react::<C>)
+            .on_remove(on_remove_react::<C>);
+    }
+}
+
 impl<C: ReactComponent> React<C>
 {
+    /// Makes a new `React` that can be inserted directly, e.g. as part of a bundle.
+    ///
+    /// Insertion reactions will be scheduled when the component is inserted to an entity.
+    pub fn new(component: C) -> Self
+    {
+        Self{ entity: Entity::PLACEHOLDER, component }
+    }
+
     /// Immutably accesses the component.
     pub fn get(&self) -> &C
     {
@@ -38,14 +127,15 @@ impl<C: ReactComponent> React<C>
     /// Mutably accesses the component and triggers reactions.
     pub fn get_mut<'a>(&'a mut self, c: &mut Commands) -> &'a mut C
     {
-        c.syscall(self.entity, ReactCache::schedule_mutation_reaction::<C>);
+        let previous = self.component.snapshot_previous();
+        c.syscall((self.entity, previous), ReactCache::schedule_mutation_reaction::<C>);
         &mut self.component
     }
 
     /// Allows manually triggering mutation reactions when in an exclusive context.
     pub fn trigger_mutation(entity: Entity, world: &mut World)
     {
-        world.syscall(entity, ReactCache::schedule_mutation_reaction::<C>);
+        world.syscall((entity, None), ReactCache::schedule_mutation_reaction::<C>);
     }
 
     /// Mutably accesses the component without triggering reactions.
@@ -63,7 +153,8 @@ impl<C: ReactComponent> React<C>
     {
         if new == self.component { return None; }
 
-        c.syscall(self.entity, ReactCache::schedule_mutation_reaction::<C>);
+        let previous = self.component.snapshot_previous();
+        c.syscall((self.entity, previous), ReactCache::schedule_mutation_reaction::<C>);
         let old = std::mem::replace(&mut self.component, new);
         Some(old)
     }
@@ -94,26 +185,36 @@ impl<C: ReactComponent> Deref for React<C>
 pub struct Reactive<'w, 's, T: ReactComponent>
 {
     components: Query<'w, 's, (Entity, &'static React<T>)>,
+    tracker: Option<Res<'w, ReactReadTracker>>,
 }
 
 impl<'w, 's, T: ReactComponent> Reactive<'w, 's, T>
 {
+    /// Records a read for auto-tracked reactors.
+    fn record(&self, entity: Entity)
+    {
+        let Some(tracker) = &self.tracker else { return; };
+        tracker.record_component::<T>(entity);
+    }
+
     /// Reads `T` on `entity`.
     ///
-    /// Does not trigger reactions.
+    /// Does not trigger reactions. The read is tracked by [auto-tracked reactors](ReactCommands::on_auto_tracked).
     pub fn get(&self, entity: Entity) -> Option<&T>
     {
+        self.record(entity);
         self.components.get(entity).ok().map(|(_, c)| c.get())
     }
 
     /// Reads `T` on a single entity.
     ///
-    /// Does not trigger reactions.
+    /// Does not trigger reactions. The read is tracked by [auto-tracked reactors](ReactCommands::on_auto_tracked).
     ///
     /// Panics if the inner query doesn't have exactly one entity.
     pub fn single(&self) -> (Entity, &T)
     {
         let (e, x) = self.components.single();
+        self.record(e);
         (e, x.get())
     }
 }
@@ -0,0 +1,114 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+use std::fmt;
+use std::sync::Arc;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+type ErrorHandlerFn = Arc<dyn Fn(&mut World, ReactorError) + Send + Sync + 'static>;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Passes a reactor error to the [`ReactErrorHandler`].
+pub(crate) fn handle_reactor_error(world: &mut World, error: ReactorError)
+{
+    let Some(handler) = world.get_resource::<ReactErrorHandler>().map(|h| h.handler.clone()) else {
+        log_reactor_error(world, error);
+        return;
+    };
+    (handler)(world, error);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The default reactor error handler.
+fn log_reactor_error(_: &mut World, error: ReactorError)
+{
+    tracing::error!(reactor = ?error.reactor, kind = ?error.kind, name = error.name, "{}", error.error);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Type-erased error returned by fallible system commands. See [`SystemCommandCallback::new_fallible`].
+pub type ReactError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// An error returned by a fallible system command. Passed to the [`ReactErrorHandler`].
+#[derive(Debug)]
+pub struct ReactorError
+{
+    /// The system command that failed.
+    pub reactor: SystemCommand,
+    /// The name of the system command's system, if known.
+    pub name: Option<&'static str>,
+    /// What caused the system command to run.
+    pub kind: ReactionKind,
+    /// The error.
+    pub error: ReactError,
+}
+
+impl fmt::Display for ReactorError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{:?} reactor {:?}", self.kind, self.reactor)?;
+        if let Some(name) = self.name
+        {
+            write!(f, " ({name})")?;
+        }
+        write!(f, " failed: {}", self.error)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that handles errors returned by fallible system commands (see [`ReactCommands::on_fallible`] and
+/// [`SystemCommandCallback::new_fallible`]).
+///
+/// The handler runs after the failed system command's commands are applied (including any reactions they trigger). The
+/// default handler logs errors with `tracing::error!`.
+///
+/// Insert this resource to change the handler.
+///
+/*
+```rust
+app.insert_resource(ReactErrorHandler::new(
+    |world: &mut World, error: ReactorError|
+    {
+        world.resource_mut::<ErrorLog>().push(error.to_string());
+    }
+));
+```
+*/
+#[derive(Resource, Clone)]
+pub struct ReactErrorHandler
+{
+    handler: ErrorHandlerFn,
+}
+
+impl ReactErrorHandler
+{
+    /// Makes a new error handler.
+    pub fn new(handler: impl Fn(&mut World, ReactorError) + Send + Sync + 'static) -> Self
+    {
+        Self{ handler: Arc::new(handler) }
+    }
+}
+
+impl Default for ReactErrorHandler
+{
+    fn default() -> Self
+    {
+        Self::new(log_reactor_error)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
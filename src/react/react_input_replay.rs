@@ -0,0 +1,662 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+#[cfg(feature = "reflect_recording")]
+use bevy::reflect::serde::{TypedReflectDeserializer, TypedReflectSerializer};
+use bevy::utils::HashMap;
+
+//standard shortcuts
+use core::any::{Any, TypeId};
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+type EncodeFn = Arc<dyn Fn(&dyn Any) -> Option<String> + Send + Sync + 'static>;
+type ReplayFn = Arc<dyn Fn(&mut World, ReactInputKind, Option<Entity>, &str) -> bool + Send + Sync + 'static>;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn escape_payload(s: &str) -> String
+{
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars()
+    {
+        match c
+        {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn unescape_payload(s: &str) -> String
+{
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next()
+    {
+        if c != '\\' { unescaped.push(c); continue; }
+        match chars.next()
+        {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('t') => unescaped.push('\t'),
+            Some(c)   => unescaped.push(c),
+            None      => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Makes an encoder that serializes values to RON with the type registry.
+#[cfg(feature = "reflect_recording")]
+pub(crate) fn reflect_encoder<T: Reflect>(
+    registry: AppTypeRegistry
+) -> impl Fn(&T) -> Option<String> + Send + Sync + 'static
+{
+    move |value|
+    {
+        let registry = registry.read();
+        let serializer = TypedReflectSerializer::new(value.as_partial_reflect(), &registry);
+        match ron::to_string(&serializer)
+        {
+            Ok(payload) => Some(payload),
+            Err(err) =>
+            {
+                tracing::error!(?err, type_name = std::any::type_name::<T>(), "failed serializing react input");
+                None
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Makes a decoder that deserializes values from RON with the type registry.
+#[cfg(feature = "reflect_recording")]
+pub(crate) fn reflect_decoder<T: FromReflect + TypePath>(
+    registry: AppTypeRegistry
+) -> impl Fn(&str) -> Option<T> + Send + Sync + 'static
+{
+    move |payload|
+    {
+        let registry = registry.read();
+        let deserializer = TypedReflectDeserializer::of::<T>(&registry);
+        let value = ron::Options::default().from_str_seed(payload, deserializer).ok()?;
+        T::from_reflect(value.as_ref())
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn replay_event<E: Send + Sync + 'static>(
+    world  : &mut World,
+    kind   : ReactInputKind,
+    target : Option<Entity>,
+    event  : E
+) -> bool
+{
+    match (kind, target)
+    {
+        (ReactInputKind::Broadcast, _)              => { world.react(|rc| rc.broadcast(event)); }
+        (ReactInputKind::EntityEvent, Some(target)) => { world.react(|rc| rc.entity_event(target, event)); }
+        (ReactInputKind::SystemEvent, Some(target)) => { world.send_system_event(SystemCommand(target), event); }
+        _ => return false,
+    }
+    true
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn replay_component<C: ReactComponent>(
+    world     : &mut World,
+    kind      : ReactInputKind,
+    target    : Option<Entity>,
+    component : C
+) -> bool
+{
+    let Some(target) = target else { return false; };
+    match kind
+    {
+        ReactInputKind::Insertion => { world.react(|rc| rc.insert(target, component)); }
+        ReactInputKind::Mutation =>
+        {
+            let Some(mut existing) = world.get_mut::<React<C>>(target) else { return false; };
+            *existing.get_noreact() = component;
+            React::<C>::trigger_mutation(target, world);
+        }
+        _ => return false,
+    }
+    true
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records inputs that enter the reactive system from outside any system command.
+pub(crate) fn record_react_input<T: 'static>(
+    counter  : Option<&SyscommandCounter>,
+    codecs   : Option<&ReactInputCodecs>,
+    recorder : Option<&mut ReactInputRecorder>,
+    kind     : ReactInputKind,
+    target   : Option<Entity>,
+    value    : &T,
+){
+    let Some(recorder) = recorder else { return; };
+    if recorder.paused { return; }
+    if counter.map(|c| **c).unwrap_or_default() > 0 { return; }
+    let Some(codec) = codecs.and_then(|c| c.codecs.get(&TypeId::of::<T>())) else { return; };
+    let Some(payload) = (codec.encode)(value) else { return; };
+
+    recorder.pending.push(
+        RecordedReactInput{
+            frame: recorder.frame,
+            kind,
+            type_name: codec.name.into(),
+            target,
+            payload,
+        }
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records an input that enters the reactive system with exclusive `World` access.
+pub(crate) fn record_react_input_in_world<T: 'static>(
+    world  : &mut World,
+    kind   : ReactInputKind,
+    target : Option<Entity>,
+    value  : &T,
+){
+    if !world.contains_resource::<ReactInputRecorder>() { return; }
+    world.resource_scope(
+        |world: &mut World, mut recorder: Mut<ReactInputRecorder>|
+        {
+            record_react_input(
+                world.get_resource::<SyscommandCounter>(),
+                world.get_resource::<ReactInputCodecs>(),
+                Some(&mut recorder),
+                kind,
+                target,
+                value
+            );
+        }
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Writes recorded inputs to the recording file and advances the recorder's frame.
+fn write_react_inputs(mut recorder: ResMut<ReactInputRecorder>)
+{
+    if let Err(err) = recorder.flush()
+    {
+        tracing::error!(?err, path = ?recorder.path, "failed writing recorded react inputs");
+    }
+    recorder.frame += 1;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Replays recorded inputs for the current frame and advances the replayer's frame.
+fn replay_react_inputs(world: &mut World)
+{
+    world.resource_scope(
+        |world: &mut World, mut replayer: Mut<ReactInputReplayer>|
+        {
+            let frame = replayer.frame;
+            replayer.frame += 1;
+            if replayer.entries.front().map(|e| e.frame > frame).unwrap_or(true) { return; }
+
+            world.resource_scope(
+                |world: &mut World, codecs: Mut<ReactInputCodecs>|
+                {
+                    while replayer.entries.front().map(|e| e.frame <= frame).unwrap_or(false)
+                    {
+                        let Some(entry) = replayer.entries.pop_front() else { break; };
+                        let Some(replay) = codecs.replayers.get(entry.type_name.as_str()) else {
+                            tracing::warn!(type_name = entry.type_name, "failed replaying react input, the type is not \
+                                registered for recording");
+                            continue;
+                        };
+                        if !(replay)(world, entry.kind, entry.target, &entry.payload)
+                        {
+                            tracing::warn!(?entry, "failed replaying react input");
+                        }
+                    }
+                }
+            );
+        }
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The kind of a [`RecordedReactInput`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ReactInputKind
+{
+    /// A [`ReactCommands::broadcast`] event.
+    Broadcast,
+    /// A [`ReactCommands::entity_event`] event.
+    EntityEvent,
+    /// A system event sent with `send_system_event`.
+    SystemEvent,
+    /// A [`ReactComponent`] inserted with [`ReactCommands::insert`].
+    Insertion,
+    /// A [`ReactComponent`] mutated with [`React::get_mut`] or similar. The value is the component's value when
+    /// mutation reactions were scheduled.
+    Mutation,
+}
+
+impl ReactInputKind
+{
+    fn as_str(&self) -> &'static str
+    {
+        match self
+        {
+            Self::Broadcast   => "broadcast",
+            Self::EntityEvent => "entity_event",
+            Self::SystemEvent => "system_event",
+            Self::Insertion   => "insertion",
+            Self::Mutation    => "mutation",
+        }
+    }
+
+    fn from_str(s: &str) -> Option<Self>
+    {
+        match s
+        {
+            "broadcast"    => Some(Self::Broadcast),
+            "entity_event" => Some(Self::EntityEvent),
+            "system_event" => Some(Self::SystemEvent),
+            "insertion"    => Some(Self::Insertion),
+            "mutation"     => Some(Self::Mutation),
+            _              => None,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// An input recorded by the [`ReactInputRecorder`].
+///
+/// Recordings are stored one input per line as tab-separated `frame`, `kind`, `type_name`, `target`, and `payload`
+/// fields. The target is the target entity's bits, or `-` if there is no target.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RecordedReactInput
+{
+    /// The frame the input was recorded in, counted from the start of the recording.
+    pub frame: u32,
+    /// The kind of input.
+    pub kind: ReactInputKind,
+    /// The name the input's type was registered with (its type name).
+    pub type_name: String,
+    /// The target entity of entity events, system events, and component inputs.
+    pub target: Option<Entity>,
+    /// The serialized input value.
+    pub payload: String,
+}
+
+impl RecordedReactInput
+{
+    /// Serializes the input as a line of a recording, without a newline.
+    pub fn to_line(&self) -> String
+    {
+        let target = self.target.map(|t| t.to_bits().to_string()).unwrap_or_else(|| String::from("-"));
+        format!("{}\t{}\t{}\t{}\t{}",
+            self.frame, self.kind.as_str(), self.type_name, target, escape_payload(&self.payload))
+    }
+
+    /// Deserializes the input from a line of a recording.
+    pub fn from_line(line: &str) -> Option<Self>
+    {
+        let mut fields = line.splitn(5, '\t');
+        let frame = fields.next()?.parse().ok()?;
+        let kind = ReactInputKind::from_str(fields.next()?)?;
+        let type_name = String::from(fields.next()?);
+        let target = match fields.next()?
+        {
+            "-" => None,
+            bits => Some(Entity::try_from_bits(bits.parse().ok()?).ok()?),
+        };
+        let payload = unescape_payload(fields.next()?);
+
+        Some(Self{ frame, kind, type_name, target, payload })
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+struct ReactInputCodec
+{
+    name: &'static str,
+    encode: EncodeFn,
+}
+
+/// Stores the encoders and decoders of types registered for recording.
+///
+/// See [`ReactAppExt::register_recorded_event`] and [`ReactAppExt::register_recorded_component`].
+#[derive(Resource, Default)]
+pub(crate) struct ReactInputCodecs
+{
+    codecs: HashMap<TypeId, ReactInputCodec>,
+    replayers: HashMap<&'static str, ReplayFn>,
+}
+
+impl ReactInputCodecs
+{
+    pub(crate) fn register_event<E: Send + Sync + 'static>(
+        &mut self,
+        encode : impl Fn(&E) -> Option<String> + Send + Sync + 'static,
+        decode : impl Fn(&str) -> Option<E> + Send + Sync + 'static
+    ){
+        self.register::<E>(
+            encode,
+            Arc::new(
+                move |world, kind, target, payload|
+                {
+                    let Some(event) = (decode)(payload) else { return false; };
+                    replay_event(world, kind, target, event)
+                }
+            )
+        );
+    }
+
+    pub(crate) fn register_component<C: ReactComponent>(
+        &mut self,
+        encode : impl Fn(&C) -> Option<String> + Send + Sync + 'static,
+        decode : impl Fn(&str) -> Option<C> + Send + Sync + 'static
+    ){
+        self.register::<C>(
+            encode,
+            Arc::new(
+                move |world, kind, target, payload|
+                {
+                    let Some(component) = (decode)(payload) else { return false; };
+                    replay_component(world, kind, target, component)
+                }
+            )
+        );
+    }
+
+    fn register<T: 'static>(&mut self, encode: impl Fn(&T) -> Option<String> + Send + Sync + 'static, replay: ReplayFn)
+    {
+        let name = std::any::type_name::<T>();
+        let encode: EncodeFn = Arc::new(move |value| value.downcast_ref::<T>().and_then(&encode));
+        self.codecs.insert(TypeId::of::<T>(), ReactInputCodec{ name, encode });
+        self.replayers.insert(name, replay);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter used to record react inputs in the [`ReactCache`] scheduling systems.
+#[derive(SystemParam)]
+pub(crate) struct ReactInputRecording<'w>
+{
+    counter: Option<Res<'w, SyscommandCounter>>,
+    codecs: Option<Res<'w, ReactInputCodecs>>,
+    recorder: Option<ResMut<'w, ReactInputRecorder>>,
+}
+
+impl ReactInputRecording<'_>
+{
+    pub(crate) fn record<T: 'static>(&mut self, kind: ReactInputKind, target: Option<Entity>, value: &T)
+    {
+        record_react_input(
+            self.counter.as_deref(),
+            self.codecs.as_deref(),
+            self.recorder.as_deref_mut(),
+            kind,
+            target,
+            value
+        );
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that records react inputs to a file. Added by [`ReactInputRecorderPlugin`].
+///
+/// Only inputs that enter the reactive system from outside system commands are recorded (e.g. from normal systems or
+/// exclusive `World` access), since inputs sent by reactors will be re-sent when the recording is replayed. Only types
+/// registered with [`ReactAppExt::register_recorded_event`] or [`ReactAppExt::register_recorded_component`] are
+/// recorded.
+///
+/// Recorded inputs are written to the file in `Last` every frame.
+#[derive(Resource, Debug)]
+pub struct ReactInputRecorder
+{
+    path: PathBuf,
+    file: Option<File>,
+    frame: u32,
+    paused: bool,
+    pending: Vec<RecordedReactInput>,
+}
+
+impl ReactInputRecorder
+{
+    /// Makes a new recorder that writes to `path`.
+    ///
+    /// The file is created or truncated when the recorder is first flushed.
+    pub fn new(path: impl Into<PathBuf>) -> Self
+    {
+        Self{ path: path.into(), file: None, frame: 0, paused: false, pending: Vec::default() }
+    }
+
+    /// Gets the path of the recording file.
+    pub fn path(&self) -> &Path
+    {
+        &self.path
+    }
+
+    /// Gets the current frame of the recording.
+    pub fn frame(&self) -> u32
+    {
+        self.frame
+    }
+
+    /// Stops recording inputs until [`Self::resume`] is called.
+    pub fn pause(&mut self)
+    {
+        self.paused = true;
+    }
+
+    /// Resumes recording inputs.
+    pub fn resume(&mut self)
+    {
+        self.paused = false;
+    }
+
+    /// Returns `true` if the recorder is paused.
+    pub fn is_paused(&self) -> bool
+    {
+        self.paused
+    }
+
+    /// Writes pending inputs to the recording file.
+    pub fn flush(&mut self) -> std::io::Result<()>
+    {
+        let file = match &mut self.file
+        {
+            Some(file) => file,
+            None => self.file.insert(File::create(&self.path)?),
+        };
+
+        let mut buffer = String::new();
+        for input in self.pending.drain(..)
+        {
+            buffer.push_str(&input.to_line());
+            buffer.push('\n');
+        }
+        file.write_all(buffer.as_bytes())?;
+        file.flush()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that replays react inputs from a file. Added by [`ReactInputReplayerPlugin`].
+///
+/// Inputs are replayed in `First` in the order they were recorded, with frames counted from the first time the
+/// replayer runs. Entity-targeted inputs are replayed with the same entity ids as the recording, so the replaying world
+/// should spawn entities in the same order as the recording world.
+#[derive(Resource, Debug, Default)]
+pub struct ReactInputReplayer
+{
+    frame: u32,
+    entries: VecDeque<RecordedReactInput>,
+}
+
+impl ReactInputReplayer
+{
+    /// Makes a new replayer for the given inputs.
+    pub fn new(entries: impl IntoIterator<Item = RecordedReactInput>) -> Self
+    {
+        Self{ frame: 0, entries: entries.into_iter().collect() }
+    }
+
+    /// Loads a replayer from a recording file.
+    ///
+    /// Lines that can't be parsed are skipped with a warning.
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self>
+    {
+        let recording = std::fs::read_to_string(path)?;
+        let entries = recording
+            .lines()
+            .filter(|line| !line.is_empty())
+            .filter_map(
+                |line|
+                {
+                    let entry = RecordedReactInput::from_line(line);
+                    if entry.is_none() { tracing::warn!(line, "failed parsing recorded react input"); }
+                    entry
+                }
+            );
+        Ok(Self::new(entries))
+    }
+
+    /// Iterates inputs that have not been replayed yet.
+    pub fn remaining(&self) -> impl Iterator<Item = &RecordedReactInput> + '_
+    {
+        self.entries.iter()
+    }
+
+    /// Returns `true` if all inputs have been replayed.
+    pub fn is_finished(&self) -> bool
+    {
+        self.entries.is_empty()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Plugin that records react inputs to a file with [`ReactInputRecorder`].
+///
+/// Requires [`ReactPlugin`]. Only types registered with [`ReactAppExt::register_recorded_event`] or
+/// [`ReactAppExt::register_recorded_component`] are recorded, using the codecs passed at registration. With the
+/// `reflect_recording` feature, `Reflect` types can instead be registered with
+/// `ReactAppExt::register_recorded_reflect_event` and `ReactAppExt::register_recorded_reflect_component`, which store
+/// values as RON.
+///
+/*
+```rust
+struct Score(u32);
+
+#[derive(Reflect)]
+struct Damage(u32);
+
+app.add_plugins(ReactInputRecorderPlugin::new("inputs.txt"))
+    .register_recorded_event::<Score>(
+        |event| event.0.to_string(),
+        |payload| payload.parse().ok().map(Score)
+    )
+    .register_recorded_reflect_event::<Damage>();
+```
+*/
+#[derive(Debug, Clone)]
+pub struct ReactInputRecorderPlugin
+{
+    path: PathBuf,
+}
+
+impl ReactInputRecorderPlugin
+{
+    /// Makes a new plugin that records to `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self
+    {
+        Self{ path: path.into() }
+    }
+}
+
+impl Plugin for ReactInputRecorderPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        if app.world().contains_resource::<ReactInputRecorder>()
+        {
+            panic!("the react input recorder is already enabled");
+        }
+        app.init_resource::<ReactInputCodecs>()
+            .insert_resource(ReactInputRecorder::new(self.path.clone()))
+            .add_systems(Last, write_react_inputs.after(poll_react_tasks));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Plugin that replays react inputs from a file with [`ReactInputReplayer`].
+///
+/// Requires [`ReactPlugin`]. Types in the recording must be registered the same way as in the recording app.
+///
+/// Logs an error if the file can't be read.
+#[derive(Debug, Clone)]
+pub struct ReactInputReplayerPlugin
+{
+    path: PathBuf,
+}
+
+impl ReactInputReplayerPlugin
+{
+    /// Makes a new plugin that replays from `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self
+    {
+        Self{ path: path.into() }
+    }
+}
+
+impl Plugin for ReactInputReplayerPlugin
+{
+    fn build(&self, app: &mut App)
+    {
+        let replayer = ReactInputReplayer::load(&self.path).unwrap_or_else(
+            |err|
+            {
+                tracing::error!(?err, path = ?self.path, "failed loading react input recording");
+                ReactInputReplayer::default()
+            }
+        );
+        app.init_resource::<ReactInputCodecs>()
+            .insert_resource(replayer)
+            .add_systems(First, replay_react_inputs.after(advance_react_timers));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,218 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+//standard shortcuts
+use core::any::TypeId;
+use std::fmt;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn reactor_mode(handle: &ReactorHandle, modes: &Query<&RegisteredReactorMode>) -> ReactorMode
+{
+    match handle
+    {
+        ReactorHandle::Persistent(_) => ReactorMode::Persistent,
+        ReactorHandle::AutoDespawn(signal) =>
+        {
+            modes.get(signal.entity()).map(|mode| mode.0).unwrap_or(ReactorMode::Cleanup)
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Information about a registered reactor. Obtained from [`ReactInspector`].
+#[derive(Debug, Clone)]
+pub struct ReactorInfo
+{
+    /// The trigger the reactor is registered for.
+    pub reactor_type: ReactorType,
+    /// The name of the component, resource, or event type referenced by the trigger, if known.
+    pub type_name: Option<&'static str>,
+    /// The reactor's system command.
+    pub sys_command: SystemCommand,
+    /// The reactor's cleanup mode.
+    pub mode: ReactorMode,
+    /// The reactor's priority. See [`ReactCommands::with_priority`].
+    pub priority: i32,
+    /// Whether the reactor has a trigger filter.
+    pub filtered: bool,
+    /// Whether the reactor is coalesced.
+    pub coalesced: bool,
+}
+
+impl fmt::Display for ReactorInfo
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{:?}", self.reactor_type)?;
+        if let Some(type_name) = self.type_name
+        {
+            write!(f, " <{type_name}>")?;
+        }
+        write!(f, " -> {:?} [{:?}, priority {}", self.sys_command, self.mode, self.priority)?;
+        if self.filtered { write!(f, ", filtered")?; }
+        if self.coalesced { write!(f, ", coalesced")?; }
+        write!(f, "]")
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter for inspecting registered reactors.
+///
+/// This is read-only and intended for debugging and tooling.
+///
+/// Timer reactors are not included.
+///
+/*
+```rust
+fn dump_reactors(inspector: ReactInspector)
+{
+    for info in inspector.all_reactors()
+    {
+        println!("{info}");
+    }
+}
+
+world.syscall((), dump_reactors);
+```
+*/
+#[derive(SystemParam)]
+pub struct ReactInspector<'w, 's>
+{
+    cache: Res<'w, ReactCache>,
+    entity_reactors: Query<'w, 's, (Entity, &'static EntityReactors)>,
+    modes: Query<'w, 's, &'static RegisteredReactorMode>,
+}
+
+impl<'w, 's> ReactInspector<'w, 's>
+{
+    /// Gets all registered reactors.
+    pub fn all_reactors(&self) -> Vec<ReactorInfo>
+    {
+        let mut reactors: Vec<ReactorInfo> = self.cache
+            .iter_reactors()
+            .map(|(reactor_type, entry)| self.info(reactor_type, entry))
+            .collect();
+        for (entity, _) in self.entity_reactors.iter()
+        {
+            self.append_entity_reactors(entity, &mut reactors);
+        }
+        reactors.extend(
+            self.cache
+                .iter_despawn_reactors()
+                .map(|(entity, handle)| self.despawn_info(entity, handle))
+        );
+        reactors
+    }
+
+    /// Gets reactors registered for a specific reactor type.
+    ///
+    /// Reactors are returned in the order they will run.
+    pub fn reactors_of(&self, reactor_type: &ReactorType) -> Vec<ReactorInfo>
+    {
+        if let ReactorType::Despawn(entity) = *reactor_type
+        {
+            return self.cache
+                .iter_despawn_reactors()
+                .filter(|(e, _)| *e == entity)
+                .map(|(entity, handle)| self.despawn_info(entity, handle))
+                .collect();
+        }
+        if let Some(entity) = reactor_type.get_entity()
+        {
+            return self.entity_reactors(entity)
+                .into_iter()
+                .filter(|info| info.reactor_type == *reactor_type)
+                .collect();
+        }
+
+        self.cache
+            .iter_reactors()
+            .filter(|(rtype, _)| rtype == reactor_type)
+            .map(|(rtype, entry)| self.info(rtype, entry))
+            .collect()
+    }
+
+    /// Gets reactors that target a specific entity, including [`despawn()`] reactors.
+    pub fn entity_reactors(&self, entity: Entity) -> Vec<ReactorInfo>
+    {
+        let mut reactors = Vec::default();
+        self.append_entity_reactors(entity, &mut reactors);
+        reactors.extend(
+            self.cache
+                .iter_despawn_reactors()
+                .filter(|(e, _)| *e == entity)
+                .map(|(entity, handle)| self.despawn_info(entity, handle))
+        );
+        reactors
+    }
+
+    /// Iterates entities that have entity-specific reactors.
+    ///
+    /// Does not include entities that only have [`despawn()`] reactors.
+    pub fn entities_with_reactors(&self) -> impl Iterator<Item = Entity> + '_
+    {
+        self.entity_reactors.iter().map(|(entity, _)| entity)
+    }
+
+    /// Gets the React components whose removals are tracked, with their type names.
+    pub fn tracked_removals(&self) -> Vec<(TypeId, Option<&'static str>)>
+    {
+        self.cache
+            .iter_tracked_removals()
+            .map(|id| (id, self.cache.type_name(id)))
+            .collect()
+    }
+
+    /// Gets the name of a component, resource, or event type referenced by a registered trigger.
+    pub fn type_name(&self, id: TypeId) -> Option<&'static str>
+    {
+        self.cache.type_name(id)
+    }
+
+    fn append_entity_reactors(&self, entity: Entity, reactors: &mut Vec<ReactorInfo>)
+    {
+        let Ok((_, entity_reactors)) = self.entity_reactors.get(entity) else { return; };
+        reactors.extend(
+            entity_reactors
+                .iter_entries()
+                .map(|(rtype, entry)| self.info(rtype.to_reactor_type(entity), entry))
+        );
+    }
+
+    fn info(&self, reactor_type: ReactorType, entry: &ReactorEntry) -> ReactorInfo
+    {
+        ReactorInfo{
+            type_name: reactor_type.get_type_id().and_then(|id| self.cache.type_name(id)),
+            reactor_type,
+            sys_command: entry.sys_command(),
+            mode: reactor_mode(entry.handle(), &self.modes),
+            priority: entry.priority(),
+            filtered: entry.is_filtered(),
+            coalesced: entry.is_coalesced(),
+        }
+    }
+
+    fn despawn_info(&self, entity: Entity, handle: &ReactorHandle) -> ReactorInfo
+    {
+        ReactorInfo{
+            reactor_type: ReactorType::Despawn(entity),
+            type_name: None,
+            sys_command: handle.sys_command(),
+            mode: reactor_mode(handle, &self.modes),
+            priority: 0,
+            filtered: false,
+            coalesced: false,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
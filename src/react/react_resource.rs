@@ -22,7 +22,7 @@ fn trigger_resource_mutation<R: ReactResource>(mut c: Commands)
 
 /// Resource wrapper that enables reacting to resource mutations.
 #[derive(Resource)]
-struct ReactResInner<R: ReactResource>
+pub(crate) struct ReactResInner<R: ReactResource>
 {
     resource: R,
 }
@@ -38,7 +38,7 @@ impl<R: ReactResource> ReactResInner<R>
     /// Mutably access the resource and trigger reactions.
     fn get_mut<'a>(&'a mut self, c: &mut Commands) -> &'a mut R
     {
-        c.react().trigger_resource_mutation::<R>();
+        c.react().trigger_resource_mutation_with_previous(self.resource.snapshot_previous());
         &mut self.resource
     }
 
@@ -51,13 +51,13 @@ impl<R: ReactResource> ReactResInner<R>
     /// Sets the resource value and triggers mutations only if the value will change.
     ///
     /// Returns the previous value if it changed.
-    fn set_if_neq(&mut self, c: &mut Commands, new: R) -> Option<R>
+    pub(crate) fn set_if_neq(&mut self, c: &mut Commands, new: R) -> Option<R>
     where
         R: PartialEq
     {
         if new == self.resource { return None; }
 
-        c.react().trigger_resource_mutation::<R>();
+        c.react().trigger_resource_mutation_with_previous(self.resource.snapshot_previous());
         let old = std::mem::replace(&mut self.resource, new);
         Some(old)
     }
@@ -85,15 +85,28 @@ impl<R: ReactResource> Deref for ReactResInner<R>
 /// Tag trait for reactive resources.
 ///
 /// It is not recommended to add `ReactResource` and `Resource` to the same struct, as it will likely cause confusion.
-pub trait ReactResource: Send + Sync + 'static {}
+///
+/// Add `#[react(track_previous)]` to the derive to expose pre-mutation values in
+/// [`ResourceMutationEvent::previous`]. The resource must implement `Clone`.
+pub trait ReactResource: Send + Sync + 'static
+{
+    /// Snapshots the resource before it is mutated with [`ReactResMut::get_mut`] or [`ReactResMut::set_if_neq`].
+    ///
+    /// The snapshot is readable by all reactors of that mutation with [`ResourceMutationEvent::previous`]. Returns
+    /// `None` by default (no snapshot is taken).
+    fn snapshot_previous(&self) -> Option<Self> where Self: Sized { None }
+}
 
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Immutable reader for reactive resources.
+///
+/// Reads are tracked by [auto-tracked reactors](ReactCommands::on_auto_tracked).
 #[derive(SystemParam)]
 pub struct ReactRes<'w, R: ReactResource>
 {
     inner: Res<'w, ReactResInner<R>>,
+    tracker: Option<Res<'w, ReactReadTracker>>,
 }
 
 impl<'w, R: ReactResource> DetectChanges for ReactRes<'w, R>
@@ -109,6 +122,7 @@ impl<'w, R: ReactResource> Deref for ReactRes<'w, R>
 
     fn deref(&self) -> &R
     {
+        if let Some(tracker) = &self.tracker { tracker.record_resource::<R>(); }
         &self.inner
     }
 }
@@ -0,0 +1,303 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+use bevy::utils::synccell::SyncCell;
+
+//standard shortcuts
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::Wake;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+type WorldRequestFn = Box<dyn FnOnce(&mut World) + Send + 'static>;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Shared slot for the output of a future that is completed by the world.
+struct Completion<O>
+{
+    inner: Mutex<(Option<O>, Option<Waker>)>,
+}
+
+impl<O> Completion<O>
+{
+    fn new() -> Arc<Self>
+    {
+        Arc::new(Self{ inner: Mutex::new((None, None)) })
+    }
+
+    fn complete(&self, output: O)
+    {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.0 = Some(output);
+        if let Some(waker) = inner.1.take() { waker.wake(); }
+    }
+
+    fn poll(&self, cx: &mut Context<'_>) -> Poll<O>
+    {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        match inner.0.take()
+        {
+            Some(output) => Poll::Ready(output),
+            None =>
+            {
+                inner.1 = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Waker for a [`ReactTask`]. Waking a task marks it for polling the next time [`poll_react_tasks`] runs.
+#[derive(Default)]
+struct TaskWaker
+{
+    woken: AtomicBool,
+}
+
+impl Wake for TaskWaker
+{
+    fn wake(self: Arc<Self>)
+    {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>)
+    {
+        self.woken.store(true, Ordering::Release);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A future spawned with [`ReactCommands::spawn_async`].
+struct ReactTask
+{
+    future: SyncCell<Pin<Box<dyn Future<Output = ()> + Send + 'static>>>,
+    waker: Arc<TaskWaker>,
+}
+
+impl ReactTask
+{
+    /// Polls the task if it was woken. Returns `true` if the task is finished.
+    fn try_poll(&mut self) -> bool
+    {
+        if !self.waker.woken.swap(false, Ordering::Acquire) { return false; }
+        let waker = Waker::from(self.waker.clone());
+        let mut cx = Context::from_waker(&waker);
+        self.future.get().as_mut().poll(&mut cx).is_ready()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that stores tasks spawned with [`ReactCommands::spawn_async`].
+#[derive(Resource, Default)]
+pub(crate) struct ReactTasks
+{
+    tasks: Vec<ReactTask>,
+    /// Requests for world access made by [`AsyncWorld`] handles.
+    requests: Arc<Mutex<Vec<WorldRequestFn>>>,
+    /// True while [`poll_react_tasks`] is running.
+    polling: bool,
+}
+
+impl ReactTasks
+{
+    fn take_requests(&self) -> Vec<WorldRequestFn>
+    {
+        std::mem::take(&mut *self.requests.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Future returned by [`ReactCommands::wait_for`] and [`ReactCommands::wait_for_with`].
+///
+/// Resolves the first time one of its triggers fires.
+pub struct ReactionFuture<O>
+{
+    completion: Arc<Completion<O>>,
+}
+
+impl<O> Future for ReactionFuture<O>
+{
+    type Output = O;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<O>
+    {
+        self.completion.poll(cx)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Future returned by [`AsyncWorld::with`].
+///
+/// The request is sent when the future is first polled.
+pub struct WorldRequest<R>
+{
+    request: Option<WorldRequestFn>,
+    requests: Arc<Mutex<Vec<WorldRequestFn>>>,
+    completion: Arc<Completion<R>>,
+}
+
+impl<R> Future for WorldRequest<R>
+{
+    type Output = R;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<R>
+    {
+        if let Some(request) = self.request.take()
+        {
+            self.requests.lock().unwrap_or_else(|e| e.into_inner()).push(request);
+        }
+        self.completion.poll(cx)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Handle for accessing the world from tasks spawned with [`ReactCommands::spawn_async`].
+///
+/// World access is only granted by the task driver (see [`poll_react_tasks`]), so futures that await
+/// [`Self::with`] will only make progress inside tasks spawned with [`ReactCommands::spawn_async`].
+#[derive(Clone)]
+pub struct AsyncWorld
+{
+    requests: Arc<Mutex<Vec<WorldRequestFn>>>,
+}
+
+impl AsyncWorld
+{
+    /// Runs `callback` with world access and returns its output.
+    ///
+    /// Commands queued by the callback, and any reactions they cause, are applied before the future resolves.
+    pub fn with<R: Send + 'static>(
+        &self,
+        callback: impl FnOnce(&mut World) -> R + Send + 'static
+    ) -> WorldRequest<R>
+    {
+        let completion = Completion::new();
+        let completion_clone = completion.clone();
+        let request: WorldRequestFn = Box::new(
+            move |world: &mut World|
+            {
+                let output = (callback)(world);
+                world.flush();
+                completion_clone.complete(output);
+            }
+        );
+
+        WorldRequest{ request: Some(request), requests: self.requests.clone(), completion }
+    }
+
+    /// Waits until one of the triggers fires.
+    ///
+    /// See [`ReactCommands::wait_for`].
+    pub fn wait_for(&self, triggers: impl ReactionTriggerBundle) -> impl Future<Output = ()> + Send + 'static
+    {
+        let request = self.with(move |world: &mut World| world.react(|rc| rc.wait_for(triggers)));
+        async move { request.await.await }
+    }
+
+    /// Waits until one of the triggers fires, then returns the output of `reader`.
+    ///
+    /// See [`ReactCommands::wait_for_with`].
+    pub fn wait_for_with<O: Send + 'static, M>(
+        &self,
+        triggers : impl ReactionTriggerBundle,
+        reader   : impl IntoSystem<(), O, M> + Send + Sync + 'static
+    ) -> impl Future<Output = O> + Send + 'static
+    {
+        let request = self.with(move |world: &mut World| world.react(|rc| rc.wait_for_with(triggers, reader)));
+        async move { request.await.await }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Creates a [`ReactionFuture`] that is completed by a one-off reactor.
+pub(crate) fn wait_for_reaction<O: Send + 'static, M>(
+    rc       : &mut ReactCommands,
+    triggers : impl ReactionTriggerBundle,
+    reader   : impl IntoSystem<(), O, M> + Send + Sync + 'static
+) -> ReactionFuture<O>
+{
+    let completion = Completion::new();
+    let completion_clone = completion.clone();
+    rc.once(triggers, reader.pipe(
+        move |In(output): In<O>, mut c: Commands|
+        {
+            completion_clone.complete(output);
+            c.queue(poll_react_tasks);
+        }
+    ));
+
+    ReactionFuture{ completion }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawns a task and polls it for the first time.
+pub(crate) fn spawn_react_task<F, Fut>(world: &mut World, task: F)
+where
+    F: FnOnce(AsyncWorld) -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let mut tasks = world.resource_mut::<ReactTasks>();
+    let async_world = AsyncWorld{ requests: tasks.requests.clone() };
+    let waker = Arc::new(TaskWaker::default());
+    waker.woken.store(true, Ordering::Release);
+    tasks.tasks.push(ReactTask{ future: SyncCell::new(Box::pin((task)(async_world))), waker });
+
+    poll_react_tasks(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Polls woken tasks spawned with [`ReactCommands::spawn_async`] and grants their requests for world access.
+///
+/// Polling repeats until the tasks stop requesting world access, so a task will run until it waits for something
+/// other than the world. Tasks are also polled immediately when a [`ReactCommands::wait_for`] reaction wakes them,
+/// so most tasks will resume inside the reaction tree that woke them.
+///
+/// This system is automatically run in `Last` to resume tasks woken by other sources. Use
+/// [`ReactAppExt::poll_react_tasks_in`] to also run it in other schedules.
+pub fn poll_react_tasks(world: &mut World)
+{
+    // Tasks woken while polling will be picked up by the outer call.
+    let Some(mut tasks) = world.get_resource_mut::<ReactTasks>() else { return; };
+    if tasks.polling { return; }
+    tasks.polling = true;
+
+    loop
+    {
+        // Poll woken tasks.
+        let mut tasks = world.resource_mut::<ReactTasks>();
+        tasks.tasks.retain_mut(|task| !task.try_poll());
+
+        // Grant world access.
+        let requests = tasks.take_requests();
+        if requests.is_empty() { break; }
+        for request in requests
+        {
+            (request)(world);
+        }
+    }
+
+    world.resource_mut::<ReactTasks>().polling = false;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
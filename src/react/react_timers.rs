@@ -0,0 +1,321 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+use std::time::Duration;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// One-off callback for delayed events.
+pub(crate) type TimerCallback = Box<dyn FnOnce(&mut World) + Send + Sync + 'static>;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Action to take when a timer expires.
+enum TimerAction
+{
+    /// Run a reactor.
+    ///
+    /// If `period` is set then the timer will be rescheduled after it expires.
+    Reactor
+    {
+        handle: ReactorHandle,
+        period: Option<Duration>,
+    },
+    /// Run a one-off callback.
+    ///
+    /// The `id` is a placeholder entity used to revoke the callback. It is despawned when the callback expires or is
+    /// revoked.
+    Callback
+    {
+        id: SystemCommand,
+        callback: TimerCallback,
+    },
+}
+
+impl TimerAction
+{
+    fn id(&self) -> SystemCommand
+    {
+        match self
+        {
+            Self::Reactor{ handle, .. } => handle.sys_command(),
+            Self::Callback{ id, .. }    => *id,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// When a timer expires.
+#[derive(Copy, Clone)]
+enum TimerDeadline
+{
+    /// Expires when the frame counter reaches this value.
+    Frame(u64),
+    /// Expires when [`Time::elapsed`] reaches this value.
+    Elapsed(Duration),
+}
+
+impl TimerDeadline
+{
+    fn is_expired(&self, frame: u64, elapsed: Duration) -> bool
+    {
+        match *self
+        {
+            Self::Frame(deadline)   => deadline <= frame,
+            Self::Elapsed(deadline) => deadline <= elapsed,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+struct ReactTimer
+{
+    deadline : TimerDeadline,
+    action   : TimerAction,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Queue of pending timers for timer triggers and delayed events.
+///
+/// Expired timers are dispatched by [`schedule_timer_reactors`].
+#[derive(Resource, Default)]
+pub(crate) struct ReactTimers
+{
+    /// Frame counter, advanced once per frame in `First`.
+    frame: u64,
+    /// Pending timers in the order they were added.
+    timers: Vec<ReactTimer>,
+    /// Cached buffer for collecting expired timers.
+    expired_buffer: Vec<TimerAction>,
+}
+
+impl ReactTimers
+{
+    fn add_frame_timer(&mut self, frames: u32, action: TimerAction)
+    {
+        let deadline = TimerDeadline::Frame(self.frame + frames as u64);
+        self.timers.push(ReactTimer{ deadline, action });
+    }
+
+    fn add_duration_timer(&mut self, elapsed: Duration, delay: Duration, action: TimerAction)
+    {
+        let deadline = TimerDeadline::Elapsed(elapsed + delay);
+        self.timers.push(ReactTimer{ deadline, action });
+    }
+
+    /// Revokes all timers of a reactor or delayed event.
+    ///
+    /// Returns `true` if a delayed event was revoked.
+    pub(crate) fn revoke_timer(&mut self, id: SystemCommand) -> bool
+    {
+        let mut revoked_callback = false;
+        self.timers.retain(
+            |timer|
+            {
+                if timer.action.id() != id { return true; }
+                revoked_callback |= matches!(timer.action, TimerAction::Callback{ .. });
+                false
+            }
+        );
+        revoked_callback
+    }
+
+    /// Moves expired timers to the expired buffer and reschedules repeating timers.
+    fn collect_expired(&mut self, elapsed: Duration)
+    {
+        let frame = self.frame;
+        let mut idx = 0;
+        while idx < self.timers.len()
+        {
+            let timer = &mut self.timers[idx];
+            if !timer.deadline.is_expired(frame, elapsed) { idx += 1; continue; }
+
+            // reschedule repeating timers
+            if let (TimerAction::Reactor{ handle, period: Some(period) }, TimerDeadline::Elapsed(deadline)) =
+                (&timer.action, &mut timer.deadline)
+            {
+                // Missed periods are skipped, the reactor will run at most once per drain.
+                *deadline += *period;
+                if *deadline <= elapsed { *deadline = elapsed + *period; }
+                self.expired_buffer.push(TimerAction::Reactor{ handle: handle.clone(), period: None });
+                idx += 1;
+                continue;
+            }
+
+            self.expired_buffer.push(self.timers.remove(idx).action);
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn current_elapsed(time: &Option<Res<Time>>) -> Duration
+{
+    time.as_ref().map(|t| t.elapsed()).unwrap_or_default()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn register_frame_timer(
+    In((frames, handle)) : In<(u32, ReactorHandle)>,
+    mut timers           : ResMut<ReactTimers>,
+){
+    timers.add_frame_timer(frames, TimerAction::Reactor{ handle, period: None });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn register_duration_timer(
+    In((delay, period, handle)) : In<(Duration, Option<Duration>, ReactorHandle)>,
+    mut timers                  : ResMut<ReactTimers>,
+    time                        : Option<Res<Time>>,
+){
+    timers.add_duration_timer(current_elapsed(&time), delay, TimerAction::Reactor{ handle, period });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Schedules a callback to run after a delay.
+///
+/// The `id` entity will be despawned when the callback expires or is revoked.
+pub(crate) fn register_delayed_callback(
+    In((delay, id, callback)) : In<(Duration, SystemCommand, TimerCallback)>,
+    mut timers                : ResMut<ReactTimers>,
+    time                      : Option<Res<Time>>,
+){
+    timers.add_duration_timer(current_elapsed(&time), delay, TimerAction::Callback{ id, callback });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Advances the frame counter used by [`after_frames`] triggers.
+pub(crate) fn advance_react_timers(mut timers: ResMut<ReactTimers>)
+{
+    timers.frame += 1;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Queues reactors and delayed events whose timers have expired.
+///
+/// This system is automatically run in `PreUpdate`. Use [`ReactAppExt::drain_react_timers_in`] to also run it in
+/// other schedules.
+///
+/// Duration-based timers use [`Time::elapsed`], so they require Bevy's `TimePlugin`.
+pub fn schedule_timer_reactors(world: &mut World)
+{
+    let elapsed = world.get_resource::<Time>().map(|t| t.elapsed()).unwrap_or_default();
+    let Some(mut timers) = world.get_resource_mut::<ReactTimers>() else { return; };
+    timers.collect_expired(elapsed);
+    if timers.expired_buffer.is_empty() { return; }
+    let mut expired = std::mem::take(&mut timers.expired_buffer);
+
+    for action in expired.drain(..)
+    {
+        match action
+        {
+            TimerAction::Reactor{ handle, .. } =>
+            {
+                world.commands().queue(ReactionCommand::Timer{ reactor: handle.sys_command(), handle });
+            }
+            TimerAction::Callback{ id, callback } =>
+            {
+                world.despawn(*id);
+                world.commands().queue(callback);
+            }
+        }
+    }
+
+    // return the cached buffer
+    world.resource_mut::<ReactTimers>().expired_buffer = expired;
+    world.flush();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reaction trigger that fires once after a number of frames.
+/// - Frames are counted from the frame when the trigger is registered, see [`schedule_timer_reactors`].
+#[derive(Copy, Clone)]
+pub struct AfterFramesTrigger(u32);
+
+impl ReactionTrigger for AfterFramesTrigger
+{
+    fn reactor_type(&self) -> ReactorType
+    {
+        ReactorType::Timer
+    }
+
+    fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        commands.syscall((self.0, handle.clone()), register_frame_timer);
+    }
+}
+
+/// Returns an [`AfterFramesTrigger`] reaction trigger.
+pub fn after_frames(frames: u32) -> AfterFramesTrigger { AfterFramesTrigger(frames) }
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reaction trigger that fires once after a duration has elapsed.
+/// - The duration is measured with [`Time::elapsed`] from when the trigger is registered.
+#[derive(Copy, Clone)]
+pub struct AfterDurationTrigger(Duration);
+
+impl ReactionTrigger for AfterDurationTrigger
+{
+    fn reactor_type(&self) -> ReactorType
+    {
+        ReactorType::Timer
+    }
+
+    fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        commands.syscall((self.0, None, handle.clone()), register_duration_timer);
+    }
+}
+
+/// Returns an [`AfterDurationTrigger`] reaction trigger.
+pub fn after_duration(duration: Duration) -> AfterDurationTrigger { AfterDurationTrigger(duration) }
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reaction trigger that fires repeatedly each time a period elapses.
+/// - The period is measured with [`Time::elapsed`] from when the trigger is registered.
+/// - The reactor will run at most once each time timers are drained, even if multiple periods elapsed.
+#[derive(Copy, Clone)]
+pub struct EveryTrigger(Duration);
+
+impl ReactionTrigger for EveryTrigger
+{
+    fn reactor_type(&self) -> ReactorType
+    {
+        ReactorType::Timer
+    }
+
+    fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        commands.syscall((self.0, Some(self.0), handle.clone()), register_duration_timer);
+    }
+}
+
+/// Returns an [`EveryTrigger`] reaction trigger.
+///
+/// Panics if `period` is zero.
+pub fn every(period: Duration) -> EveryTrigger
+{
+    if period.is_zero() { panic!("every() trigger period must be non-zero"); }
+    EveryTrigger(period)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
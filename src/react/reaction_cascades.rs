@@ -0,0 +1,394 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+use std::collections::VecDeque;
+use std::fmt::Write;
+use std::path::Path;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn escape_json(s: &str) -> String
+{
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars()
+    {
+        match c
+        {
+            '"'  => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => { let _ = write!(escaped, "\\u{:04x}", c as u32); }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn escape_dot(s: &str) -> String
+{
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Stores the reaction kind and source of the next system command to run.
+#[derive(Resource, Default, Debug)]
+pub(crate) struct PendingReactionKind(Option<(ReactionKind, Option<Entity>)>);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Marks the next system command run as a reaction of the given kind.
+pub(crate) fn prepare_cascade_node(world: &mut World, kind: ReactionKind, source: Option<Entity>)
+{
+    let Some(mut pending) = world.get_resource_mut::<PendingReactionKind>() else { return; };
+    pending.0 = Some((kind, source));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Takes the reaction kind and source of the system command that is about to run.
+///
+/// Defaults to [`ReactionKind::SystemCommand`] if the system command was run directly.
+pub(crate) fn take_cascade_node(world: &mut World) -> (ReactionKind, Option<Entity>)
+{
+    world.get_resource_mut::<PendingReactionKind>()
+        .and_then(|mut pending| pending.0.take())
+        .unwrap_or((ReactionKind::SystemCommand, None))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The kind of reaction that caused a system command to run (e.g. a node in a [`ReactionCascade`]).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ReactionKind
+{
+    /// A system command that was run directly.
+    SystemCommand,
+    /// A system command that received a system event or system request.
+    SystemEvent,
+    /// A reaction to a resource mutation.
+    ResourceMutation,
+    /// A reaction to a component insertion.
+    Insertion,
+    /// A reaction to a component mutation.
+    Mutation,
+    /// A reaction to a component removal.
+    Removal,
+    /// A reaction to a plain component being added.
+    Added,
+    /// A reaction to a plain component being changed.
+    Changed,
+    /// A reaction to an entity despawn.
+    Despawn,
+    /// A reaction to an entity event.
+    EntityEvent,
+    /// A reaction to an expired timer.
+    Timer,
+    /// A reaction to a broadcast event.
+    Broadcast,
+    /// A reaction to a batch of coalesced reactions.
+    Coalesced,
+}
+
+impl From<EntityReactionType> for ReactionKind
+{
+    fn from(reaction_type: EntityReactionType) -> Self
+    {
+        match reaction_type
+        {
+            EntityReactionType::Insertion(_) => Self::Insertion,
+            EntityReactionType::Mutation(_)  => Self::Mutation,
+            EntityReactionType::Removal(_)   => Self::Removal,
+            EntityReactionType::Added(_)     => Self::Added,
+            EntityReactionType::Changed(_)   => Self::Changed,
+            EntityReactionType::Event(_)     => Self::EntityEvent,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A system command run within a [`ReactionCascade`].
+#[derive(Debug, Clone)]
+pub struct CascadeNode
+{
+    /// What caused the system command to run.
+    pub kind: ReactionKind,
+    /// The system command that ran.
+    pub reactor: SystemCommand,
+    /// The name of the system command's system, if known.
+    pub name: Option<&'static str>,
+    /// The entity that triggered the reaction, if any (e.g. the target of an entity event).
+    pub source: Option<Entity>,
+    /// The nesting depth of the system command. The root of a cascade has depth 0.
+    pub depth: usize,
+    /// The index of the node's parent in [`ReactionCascade::nodes`].
+    pub parent: Option<usize>,
+    /// The indices of the node's children in [`ReactionCascade::nodes`], in the order they ran.
+    pub children: Vec<usize>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A tree of system commands that ran recursively, starting from a root system command.
+///
+/// Nodes are stored in the order they started running, so the root is always the first node.
+#[derive(Debug, Clone, Default)]
+pub struct ReactionCascade
+{
+    nodes: Vec<CascadeNode>,
+}
+
+impl ReactionCascade
+{
+    /// Gets the root node, which is the root cause of the cascade.
+    pub fn root(&self) -> &CascadeNode
+    {
+        &self.nodes[0]
+    }
+
+    /// Gets all nodes in the order they started running.
+    pub fn nodes(&self) -> &[CascadeNode]
+    {
+        &self.nodes
+    }
+
+    /// Gets the children of a node.
+    pub fn children(&self, node: usize) -> impl Iterator<Item = &CascadeNode> + '_
+    {
+        self.nodes[node].children.iter().map(|idx| &self.nodes[*idx])
+    }
+
+    /// Gets the number of nodes in the cascade.
+    pub fn num_nodes(&self) -> usize
+    {
+        self.nodes.len()
+    }
+
+    /// Gets the maximum nesting depth of the cascade.
+    pub fn depth(&self) -> usize
+    {
+        self.nodes.iter().map(|node| node.depth).max().unwrap_or_default()
+    }
+
+    fn write_json(&self, out: &mut String)
+    {
+        out.push_str("{\"nodes\":[");
+        for (idx, node) in self.nodes.iter().enumerate()
+        {
+            if idx > 0 { out.push(','); }
+            let _ = write!(out, "{{\"id\":{idx},\"kind\":\"{:?}\",\"reactor\":\"{}\"", node.kind, node.reactor.0);
+            match node.name
+            {
+                Some(name) => { let _ = write!(out, ",\"name\":\"{}\"", escape_json(name)); }
+                None       => out.push_str(",\"name\":null"),
+            }
+            match node.source
+            {
+                Some(source) => { let _ = write!(out, ",\"source\":\"{source}\""); }
+                None         => out.push_str(",\"source\":null"),
+            }
+            let _ = write!(out, ",\"depth\":{}", node.depth);
+            match node.parent
+            {
+                Some(parent) => { let _ = write!(out, ",\"parent\":{parent}"); }
+                None         => out.push_str(",\"parent\":null"),
+            }
+            out.push_str(",\"children\":[");
+            for (i, child) in node.children.iter().enumerate()
+            {
+                if i > 0 { out.push(','); }
+                let _ = write!(out, "{child}");
+            }
+            out.push_str("]}");
+        }
+        out.push_str("]}");
+    }
+
+    fn write_dot(&self, cascade_idx: usize, out: &mut String)
+    {
+        let _ = writeln!(out, "  subgraph cluster_{cascade_idx} {{");
+        let _ = writeln!(out, "    label=\"cascade {cascade_idx}\";");
+        for (idx, node) in self.nodes.iter().enumerate()
+        {
+            let mut label = format!("{:?}\\n{}", node.kind, node.reactor.0);
+            if let Some(name) = node.name
+            {
+                let _ = write!(label, "\\n{}", escape_dot(name));
+            }
+            if let Some(source) = node.source
+            {
+                let _ = write!(label, "\\nsource: {source}");
+            }
+            let _ = writeln!(out, "    c{cascade_idx}_{idx} [label=\"{label}\"];");
+        }
+        for (idx, node) in self.nodes.iter().enumerate()
+        {
+            for child in node.children.iter()
+            {
+                let _ = writeln!(out, "    c{cascade_idx}_{idx} -> c{cascade_idx}_{child};");
+            }
+        }
+        out.push_str("  }\n");
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that records the last N reaction cascades.
+///
+/// A cascade is a tree of system commands that start running while another system command is running (e.g. a broadcast
+/// reactor that mutates a resource, which triggers resource mutation reactors). Every system command that runs outside
+/// another system command starts a new cascade.
+///
+/// Cascades are only recorded if this resource exists. Use [`ReactAppExt::enable_cascade_recorder`] to add it.
+///
+/*
+```rust
+fn dump_cascades(recorder: Res<CascadeRecorder>)
+{
+    recorder.export_dot("cascades.dot").unwrap();
+    recorder.export_json("cascades.json").unwrap();
+}
+```
+*/
+#[derive(Resource, Debug)]
+pub struct CascadeRecorder
+{
+    max_cascades: usize,
+    cascades: VecDeque<ReactionCascade>,
+    /// The cascade currently being recorded.
+    current: ReactionCascade,
+    /// Indices of the nodes that are currently running.
+    stack: Vec<usize>,
+}
+
+impl CascadeRecorder
+{
+    /// Makes a new recorder that keeps the last `max_cascades` cascades.
+    pub fn new(max_cascades: usize) -> Self
+    {
+        Self{
+            max_cascades,
+            cascades: VecDeque::default(),
+            current: ReactionCascade::default(),
+            stack: Vec::default(),
+        }
+    }
+
+    /// Iterates recorded cascades from oldest to newest.
+    pub fn cascades(&self) -> impl Iterator<Item = &ReactionCascade> + '_
+    {
+        self.cascades.iter()
+    }
+
+    /// Gets the most recently finished cascade.
+    pub fn latest(&self) -> Option<&ReactionCascade>
+    {
+        self.cascades.back()
+    }
+
+    /// Clears recorded cascades.
+    pub fn clear(&mut self)
+    {
+        self.cascades.clear();
+    }
+
+    /// Exports recorded cascades as a Graphviz DOT graph.
+    ///
+    /// Each cascade is a cluster, and each node is labeled with its reaction kind, reactor, system name, and source
+    /// entity.
+    pub fn to_dot(&self) -> String
+    {
+        let mut out = String::from("digraph cascades {\n  node [shape=box];\n");
+        for (idx, cascade) in self.cascades.iter().enumerate()
+        {
+            cascade.write_dot(idx, &mut out);
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Exports recorded cascades as a JSON array.
+    ///
+    /// Each cascade is an object with a `nodes` array. Nodes have the fields `id`, `kind`, `reactor`, `name`, `source`,
+    /// `depth`, `parent`, and `children`.
+    pub fn to_json(&self) -> String
+    {
+        let mut out = String::from("[");
+        for (idx, cascade) in self.cascades.iter().enumerate()
+        {
+            if idx > 0 { out.push(','); }
+            cascade.write_json(&mut out);
+        }
+        out.push(']');
+        out
+    }
+
+    /// Writes [`Self::to_dot`] to a file.
+    pub fn export_dot(&self, path: impl AsRef<Path>) -> std::io::Result<()>
+    {
+        std::fs::write(path, self.to_dot())
+    }
+
+    /// Writes [`Self::to_json`] to a file.
+    pub fn export_json(&self, path: impl AsRef<Path>) -> std::io::Result<()>
+    {
+        std::fs::write(path, self.to_json())
+    }
+
+    /// Starts recording a system command.
+    pub(crate) fn start(
+        &mut self,
+        reactor : SystemCommand,
+        name    : Option<&'static str>,
+        node    : (ReactionKind, Option<Entity>)
+    ){
+        let (kind, source) = node;
+        let parent = self.stack.last().copied();
+        let idx = self.current.nodes.len();
+        self.current.nodes.push(CascadeNode{
+            kind,
+            reactor,
+            name,
+            source,
+            depth: self.stack.len(),
+            parent,
+            children: Vec::default(),
+        });
+        if let Some(parent) = parent
+        {
+            self.current.nodes[parent].children.push(idx);
+        }
+        self.stack.push(idx);
+    }
+
+    /// Finishes recording a system command.
+    pub(crate) fn end(&mut self)
+    {
+        if self.stack.pop().is_none() { return; }
+        if !self.stack.is_empty() { return; }
+
+        // The cascade is finished.
+        let cascade = std::mem::take(&mut self.current);
+        if self.max_cascades == 0 { return; }
+        if self.cascades.len() >= self.max_cascades
+        {
+            self.cascades.pop_front();
+        }
+        self.cascades.push_back(cascade);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,112 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that limits how far a reaction cascade can go before it is considered an infinite loop.
+///
+/// A flush starts when a system command runs outside any other system command, and includes all system commands that
+/// run recursively inside it (i.e. a reaction cascade, see [`CascadeRecorder`]).
+///
+/// If a limit is exceeded, all remaining system commands in the flush are aborted, the offending chain of system
+/// commands is logged, and a [`ReactionLoopDetected`] event is broadcast after the flush ends.
+///
+/// Insert this resource to change the limits.
+#[derive(Resource, Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ReactionLimits
+{
+    /// The maximum number of nested system commands.
+    ///
+    /// Defaults to `64`.
+    pub max_depth: usize,
+    /// The maximum number of system commands that can run in one flush.
+    ///
+    /// Defaults to `100_000`.
+    pub max_reactions: usize,
+}
+
+impl Default for ReactionLimits
+{
+    fn default() -> Self
+    {
+        Self{ max_depth: 64, max_reactions: 100_000 }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Broadcast event sent when a [`ReactionLimits`] limit is exceeded.
+///
+/// The event is sent after the aborted flush ends, so reactors for this event run in a new flush.
+#[derive(Debug, Clone)]
+pub struct ReactionLoopDetected
+{
+    /// The chain of running system commands when the limit was exceeded, from the root of the flush to the system
+    /// command that was aborted, with their system names if known.
+    pub chain: Vec<(SystemCommand, Option<&'static str>)>,
+    /// The nesting depth when the limit was exceeded.
+    pub depth: usize,
+    /// The number of system commands that ran in the flush before the limit was exceeded.
+    pub reactions: usize,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks the chain of running system commands in order to enforce [`ReactionLimits`].
+#[derive(Resource, Default, Debug)]
+pub(crate) struct ReactionLoopGuard
+{
+    /// System commands whose runner is currently active.
+    chain: Vec<(SystemCommand, Option<&'static str>)>,
+    /// Set when a limit is exceeded. Remaining system commands in the flush will be aborted.
+    detected: Option<ReactionLoopDetected>,
+}
+
+impl ReactionLoopGuard
+{
+    /// Returns `true` if `command` should be aborted.
+    ///
+    /// `reactions` is the number of system commands that have run in the current flush.
+    pub(crate) fn check(&mut self, limits: ReactionLimits, command: SystemCommand, reactions: usize) -> bool
+    {
+        if self.detected.is_some() { return true; }
+
+        let depth = self.chain.len();
+        if depth < limits.max_depth && reactions < limits.max_reactions { return false; }
+
+        let mut chain = self.chain.clone();
+        chain.push((command, None));
+        tracing::error!(depth, reactions, ?chain, "reaction limit exceeded, aborting the reaction cascade; this is \
+            likely an infinite reaction loop");
+        self.detected = Some(ReactionLoopDetected{ chain, depth, reactions });
+
+        true
+    }
+
+    /// Records that a system command is running.
+    pub(crate) fn push(&mut self, command: SystemCommand, name: Option<&'static str>)
+    {
+        self.chain.push((command, name));
+    }
+
+    /// Records that the most recent system command finished.
+    pub(crate) fn pop(&mut self)
+    {
+        self.chain.pop();
+    }
+
+    /// Takes the detected loop once the flush has ended.
+    pub(crate) fn take_detected(&mut self) -> Option<ReactionLoopDetected>
+    {
+        self.detected.take()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -39,6 +39,57 @@ impl<R: ReactionTrigger> ReactionTriggerBundle for R
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Helper trait for reaction triggers that can be filtered based on their trigger data.
+///
+/// Filters are evaluated before a reaction is scheduled, so reactors will not run for data they don't care about.
+///
+/*
+```rust
+c.react().on(broadcast::<u32>().filter(|e: &u32| *e > 10), my_reactor);
+c.react().on(entity_mutation::<Health>(entity).when(|h: &Health| h.0 == 0), on_death);
+```
+*/
+pub trait FilterableTrigger: ReactionTrigger
+{
+    /// The trigger data passed to filters.
+    ///
+    /// For component triggers this is the component value, for resource triggers the resource value, and for
+    /// event triggers the event.
+    type Data: 'static;
+
+    /// Registers a trigger with [`ReactCommands`] that will only schedule reactions if `filter` passes.
+    fn register_filtered(&self, commands: &mut Commands, handle: &ReactorHandle, filter: TriggerFilter);
+
+    /// Wraps the trigger in a [`FilteredTrigger`] that only schedules reactions if `filter` returns `true`.
+    fn filter<F>(self, filter: F) -> FilteredTrigger<Self, F>
+    where
+        F: Fn(&Self::Data) -> bool + Copy + Send + Sync + 'static
+    {
+        FilteredTrigger::new(self, filter)
+    }
+
+    /// Equivalent to [`Self::filter`]. Reads better for component and resource triggers.
+    fn when<F>(self, filter: F) -> FilteredTrigger<Self, F>
+    where
+        F: Fn(&Self::Data) -> bool + Copy + Send + Sync + 'static
+    {
+        FilteredTrigger::new(self, filter)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Helper trait for reaction triggers whose reactions can be coalesced.
+///
+/// Use [`coalesce`] to wrap a trigger so its reactions are batched.
+pub trait CoalescableTrigger: ReactionTrigger
+{
+    /// Registers a trigger with [`ReactCommands`] whose reactions will be coalesced.
+    fn register_coalesced(&self, commands: &mut Commands, handle: &ReactorHandle);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Helper trait for [`EntityTriggerBundle`].
 pub trait EntityTrigger: Copy + Clone + Send + Sync + 'static
 {
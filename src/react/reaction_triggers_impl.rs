@@ -20,6 +20,14 @@ fn track_removals<C: ReactComponent>(mut cache: ResMut<ReactCache>)
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
+fn track_changes<C: Component>(mut commands: Commands, mut cache: ResMut<ReactCache>)
+{
+    cache.track_changes::<C>(&mut commands);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Tag for tracking despawns of entities with despawn reactors.
 #[derive(Component)]
 struct DespawnTracker
@@ -39,50 +47,115 @@ impl Drop for DespawnTracker
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn register_insertion_reactor<C: ReactComponent>(In(handle): In<ReactorHandle>, mut cache: ResMut<ReactCache>)
-{
-    cache.register_insertion_reactor::<C>(handle);
+fn register_insertion_reactor<C: ReactComponent>(
+    In(entry)  : In<ReactorEntry>,
+    mut cache  : ResMut<ReactCache>,
+    priorities : Query<&ReactorPriority>,
+){
+    let entry = entry.prioritized(&priorities);
+    cache.register_insertion_reactor::<C>(entry);
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn register_mutation_reactor<C: ReactComponent>(In(handle): In<ReactorHandle>, mut cache: ResMut<ReactCache>)
-{
-    cache.register_mutation_reactor::<C>(handle);
+fn register_mutation_reactor<C: ReactComponent>(
+    In(entry)  : In<ReactorEntry>,
+    mut cache  : ResMut<ReactCache>,
+    priorities : Query<&ReactorPriority>,
+){
+    let entry = entry.prioritized(&priorities);
+    cache.register_mutation_reactor::<C>(entry);
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn register_removal_reactor<C: ReactComponent>(In(handle): In<ReactorHandle>, mut cache: ResMut<ReactCache>)
-{
+fn register_removal_reactor<C: ReactComponent>(
+    In(entry)  : In<ReactorEntry>,
+    mut cache  : ResMut<ReactCache>,
+    priorities : Query<&ReactorPriority>,
+){
+    let entry = entry.prioritized(&priorities);
     cache.track_removals::<C>();
-    cache.register_removal_reactor::<C>(handle);
+    cache.register_removal_reactor::<C>(entry);
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn register_any_entity_event_reactor<E: 'static>(In(handle): In<ReactorHandle>, mut cache: ResMut<ReactCache>)
-{
-    cache.register_any_entity_event_reactor::<E>(handle);
+fn register_added_reactor<C: Component>(
+    In(entry)    : In<ReactorEntry>,
+    mut commands : Commands,
+    mut cache    : ResMut<ReactCache>,
+    priorities   : Query<&ReactorPriority>,
+){
+    let entry = entry.prioritized(&priorities);
+    cache.track_changes::<C>(&mut commands);
+    cache.register_added_reactor::<C>(entry);
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn register_resource_mutation_reactor<R: ReactResource>(In(handle): In<ReactorHandle>, mut cache: ResMut<ReactCache>)
-{
-    cache.register_resource_mutation_reactor::<R>(handle);
+fn register_changed_reactor<C: Component>(
+    In(entry)    : In<ReactorEntry>,
+    mut commands : Commands,
+    mut cache    : ResMut<ReactCache>,
+    priorities   : Query<&ReactorPriority>,
+){
+    let entry = entry.prioritized(&priorities);
+    cache.track_changes::<C>(&mut commands);
+    cache.register_changed_reactor::<C>(entry);
 }
 
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
-fn register_broadcast_reactor<E: Send + Sync + 'static>(In(handle): In<ReactorHandle>, mut cache: ResMut<ReactCache>)
-{
-    cache.register_broadcast_reactor::<E>(handle);
+fn register_any_entity_event_reactor<E: 'static>(
+    In(entry)  : In<ReactorEntry>,
+    mut cache  : ResMut<ReactCache>,
+    priorities : Query<&ReactorPriority>,
+){
+    let entry = entry.prioritized(&priorities);
+    cache.register_any_entity_event_reactor::<E>(entry);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn register_resource_mutation_reactor<R: ReactResource>(
+    In(entry)  : In<ReactorEntry>,
+    mut cache  : ResMut<ReactCache>,
+    priorities : Query<&ReactorPriority>,
+){
+    let entry = entry.prioritized(&priorities);
+    cache.register_resource_mutation_reactor::<R>(entry);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Registers a resource mutation reactor for a type-erased resource.
+pub(crate) fn register_resource_mutation_reactor_by_id(
+    In((id, entry)) : In<(TypeId, ReactorEntry)>,
+    mut cache       : ResMut<ReactCache>,
+    priorities      : Query<&ReactorPriority>,
+){
+    let entry = entry.prioritized(&priorities);
+    cache.register_resource_mutation_reactor_by_id(id, entry);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn register_broadcast_reactor<E: Send + Sync + 'static>(
+    In(entry)  : In<ReactorEntry>,
+    mut cache  : ResMut<ReactCache>,
+    priorities : Query<&ReactorPriority>,
+){
+    let entry = entry.prioritized(&priorities);
+    cache.register_broadcast_reactor::<E>(entry);
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -114,22 +187,43 @@ fn register_despawn_reactor(
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Adds a reactor to an entity, recording the name of the trigger type `T`.
+///
+/// The reactor will be invoked when the trigger targets the entity.
+pub(crate) fn register_entity_reactor<T: 'static>(
+    In((
+        rtype,
+        entity,
+        entry
+    ))           : In<(EntityReactionType, Entity, ReactorEntry)>,
+    mut commands : Commands,
+    mut cache    : ResMut<ReactCache>,
+){
+    cache.note_type::<T>();
+    commands.syscall((rtype, entity, entry), register_entity_reactor_by_id);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Adds a reactor to an entity.
 ///
 /// The reactor will be invoked when the trigger targets the entity.
-fn register_entity_reactor(
+pub(crate) fn register_entity_reactor_by_id(
     In((
         rtype,
         entity,
-        handle
-    ))                  : In<(EntityReactionType, Entity, ReactorHandle)>,
+        entry
+    ))                  : In<(EntityReactionType, Entity, ReactorEntry)>,
     mut commands        : Commands,
     mut entity_reactors : Query<&mut EntityReactors>,
+    priorities          : Query<&ReactorPriority>,
 ){
+    let entry = entry.prioritized(&priorities);
+
     // add callback to entity
     match entity_reactors.get_mut(entity)
     {
-        Ok(mut entity_reactors) => entity_reactors.insert(rtype, handle),
+        Ok(mut entity_reactors) => entity_reactors.insert(rtype, entry),
         _ =>
         {
             let Some(mut entity_commands) = commands.get_entity(entity) else { return; };
@@ -138,7 +232,7 @@ fn register_entity_reactor(
             let mut entity_reactors = EntityReactors::default();
 
             // add callback and insert to entity
-            entity_reactors.insert(rtype, handle);
+            entity_reactors.insert(rtype, entry);
             entity_commands.insert(entity_reactors);
         }
     }
@@ -163,7 +257,25 @@ impl<C: ReactComponent> ReactionTrigger for InsertionTrigger<C>
 
     fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
     {
-        commands.syscall(handle.clone(), register_insertion_reactor::<C>);
+        commands.syscall(ReactorEntry::new(handle.clone()), register_insertion_reactor::<C>);
+    }
+}
+
+impl<C: ReactComponent> FilterableTrigger for InsertionTrigger<C>
+{
+    type Data = C;
+
+    fn register_filtered(&self, commands: &mut Commands, handle: &ReactorHandle, filter: TriggerFilter)
+    {
+        commands.syscall(ReactorEntry::new(handle.clone()).with_filter(filter), register_insertion_reactor::<C>);
+    }
+}
+
+impl<C: ReactComponent> CoalescableTrigger for InsertionTrigger<C>
+{
+    fn register_coalesced(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        commands.syscall(ReactorEntry::new(handle.clone()).coalesced(), register_insertion_reactor::<C>);
     }
 }
 
@@ -188,7 +300,25 @@ impl<C: ReactComponent> ReactionTrigger for MutationTrigger<C>
 
     fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
     {
-        commands.syscall(handle.clone(), register_mutation_reactor::<C>);
+        commands.syscall(ReactorEntry::new(handle.clone()), register_mutation_reactor::<C>);
+    }
+}
+
+impl<C: ReactComponent> FilterableTrigger for MutationTrigger<C>
+{
+    type Data = C;
+
+    fn register_filtered(&self, commands: &mut Commands, handle: &ReactorHandle, filter: TriggerFilter)
+    {
+        commands.syscall(ReactorEntry::new(handle.clone()).with_filter(filter), register_mutation_reactor::<C>);
+    }
+}
+
+impl<C: ReactComponent> CoalescableTrigger for MutationTrigger<C>
+{
+    fn register_coalesced(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        commands.syscall(ReactorEntry::new(handle.clone()).coalesced(), register_mutation_reactor::<C>);
     }
 }
 
@@ -213,7 +343,7 @@ impl<C: ReactComponent> ReactionTrigger for RemovalTrigger<C>
 
     fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
     {
-        commands.syscall(handle.clone(), register_removal_reactor::<C>);
+        commands.syscall(ReactorEntry::new(handle.clone()), register_removal_reactor::<C>);
     }
 }
 
@@ -237,8 +367,28 @@ impl<C: ReactComponent> ReactionTrigger for EntityInsertionTrigger<C>
 
     fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
     {
-        let handle = handle.clone();
-        commands.syscall((EntityReactionType::Insertion(TypeId::of::<C>()), self.0, handle), register_entity_reactor);
+        let entry = ReactorEntry::new(handle.clone());
+        commands.syscall((EntityReactionType::Insertion(TypeId::of::<C>()), self.0, entry), register_entity_reactor::<C>);
+    }
+}
+
+impl<C: ReactComponent> FilterableTrigger for EntityInsertionTrigger<C>
+{
+    type Data = C;
+
+    fn register_filtered(&self, commands: &mut Commands, handle: &ReactorHandle, filter: TriggerFilter)
+    {
+        let entry = ReactorEntry::new(handle.clone()).with_filter(filter);
+        commands.syscall((EntityReactionType::Insertion(TypeId::of::<C>()), self.0, entry), register_entity_reactor::<C>);
+    }
+}
+
+impl<C: ReactComponent> CoalescableTrigger for EntityInsertionTrigger<C>
+{
+    fn register_coalesced(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        let entry = ReactorEntry::new(handle.clone()).coalesced();
+        commands.syscall((EntityReactionType::Insertion(TypeId::of::<C>()), self.0, entry), register_entity_reactor::<C>);
     }
 }
 
@@ -278,8 +428,28 @@ impl<C: ReactComponent> ReactionTrigger for EntityMutationTrigger<C>
 
     fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
     {
-        let handle = handle.clone();
-        commands.syscall((EntityReactionType::Mutation(TypeId::of::<C>()), self.0, handle), register_entity_reactor);
+        let entry = ReactorEntry::new(handle.clone());
+        commands.syscall((EntityReactionType::Mutation(TypeId::of::<C>()), self.0, entry), register_entity_reactor::<C>);
+    }
+}
+
+impl<C: ReactComponent> FilterableTrigger for EntityMutationTrigger<C>
+{
+    type Data = C;
+
+    fn register_filtered(&self, commands: &mut Commands, handle: &ReactorHandle, filter: TriggerFilter)
+    {
+        let entry = ReactorEntry::new(handle.clone()).with_filter(filter);
+        commands.syscall((EntityReactionType::Mutation(TypeId::of::<C>()), self.0, entry), register_entity_reactor::<C>);
+    }
+}
+
+impl<C: ReactComponent> CoalescableTrigger for EntityMutationTrigger<C>
+{
+    fn register_coalesced(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        let entry = ReactorEntry::new(handle.clone()).coalesced();
+        commands.syscall((EntityReactionType::Mutation(TypeId::of::<C>()), self.0, entry), register_entity_reactor::<C>);
     }
 }
 
@@ -319,9 +489,9 @@ impl<C: ReactComponent> ReactionTrigger for EntityRemovalTrigger<C>
 
     fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
     {
-        let handle = handle.clone();
+        let entry = ReactorEntry::new(handle.clone());
         commands.syscall((), track_removals::<C>);
-        commands.syscall((EntityReactionType::Removal(TypeId::of::<C>()), self.0, handle), register_entity_reactor);
+        commands.syscall((EntityReactionType::Removal(TypeId::of::<C>()), self.0, entry), register_entity_reactor::<C>);
     }
 }
 
@@ -346,8 +516,138 @@ pub fn entity_removal<C: ReactComponent>(entity: Entity) -> EntityRemovalTrigger
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Reaction trigger for plain [`Component`] additions on any entity.
+/// - Additions are detected with Bevy change detection, see [`schedule_change_reactors`].
+/// - Only additions that occur after the trigger is registered will be detected.
+pub struct AddedTrigger<C: Component>(PhantomData<C>);
+impl<C: Component> Default for AddedTrigger<C> { fn default() -> Self { Self(PhantomData) } }
+impl<C: Component> Clone for AddedTrigger<C> { fn clone(&self) -> Self { *self } }
+impl<C: Component> Copy for AddedTrigger<C> {}
+
+impl<C: Component> ReactionTrigger for AddedTrigger<C>
+{
+    fn reactor_type(&self) -> ReactorType
+    {
+        ReactorType::ComponentAdded(TypeId::of::<C>())
+    }
+
+    fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        commands.syscall(ReactorEntry::new(handle.clone()), register_added_reactor::<C>);
+    }
+}
+
+impl<C: Component> FilterableTrigger for AddedTrigger<C>
+{
+    type Data = C;
+
+    fn register_filtered(&self, commands: &mut Commands, handle: &ReactorHandle, filter: TriggerFilter)
+    {
+        commands.syscall(ReactorEntry::new(handle.clone()).with_filter(filter), register_added_reactor::<C>);
+    }
+}
+
+/// Returns an [`AddedTrigger`] reaction trigger.
+pub fn added<C: Component>() -> AddedTrigger<C> { AddedTrigger::default() }
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reaction trigger for plain [`Component`] changes on any entity.
+/// - Changes are detected with Bevy change detection, see [`schedule_change_reactors`].
+/// - Additions are not considered changes. Use [`added`] to react to additions.
+/// - Only changes that occur after the trigger is registered will be detected.
+pub struct ChangedTrigger<C: Component>(PhantomData<C>);
+impl<C: Component> Default for ChangedTrigger<C> { fn default() -> Self { Self(PhantomData) } }
+impl<C: Component> Clone for ChangedTrigger<C> { fn clone(&self) -> Self { *self } }
+impl<C: Component> Copy for ChangedTrigger<C> {}
+
+impl<C: Component> ReactionTrigger for ChangedTrigger<C>
+{
+    fn reactor_type(&self) -> ReactorType
+    {
+        ReactorType::ComponentChanged(TypeId::of::<C>())
+    }
+
+    fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        commands.syscall(ReactorEntry::new(handle.clone()), register_changed_reactor::<C>);
+    }
+}
+
+impl<C: Component> FilterableTrigger for ChangedTrigger<C>
+{
+    type Data = C;
+
+    fn register_filtered(&self, commands: &mut Commands, handle: &ReactorHandle, filter: TriggerFilter)
+    {
+        commands.syscall(ReactorEntry::new(handle.clone()).with_filter(filter), register_changed_reactor::<C>);
+    }
+}
+
+/// Returns a [`ChangedTrigger`] reaction trigger.
+pub fn changed<C: Component>() -> ChangedTrigger<C> { ChangedTrigger::default() }
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reaction trigger for plain [`Component`] changes on a specific entity.
+/// - Registration does nothing if the entity does not exist.
+/// - Changes are detected with Bevy change detection, see [`schedule_change_reactors`].
+/// - Additions are not considered changes.
+pub struct EntityChangedTrigger<C: Component>(Entity, PhantomData<C>);
+impl<C: Component> Clone for EntityChangedTrigger<C> { fn clone(&self) -> Self { *self } }
+impl<C: Component> Copy for EntityChangedTrigger<C> {}
+
+impl<C: Component> ReactionTrigger for EntityChangedTrigger<C>
+{
+    fn reactor_type(&self) -> ReactorType
+    {
+        ReactorType::EntityChanged(self.0, TypeId::of::<C>())
+    }
+
+    fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        let entry = ReactorEntry::new(handle.clone());
+        commands.syscall((), track_changes::<C>);
+        commands.syscall((EntityReactionType::Changed(TypeId::of::<C>()), self.0, entry), register_entity_reactor::<C>);
+    }
+}
+
+impl<C: Component> FilterableTrigger for EntityChangedTrigger<C>
+{
+    type Data = C;
+
+    fn register_filtered(&self, commands: &mut Commands, handle: &ReactorHandle, filter: TriggerFilter)
+    {
+        let entry = ReactorEntry::new(handle.clone()).with_filter(filter);
+        commands.syscall((), track_changes::<C>);
+        commands.syscall((EntityReactionType::Changed(TypeId::of::<C>()), self.0, entry), register_entity_reactor::<C>);
+    }
+}
+
+impl<C: Component> EntityTrigger for EntityChangedTrigger<C>
+{
+    fn new_trigger(entity: Entity) -> Self
+    {
+        entity_changed(entity)
+    }
+
+    fn entity(&self) -> Entity
+    {
+        self.0
+    }
+}
+
+/// Returns an [`EntityChangedTrigger`] reaction trigger.
+pub fn entity_changed<C: Component>(entity: Entity) -> EntityChangedTrigger<C>
+{
+    EntityChangedTrigger(entity, PhantomData)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Reaction trigger for entity events.
-/// - Reactions only occur for events sent via [`ReactCommands::<E>::entity_event()`].
+/// - Reactions only occur for events sent via [`ReactCommands::<E>::entity_event()`], or for events sent via
+///   [`ReactCommands::propagate_entity_event()`] that visit the entity.
 pub struct EntityEventTrigger<E: Send + Sync + 'static>(Entity, PhantomData<E>);
 impl<E: Send + Sync + 'static> Clone for EntityEventTrigger<E> { fn clone(&self) -> Self { *self } }
 impl<E: Send + Sync + 'static> Copy for EntityEventTrigger<E> {}
@@ -361,8 +661,19 @@ impl<E: Send + Sync + 'static> ReactionTrigger for EntityEventTrigger<E>
 
     fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
     {
-        let handle = handle.clone();
-        commands.syscall((EntityReactionType::Event(TypeId::of::<E>()), self.0, handle), register_entity_reactor);
+        let entry = ReactorEntry::new(handle.clone());
+        commands.syscall((EntityReactionType::Event(TypeId::of::<E>()), self.0, entry), register_entity_reactor::<E>);
+    }
+}
+
+impl<E: Send + Sync + 'static> FilterableTrigger for EntityEventTrigger<E>
+{
+    type Data = E;
+
+    fn register_filtered(&self, commands: &mut Commands, handle: &ReactorHandle, filter: TriggerFilter)
+    {
+        let entry = ReactorEntry::new(handle.clone()).with_filter(filter);
+        commands.syscall((EntityReactionType::Event(TypeId::of::<E>()), self.0, entry), register_entity_reactor::<E>);
     }
 }
 
@@ -402,7 +713,17 @@ impl<E: Send + Sync + 'static> ReactionTrigger for AnyEntityEventTrigger<E>
 
     fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
     {
-        commands.syscall(handle.clone(), register_any_entity_event_reactor::<E>);
+        commands.syscall(ReactorEntry::new(handle.clone()), register_any_entity_event_reactor::<E>);
+    }
+}
+
+impl<E: Send + Sync + 'static> FilterableTrigger for AnyEntityEventTrigger<E>
+{
+    type Data = E;
+
+    fn register_filtered(&self, commands: &mut Commands, handle: &ReactorHandle, filter: TriggerFilter)
+    {
+        commands.syscall(ReactorEntry::new(handle.clone()).with_filter(filter), register_any_entity_event_reactor::<E>);
     }
 }
 
@@ -429,7 +750,25 @@ impl<R: ReactResource> ReactionTrigger for ResourceMutationTrigger<R>
 
     fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
     {
-        commands.syscall(handle.clone(), register_resource_mutation_reactor::<R>);
+        commands.syscall(ReactorEntry::new(handle.clone()), register_resource_mutation_reactor::<R>);
+    }
+}
+
+impl<R: ReactResource> FilterableTrigger for ResourceMutationTrigger<R>
+{
+    type Data = R;
+
+    fn register_filtered(&self, commands: &mut Commands, handle: &ReactorHandle, filter: TriggerFilter)
+    {
+        commands.syscall(ReactorEntry::new(handle.clone()).with_filter(filter), register_resource_mutation_reactor::<R>);
+    }
+}
+
+impl<R: ReactResource> CoalescableTrigger for ResourceMutationTrigger<R>
+{
+    fn register_coalesced(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        commands.syscall(ReactorEntry::new(handle.clone()).coalesced(), register_resource_mutation_reactor::<R>);
     }
 }
 
@@ -454,13 +793,29 @@ impl<E: Send + Sync + 'static> ReactionTrigger for BroadcastTrigger<E>
 
     fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
     {
-        commands.syscall(handle.clone(), register_broadcast_reactor::<E>);
+        commands.syscall(ReactorEntry::new(handle.clone()), register_broadcast_reactor::<E>);
+    }
+}
+
+impl<E: Send + Sync + 'static> FilterableTrigger for BroadcastTrigger<E>
+{
+    type Data = E;
+
+    fn register_filtered(&self, commands: &mut Commands, handle: &ReactorHandle, filter: TriggerFilter)
+    {
+        commands.syscall(ReactorEntry::new(handle.clone()).with_filter(filter), register_broadcast_reactor::<E>);
     }
 }
 
 /// Returns a [`BroadcastTrigger`] reaction trigger.
 pub fn broadcast<E: Send + Sync + 'static>() -> BroadcastTrigger<E> { BroadcastTrigger::default() }
 
+/// Returns a [`BroadcastTrigger`] reaction trigger for Bevy events.
+///
+/// Bevy events are only forwarded to broadcast reactors if they are bridged with [`ReactAppExt::bridge_bevy_event`].
+/// Reactors can read the events with the [`BroadcastEvent`] system parameter.
+pub fn bevy_event<E: Event + Clone>() -> BroadcastTrigger<E> { BroadcastTrigger::default() }
+
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Reaction trigger for despawns.
@@ -492,3 +847,77 @@ impl ReactionTrigger for DespawnTrigger
 pub fn despawn(entity: Entity) -> DespawnTrigger { DespawnTrigger(entity) }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+/// Reaction trigger that wraps a [`FilterableTrigger`] with a filter.
+/// - The filter is evaluated when reactions are scheduled. Reactions only occur if the filter returns `true`.
+/// - If the trigger data can't be accessed when scheduling (e.g. the component was removed), the reaction is skipped.
+///
+/// Use [`FilterableTrigger::filter`] or [`FilterableTrigger::when`] to make this trigger.
+///
+/// Filters must be `Copy`, so they can't capture non-`Copy` data.
+pub struct FilteredTrigger<T: FilterableTrigger, F>
+{
+    trigger: T,
+    filter: F,
+}
+
+impl<T, F> FilteredTrigger<T, F>
+where
+    T: FilterableTrigger,
+    F: Fn(&T::Data) -> bool + Copy + Send + Sync + 'static
+{
+    pub(crate) fn new(trigger: T, filter: F) -> Self
+    {
+        Self{ trigger, filter }
+    }
+}
+
+impl<T: FilterableTrigger, F: Copy> Clone for FilteredTrigger<T, F> { fn clone(&self) -> Self { *self } }
+impl<T: FilterableTrigger, F: Copy> Copy for FilteredTrigger<T, F> {}
+
+impl<T, F> ReactionTrigger for FilteredTrigger<T, F>
+where
+    T: FilterableTrigger,
+    F: Fn(&T::Data) -> bool + Copy + Send + Sync + 'static
+{
+    fn reactor_type(&self) -> ReactorType
+    {
+        self.trigger.reactor_type()
+    }
+
+    fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        self.trigger.register_filtered(commands, handle, TriggerFilter::new(self.filter));
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reaction trigger that wraps a [`CoalescableTrigger`] so its reactions are batched.
+/// - Reactions are deduplicated per reactor, entity, and component (or resource), then delivered once when
+///   [`flush_coalesced_reactions`] runs.
+/// - Reactors can read all coalesced reactions with [`CoalescedEvent`].
+///
+/// Use [`coalesce`] to make this trigger.
+#[derive(Copy, Clone)]
+pub struct CoalescedTrigger<T: CoalescableTrigger>(T);
+
+impl<T: CoalescableTrigger> ReactionTrigger for CoalescedTrigger<T>
+{
+    fn reactor_type(&self) -> ReactorType
+    {
+        self.0.reactor_type()
+    }
+
+    fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        self.0.register_coalesced(commands, handle);
+    }
+}
+
+/// Returns a [`CoalescedTrigger`] reaction trigger.
+///
+/// Supports [`insertion`], [`mutation`], [`entity_insertion`], [`entity_mutation`], and [`resource_mutation`].
+pub fn coalesce<T: CoalescableTrigger>(trigger: T) -> CoalescedTrigger<T> { CoalescedTrigger(trigger) }
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,232 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::ecs::entity::Entities;
+use bevy::prelude::*;
+use bevy::utils::{HashMap, Instant};
+#[cfg(feature = "bevy_diagnostic")]
+use bevy::diagnostic::{Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore};
+
+//standard shortcuts
+use std::time::Duration;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resets [`ReactorStats`] at the start of each frame if using [`ReactorStatsMode::PerFrame`].
+pub(crate) fn reset_reactor_stats(mut stats: ResMut<ReactorStats>)
+{
+    if stats.mode != ReactorStatsMode::PerFrame { return; }
+    stats.reset();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Removes [`ReactorStats`] entries of system commands that were despawned.
+///
+/// Runs in `Last`, after stats are recorded as diagnostics.
+pub(crate) fn prune_reactor_stats(mut stats: ResMut<ReactorStats>, entities: &Entities)
+{
+    stats.stats.retain(|sys_command, _| entities.contains(sys_command.0));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records the current frame's [`ReactorStats`] as Bevy diagnostics.
+///
+/// Each system gets two diagnostics: `cobweb/reactors/{name}/time` (in milliseconds) and
+/// `cobweb/reactors/{name}/count`. Stats of system commands that run the same system (e.g. reactors spawned repeatedly
+/// by `once` triggers) are summed, so the number of diagnostics doesn't grow with the number of system commands.
+/// Diagnostics are only recorded if the `DiagnosticsStore` resource exists.
+#[cfg(feature = "bevy_diagnostic")]
+pub(crate) fn record_reactor_diagnostics(
+    mut stats : ResMut<ReactorStats>,
+    store     : Option<ResMut<DiagnosticsStore>>,
+    mut frame : Local<HashMap<&'static str, (Duration, u64)>>,
+    mut paths : Local<HashMap<&'static str, (DiagnosticPath, DiagnosticPath)>>,
+){
+    let Some(mut store) = store else { stats.end_frame(); return; };
+    let now = Instant::now();
+
+    frame.clear();
+    for stat in stats.stats.values().filter(|stat| stat.frame_invocations > 0)
+    {
+        let (time, count) = frame.entry(stat.name.unwrap_or("system_command")).or_default();
+        *time += stat.frame_time;
+        *count += stat.frame_invocations;
+    }
+
+    for (name, (time, count)) in frame.iter()
+    {
+        let (time_path, count_path) = paths
+            .entry(*name)
+            .or_insert_with(
+                ||
+                {
+                    (
+                        DiagnosticPath::new(format!("cobweb/reactors/{name}/time")),
+                        DiagnosticPath::new(format!("cobweb/reactors/{name}/count")),
+                    )
+                }
+            );
+        if store.get(time_path).is_none()
+        {
+            store.add(Diagnostic::new(time_path.clone()).with_suffix("ms"));
+            store.add(Diagnostic::new(count_path.clone()));
+        }
+
+        let time = time.as_secs_f64() * 1000.0;
+        let count = *count as f64;
+        if let Some(diagnostic) = store.get_mut(time_path)
+        {
+            diagnostic.add_measurement(DiagnosticMeasurement{ time: now, value: time });
+        }
+        if let Some(diagnostic) = store.get_mut(count_path)
+        {
+            diagnostic.add_measurement(DiagnosticMeasurement{ time: now, value: count });
+        }
+    }
+
+    stats.end_frame();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Controls how long [`ReactorStats`] accumulate.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum ReactorStatsMode
+{
+    /// Stats accumulate until [`ReactorStats::reset`] is called.
+    #[default]
+    Cumulative,
+    /// Stats are reset at the start of each frame (in `First`).
+    PerFrame,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Profiling stats for a single [`SystemCommand`]. See [`ReactorStats`].
+#[derive(Debug, Default, Clone)]
+pub struct ReactorStat
+{
+    /// The name of the system command's system, if known.
+    pub name: Option<&'static str>,
+    /// The number of times the system command ran.
+    pub invocations: u64,
+    /// The cumulative run time of the system command.
+    pub total_time: Duration,
+    /// The longest single run of the system command.
+    pub max_time: Duration,
+
+    #[cfg(feature = "bevy_diagnostic")]
+    frame_invocations: u64,
+    #[cfg(feature = "bevy_diagnostic")]
+    frame_time: Duration,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that records per-[`SystemCommand`] invocation counts and run times.
+///
+/// Stats are only recorded if this resource exists. Use [`ReactAppExt::enable_reactor_stats`] to add it.
+///
+/// Run times are *exclusive*: time spent in system commands that run recursively inside another system command (e.g.
+/// reactions triggered by a reactor) is attributed to the inner system commands.
+///
+/// Stats of system commands that were despawned (e.g. reactors of `once` triggers) are removed in `Last`.
+///
+/// If the `bevy_diagnostic` feature is enabled, stats are also recorded as Bevy diagnostics in `Last`.
+#[derive(Resource, Debug, Default)]
+pub struct ReactorStats
+{
+    mode: ReactorStatsMode,
+    stats: HashMap<SystemCommand, ReactorStat>,
+    /// Time spent in nested system commands for each system command currently running.
+    nested_time: Vec<Duration>,
+}
+
+impl ReactorStats
+{
+    /// Makes a new stats tracker.
+    pub fn new(mode: ReactorStatsMode) -> Self
+    {
+        Self{ mode, ..default() }
+    }
+
+    /// Gets the stats mode.
+    pub fn mode(&self) -> ReactorStatsMode
+    {
+        self.mode
+    }
+
+    /// Gets the stats for a system command.
+    pub fn get(&self, sys_command: SystemCommand) -> Option<&ReactorStat>
+    {
+        self.stats.get(&sys_command)
+    }
+
+    /// Iterates stats for all system commands that have run.
+    pub fn iter(&self) -> impl Iterator<Item = (SystemCommand, &ReactorStat)> + '_
+    {
+        self.stats.iter().map(|(sys_command, stat)| (*sys_command, stat))
+    }
+
+    /// Gets stats for all system commands that have run, sorted by descending total time.
+    pub fn sorted_by_total_time(&self) -> Vec<(SystemCommand, &ReactorStat)>
+    {
+        let mut stats: Vec<_> = self.iter().collect();
+        stats.sort_by_key(|(_, stat)| std::cmp::Reverse(stat.total_time));
+        stats
+    }
+
+    /// Clears all stats.
+    pub fn reset(&mut self)
+    {
+        self.stats.clear();
+    }
+
+    /// Starts timing a system command.
+    pub(crate) fn start(&mut self) -> Instant
+    {
+        self.nested_time.push(Duration::ZERO);
+        Instant::now()
+    }
+
+    /// Finishes timing a system command.
+    pub(crate) fn end(&mut self, sys_command: SystemCommand, name: Option<&'static str>, start: Instant)
+    {
+        let elapsed = start.elapsed();
+        let nested = self.nested_time.pop().unwrap_or_default();
+        if let Some(parent) = self.nested_time.last_mut()
+        {
+            *parent += elapsed;
+        }
+        let time = elapsed.saturating_sub(nested);
+
+        let stat = self.stats.entry(sys_command).or_default();
+        if stat.name.is_none() { stat.name = name; }
+        stat.invocations += 1;
+        stat.total_time += time;
+        stat.max_time = stat.max_time.max(time);
+
+        #[cfg(feature = "bevy_diagnostic")]
+        {
+            stat.frame_invocations += 1;
+            stat.frame_time += time;
+        }
+    }
+
+    #[cfg(feature = "bevy_diagnostic")]
+    fn end_frame(&mut self)
+    {
+        for stat in self.stats.values_mut()
+        {
+            stat.frame_invocations = 0;
+            stat.frame_time = Duration::ZERO;
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
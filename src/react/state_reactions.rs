@@ -0,0 +1,277 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::ecs::event::EventCursor;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::state::state::StateTransitionSteps;
+
+//standard shortcuts
+use core::any::TypeId;
+use std::marker::PhantomData;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Tracks state transitions of `S` that need to be forwarded to state reactors.
+#[derive(Resource)]
+struct StateBridge<S: States>
+{
+    cursor: EventCursor<StateTransitionEvent<S>>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// State bridge systems waiting to be added to the [`StateTransition`] schedule.
+///
+/// Systems cannot be added to the schedule while it is running (e.g. if a state reactor is registered in an `OnEnter`
+/// system), so we defer adding them until the schedule is available.
+#[derive(Resource, Default)]
+struct PendingStateBridges(Vec<fn(&mut Schedules)>);
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Forwards state transitions of `S` to state reactors.
+fn forward_state_transitions<S: States>(
+    mut c      : Commands,
+    mut bridge : ResMut<StateBridge<S>>,
+    events     : Option<Res<Events<StateTransitionEvent<S>>>>,
+){
+    let Some(events) = events else { return; };
+    for event in bridge.cursor.read(&events)
+    {
+        c.react().broadcast(event.clone());
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn add_state_bridge_system<S: States>(schedules: &mut Schedules)
+{
+    schedules.add_systems(
+        StateTransition,
+        forward_state_transitions::<S>.after(StateTransitionSteps::EnterSchedules)
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Sets up forwarding of state transitions to state reactors.
+///
+/// Transitions that occurred before this is called will be ignored.
+fn setup_state_bridge<S: States>(world: &mut World)
+{
+    if world.contains_resource::<StateBridge<S>>() { return; }
+
+    let cursor = world.get_resource::<Events<StateTransitionEvent<S>>>()
+        .map(|events| events.get_cursor_current())
+        .unwrap_or_default();
+    world.insert_resource(StateBridge::<S>{ cursor });
+    world.get_resource_or_insert_with(PendingStateBridges::default).0.push(add_state_bridge_system::<S>);
+
+    install_state_bridges(world);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Adds pending state bridge systems to the [`StateTransition`] schedule if it is available.
+pub(crate) fn install_state_bridges(world: &mut World)
+{
+    let Some(mut pending) = world.get_resource_mut::<PendingStateBridges>() else { return; };
+    if pending.0.is_empty() { return; }
+    let pending = std::mem::take(&mut pending.0);
+
+    let Some(mut schedules) = world.get_resource_mut::<Schedules>() else { return; };
+    if !schedules.contains(StateTransition)
+    {
+        // The schedule is currently running or the app doesn't use states, try again later.
+        world.resource_mut::<PendingStateBridges>().0.extend(pending);
+        return;
+    }
+
+    for add_system in pending
+    {
+        (add_system)(&mut schedules);
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reaction trigger for all transitions of state `S`, including identity transitions.
+///
+/// Reactors can read the transition with the [`StateTransitionReader`] system parameter.
+pub struct StateTransitionTrigger<S: States>(PhantomData<S>);
+impl<S: States> Default for StateTransitionTrigger<S> { fn default() -> Self { Self(PhantomData) } }
+impl<S: States> Clone for StateTransitionTrigger<S> { fn clone(&self) -> Self { *self } }
+impl<S: States> Copy for StateTransitionTrigger<S> {}
+
+impl<S: States> ReactionTrigger for StateTransitionTrigger<S>
+{
+    fn reactor_type(&self) -> ReactorType
+    {
+        ReactorType::Broadcast(TypeId::of::<StateTransitionEvent<S>>())
+    }
+
+    fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        commands.queue(setup_state_bridge::<S>);
+        broadcast::<StateTransitionEvent<S>>().register(commands, handle);
+    }
+}
+
+impl<S: States> FilterableTrigger for StateTransitionTrigger<S>
+{
+    type Data = StateTransitionEvent<S>;
+
+    fn register_filtered(&self, commands: &mut Commands, handle: &ReactorHandle, filter: TriggerFilter)
+    {
+        commands.queue(setup_state_bridge::<S>);
+        broadcast::<StateTransitionEvent<S>>().register_filtered(commands, handle, filter);
+    }
+}
+
+/// Returns a [`StateTransitionTrigger`] reaction trigger.
+pub fn state_transition<S: States>() -> StateTransitionTrigger<S> { StateTransitionTrigger::default() }
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reaction trigger for entering a specific value of state `S`.
+/// - Reactions run after `OnEnter` schedules, and are not triggered by identity transitions (the same as `OnEnter`).
+/// - Requires `S: Copy` because reaction triggers must be `Copy`. Use [`state_transition`] with a filter for other states.
+///
+/// Reactors can read the transition with the [`StateTransitionReader`] system parameter.
+#[derive(Copy, Clone)]
+pub struct StateEnterTrigger<S: States + Copy>(S);
+
+impl<S: States + Copy> ReactionTrigger for StateEnterTrigger<S>
+{
+    fn reactor_type(&self) -> ReactorType
+    {
+        ReactorType::Broadcast(TypeId::of::<StateTransitionEvent<S>>())
+    }
+
+    fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        let state = self.0;
+        let filter = TriggerFilter::new(
+            move |event: &StateTransitionEvent<S>|
+            {
+                event.entered != event.exited && event.entered.as_ref() == Some(&state)
+            }
+        );
+        state_transition::<S>().register_filtered(commands, handle, filter);
+    }
+}
+
+/// Returns a [`StateEnterTrigger`] reaction trigger.
+pub fn state_enter<S: States + Copy>(state: S) -> StateEnterTrigger<S> { StateEnterTrigger(state) }
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reaction trigger for exiting a specific value of state `S`.
+/// - Reactions run after the exited state's `OnExit` schedule. All transition schedules run before state reactors,
+///   so the next state's `OnEnter` schedule will also have run.
+/// - Not triggered by identity transitions (the same as `OnExit`).
+/// - Requires `S: Copy` because reaction triggers must be `Copy`. Use [`state_transition`] with a filter for other states.
+///
+/// Reactors can read the transition with the [`StateTransitionReader`] system parameter.
+#[derive(Copy, Clone)]
+pub struct StateExitTrigger<S: States + Copy>(S);
+
+impl<S: States + Copy> ReactionTrigger for StateExitTrigger<S>
+{
+    fn reactor_type(&self) -> ReactorType
+    {
+        ReactorType::Broadcast(TypeId::of::<StateTransitionEvent<S>>())
+    }
+
+    fn register(&self, commands: &mut Commands, handle: &ReactorHandle)
+    {
+        let state = self.0;
+        let filter = TriggerFilter::new(
+            move |event: &StateTransitionEvent<S>|
+            {
+                event.entered != event.exited && event.exited.as_ref() == Some(&state)
+            }
+        );
+        state_transition::<S>().register_filtered(commands, handle, filter);
+    }
+}
+
+/// Returns a [`StateExitTrigger`] reaction trigger.
+pub fn state_exit<S: States + Copy>(state: S) -> StateExitTrigger<S> { StateExitTrigger(state) }
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter for reading state transitions in systems that react to them.
+///
+/// Can only be used within [`SystemCommands`](super::SystemCommand).
+///
+/// Use [`state_transition`], [`state_enter`], or [`state_exit`] to make a trigger that will read these transitions.
+///
+/*
+```rust
+fn example(mut c: Commands)
+{
+    c.react().on(
+        state_enter(GameState::Playing),
+        |transition: StateTransitionReader<GameState>|
+        {
+            println!("entered {:?} from {:?}", transition.to(), transition.from());
+        }
+    );
+}
+```
+*/
+#[derive(SystemParam)]
+pub struct StateTransitionReader<'w, 's, S: States>
+{
+    event: BroadcastEvent<'w, 's, StateTransitionEvent<S>>,
+}
+
+impl<'w, 's, S: States> StateTransitionReader<'w, 's, S>
+{
+    /// Reads the state transition.
+    ///
+    /// Panics if the system is not reacting to a state transition of `S`.
+    pub fn read(&self) -> &StateTransitionEvent<S>
+    {
+        self.event.read()
+    }
+
+    /// See [`Self::read`].
+    pub fn try_read(&self) -> Option<&StateTransitionEvent<S>>
+    {
+        self.event.try_read()
+    }
+
+    /// Returns the state that was exited.
+    ///
+    /// Returns `None` if there is no transition or no state was exited (e.g. the initial transition).
+    pub fn from(&self) -> Option<&S>
+    {
+        self.try_read()?.exited.as_ref()
+    }
+
+    /// Returns the state that was entered.
+    ///
+    /// Returns `None` if there is no transition or no state was entered (e.g. the state was removed).
+    pub fn to(&self) -> Option<&S>
+    {
+        self.try_read()?.entered.as_ref()
+    }
+
+    /// Returns `true` if there is nothing to read.
+    ///
+    /// Equivalent to `transition.try_read().is_none()`.
+    pub fn is_empty(&self) -> bool
+    {
+        self.try_read().is_none()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -19,6 +19,15 @@ fn cleanup_on_abort(world: &mut World, setup: SystemCommandSetup, cleanup: Syste
     schedule_removal_and_despawn_reactors(world);
 }
 
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Broadcasts a [`ReactionLoopDetected`] event if the flush that just ended was aborted.
+fn broadcast_detected_loop(world: &mut World)
+{
+    let Some(detected) = world.resource_mut::<ReactionLoopGuard>().take_detected() else { return; };
+    world.react(|rc| rc.broadcast(detected));
+}
+
 //-------------------------------------------------------------------------------------------------------------------
 //-------------------------------------------------------------------------------------------------------------------
 
@@ -61,6 +70,8 @@ pub(crate) struct BufferedSyscommand
     command: SystemCommand,
     setup: SystemCommandSetup,
     cleanup: SystemCommandCleanup,
+    /// The reaction kind and source of the deferred run, restored when it is re-run.
+    cascade_node: (ReactionKind, Option<Entity>),
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -78,6 +89,16 @@ pub(crate) fn syscommand_runner(
 )
 {
     let idx = **world.resource::<SyscommandCounter>();
+    let cascade_node = take_cascade_node(world);
+
+    // abort if reaction limits are exceeded
+    let limits = *world.resource::<ReactionLimits>();
+    if world.resource_mut::<ReactionLoopGuard>().check(limits, command, idx)
+    {
+        cleanup_on_abort(world, setup, cleanup);
+        if idx == 0 { broadcast_detected_loop(world); }
+        return;
+    }
 
     // cleanup
     garbage_collect_entities(world);
@@ -108,7 +129,7 @@ pub(crate) fn syscommand_runner(
         } else {
             tracing::debug!(?command, "deferring suspected recursive system command");
             world.resource_mut::<CobwebCommandQueue<BufferedSyscommand>>().push(
-                BufferedSyscommand{ command, setup, cleanup }
+                BufferedSyscommand{ command, setup, cleanup, cascade_node }
             );
         }
 
@@ -117,8 +138,30 @@ pub(crate) fn syscommand_runner(
 
     // run the system command
     **world.resource_mut::<SyscommandCounter>() += 1;
+    world.resource_mut::<ReactionLoopGuard>().push(command, callback.name());
+    let start = world.get_resource_mut::<ReactorStats>().map(|mut stats| stats.start());
+    let recording = match world.get_resource_mut::<CascadeRecorder>()
+    {
+        Some(mut recorder) => { recorder.start(command, callback.name(), cascade_node); true }
+        None => false,
+    };
     setup.run(world);
-    callback.run(world, cleanup);
+    #[cfg(feature = "testing")]
+    crate::testing::spy_system_command(world, command, callback.name());
+    let result = callback.run(world, cleanup);
+    if let (Some(start), Some(mut stats)) = (start, world.get_resource_mut::<ReactorStats>())
+    {
+        stats.end(command, callback.name(), start);
+    }
+    if let (true, Some(mut recorder)) = (recording, world.get_resource_mut::<CascadeRecorder>())
+    {
+        recorder.end();
+    }
+    if let Err(error) = result
+    {
+        let (kind, _) = cascade_node;
+        handle_reactor_error(world, ReactorError{ reactor: command, name: callback.name(), kind, error });
+    }
 
     // cleanup
     // - We do this before reinserting the callback in case the callback garbage collected itself.
@@ -163,6 +206,8 @@ pub(crate) fn syscommand_runner(
                 if buffered.command == command
                 {
                     tracing::debug!(?command, "running reordered recursive system command");
+                    let (kind, source) = buffered.cascade_node;
+                    prepare_cascade_node(world, kind, source);
                     syscommand_runner(world, buffered.command, buffered.setup, buffered.cleanup);
                     return false;
                 }
@@ -171,6 +216,7 @@ pub(crate) fn syscommand_runner(
             }
         );
     world.resource_mut::<CobwebCommandQueue<BufferedSyscommand>>().append(buffered_syscommands);
+    world.resource_mut::<ReactionLoopGuard>().pop();
 
     // final cleanup
     if idx == 0
@@ -182,6 +228,8 @@ pub(crate) fn syscommand_runner(
 
         // Reset the counter since we are exiting the system command tree.
         **world.resource_mut::<SyscommandCounter>() = 0;
+
+        broadcast_detected_loop(world);
     }
 }
 
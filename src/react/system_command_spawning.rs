@@ -7,6 +7,10 @@ use bevy::prelude::*;
 //standard shortcuts
 
 
+//-------------------------------------------------------------------------------------------------------------------
+
+type CallbackFn = Box<dyn FnMut(&mut World, SystemCommandCleanup) -> Result<(), ReactError> + Send + Sync + 'static>;
+
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Records a cleanup callback that can be injected into system commands for cleanup after the system command
@@ -45,7 +49,8 @@ impl SystemCommandCleanup
 /// between running your system and calling `apply_deferred` on that system.
 pub struct SystemCommandCallback
 {
-    inner: Box<dyn FnMut(&mut World, SystemCommandCleanup) + Send + Sync + 'static>,
+    inner: CallbackFn,
+    name: Option<&'static str>,
 }
 
 impl SystemCommandCallback
@@ -60,22 +65,63 @@ impl SystemCommandCallback
         {
             callback.run_with_cleanup(world, (), move |world: &mut World| cleanup.run(world));
         };
-        Self::with(command)
+        Self::with(command).named(std::any::type_name::<S>())
+    }
+
+    /// Makes a new system command callback from a system that can fail.
+    ///
+    /// Errors are passed to the [`ReactErrorHandler`].
+    ///
+    /// See also [`spawn_fallible_system_command`] and [`ReactCommands::on_fallible`].
+    pub fn new_fallible<S, M, E>(system: S) -> Self
+    where
+        S: IntoSystem<(), Result<(), E>, M> + Send + Sync + 'static,
+        E: Into<ReactError> + Send + Sync + 'static,
+    {
+        let mut callback = RawCallbackSystem::new(system);
+        let command = move |world: &mut World, cleanup: SystemCommandCleanup|
+        {
+            callback.run_with_cleanup(world, (), move |world: &mut World| cleanup.run(world)).map_err(Into::into)
+        };
+        Self::with_fallible(command).named(std::any::type_name::<S>())
     }
 
     /// Makes a new system command callback from a pre-defined callback.
-    pub fn with(callback: impl FnMut(&mut World, SystemCommandCleanup) + Send + Sync + 'static) -> Self
+    pub fn with(mut callback: impl FnMut(&mut World, SystemCommandCleanup) + Send + Sync + 'static) -> Self
+    {
+        Self::with_fallible(move |world, cleanup| { (callback)(world, cleanup); Ok(()) })
+    }
+
+    /// Makes a new system command callback from a pre-defined callback that can fail.
+    ///
+    /// Errors are passed to the [`ReactErrorHandler`].
+    pub fn with_fallible(
+        callback: impl FnMut(&mut World, SystemCommandCleanup) -> Result<(), ReactError> + Send + Sync + 'static
+    ) -> Self
     {
-        Self{ inner: Box::new(callback) }
+        Self{ inner: Box::new(callback), name: None }
+    }
+
+    /// Sets the name of the callback's system, which is used to label [`ReactorStats`].
+    pub fn named(mut self, name: &'static str) -> Self
+    {
+        self.name = Some(name);
+        self
+    }
+
+    /// Gets the name of the callback's system if it is known.
+    pub fn name(&self) -> Option<&'static str>
+    {
+        self.name
     }
 
     /// Runs the system command callback.
     ///
     /// The `cleanup` should be invoked between running the callback's inner system and
     /// calling `apply_deferred` on the inner system.
-    pub(crate) fn run(&mut self, world: &mut World, cleanup: SystemCommandCleanup)
+    pub(crate) fn run(&mut self, world: &mut World, cleanup: SystemCommandCleanup) -> Result<(), ReactError>
     {
-        (self.inner)(world, cleanup);
+        (self.inner)(world, cleanup)
     }
 }
 
@@ -107,6 +153,12 @@ impl SystemCommandStorage
     {
         self.callback.take()
     }
+
+    /// Returns `false` if the callback is currently running.
+    pub(crate) fn has_callback(&self) -> bool
+    {
+        self.callback.is_some()
+    }
 }
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -123,6 +175,19 @@ where
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Spawns a system that can fail as a [`SystemCommand`].
+///
+/// Errors are passed to the [`ReactErrorHandler`]. See [`SystemCommandCallback::new_fallible`].
+pub fn spawn_fallible_system_command<S, M, E>(world: &mut World, system: S) -> SystemCommand
+where
+    S: IntoSystem<(), Result<(), E>, M> + Send + Sync + 'static,
+    E: Into<ReactError> + Send + Sync + 'static,
+{
+    spawn_system_command_from(world, SystemCommandCallback::new_fallible(system))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// Spawns a [`SystemCommand`] from a pre-defined callback.
 pub fn spawn_system_command_from(world: &mut World, callback: SystemCommandCallback) -> SystemCommand
 {
@@ -56,13 +56,13 @@ impl SystemEventAccessTracker
     }
 
     /// Returns `true` if a system event is currently being processed.
-    fn is_reacting(&self) -> bool
+    pub(crate) fn is_reacting(&self) -> bool
     {
         self.currently_reacting
     }
 
     /// Returns the data entity of the most recent system event.
-    fn data_entity(&self) -> Entity
+    pub(crate) fn data_entity(&self) -> Entity
     {
         self.data_entity
     }
@@ -0,0 +1,97 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Stores data for a system request.
+#[derive(Component)]
+pub(crate) struct SystemRequestData<Req: Send + Sync + 'static, Resp: Send + Sync + 'static>
+{
+    request: Option<Req>,
+    response: Option<Resp>,
+}
+
+impl<Req: Send + Sync + 'static, Resp: Send + Sync + 'static> SystemRequestData<Req, Resp>
+{
+    /// Makes a new system request data.
+    pub(crate) fn new(request: Req) -> Self
+    {
+        Self{ request: Some(request), response: None }
+    }
+
+    /// Takes the response to the request.
+    pub(crate) fn take_response(&mut self) -> Option<Resp>
+    {
+        self.response.take()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// System parameter for receiving system requests and responding to them.
+///
+/// Requests are sent with [`world.send_system_request()`](ReactWorldExt::send_system_request) or
+/// [`commands.send_system_request()`](ReactCommandsExt::send_system_request).
+///
+/// Can only be used within [`SystemCommands`](super::SystemCommand).
+///
+/*
+```rust
+let cmd = commands.spawn_system_command(
+    |mut request: SystemRequest<Entity, bool>, items: Query<&Item>|
+    {
+        let Some(item) = request.take() else { return; };
+        request.respond(items.get(item).is_ok_and(|i| i.equippable));
+    }
+);
+
+commands.send_system_request(cmd, item,
+    |In(can_equip): In<bool>|
+    {
+        println!("can equip: {can_equip}");
+    }
+);
+```
+*/
+#[derive(SystemParam)]
+pub struct SystemRequest<'w, 's, Req: Send + Sync + 'static, Resp: Send + Sync + 'static>
+{
+    tracker: Res<'w, SystemEventAccessTracker>,
+    data: Query<'w, 's, &'static mut SystemRequestData<Req, Resp>>,
+}
+
+impl<'w, 's, Req: Send + Sync + 'static, Resp: Send + Sync + 'static> SystemRequest<'w, 's, Req, Resp>
+{
+    /// Takes system request data if it exists.
+    ///
+    /// This will return at most one unique `Req` each time a system runs.
+    pub fn take(&mut self) -> Option<Req>
+    {
+        let mut data = self.data_mut()?;
+        data.request.take()
+    }
+
+    /// Responds to the current request.
+    ///
+    /// Responding again replaces the previous response. Does nothing if the system is not handling a request.
+    pub fn respond(&mut self, response: Resp)
+    {
+        let Some(mut data) = self.data_mut() else { return; };
+        data.response = Some(response);
+    }
+
+    fn data_mut(&mut self) -> Option<Mut<'_, SystemRequestData<Req, Resp>>>
+    {
+        if !self.tracker.is_reacting() { return None; }
+        self.data.get_mut(self.tracker.data_entity()).ok()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
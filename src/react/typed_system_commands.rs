@@ -0,0 +1,292 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+//standard shortcuts
+use core::any::TypeId;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Stores the input and output of a [`SystemCommandWith`] while it runs.
+#[derive(Component)]
+struct SystemCommandIo<I: Send + Sync + 'static, O: Send + Sync + 'static>
+{
+    input: Option<I>,
+    output: Option<O>,
+}
+
+impl<I: Send + Sync + 'static, O: Send + Sync + 'static> Default for SystemCommandIo<I, O>
+{
+    fn default() -> Self
+    {
+        Self{ input: None, output: None }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Makes the callback for a [`SystemCommandWith`].
+///
+/// The callback reads its input from and writes its output to the [`SystemCommandIo`] on `entity`.
+fn typed_system_command_callback<I, O, S, M>(entity: Entity, system: S) -> SystemCommandCallback
+where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+    S: IntoSystem<In<I>, O, M> + Send + Sync + 'static,
+{
+    let mut system = RawCallbackSystem::new(system);
+    let callback = move |world: &mut World, cleanup: SystemCommandCleanup|
+    {
+        let Some(input) = world.get_mut::<SystemCommandIo<I, O>>(entity).and_then(|mut io| io.input.take())
+        else
+        {
+            tracing::warn!(?entity, "typed system command ran without input");
+            cleanup.run(world);
+            return;
+        };
+        let output = system.run_with_cleanup(world, input, move |world: &mut World| cleanup.run(world));
+        let Some(mut io) = world.get_mut::<SystemCommandIo<I, O>>(entity) else { return; };
+        io.output = Some(output);
+    };
+
+    SystemCommandCallback::with(callback).named(std::any::type_name::<S>())
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A system command that takes `In<I>` and returns `O`.
+///
+/// Spawn one with [`world.spawn_system_command_with()`](ReactWorldExt::spawn_system_command_with) or
+/// [`commands.spawn_system_command_with()`](ReactCommandsExt::spawn_system_command_with), then run it with
+/// [`world.run_system_command_with()`](ReactWorldExt::run_system_command_with) to get the output directly, or with
+/// [`commands.run_system_command_then()`](ReactCommandsExt::run_system_command_then) to pass the output to a
+/// continuation.
+///
+/// Like [`SystemCommand`], typed system commands run as part of the reaction tree.
+pub struct SystemCommandWith<I, O>
+{
+    entity: Entity,
+    _p: PhantomData<fn(I) -> O>,
+}
+
+impl<I, O> SystemCommandWith<I, O>
+{
+    /// Gets the untyped [`SystemCommand`].
+    ///
+    /// Running the untyped command does nothing because it has no input.
+    pub fn untyped(&self) -> SystemCommand
+    {
+        SystemCommand(self.entity)
+    }
+}
+
+impl<I, O> Deref for SystemCommandWith<I, O>
+{
+    type Target = Entity;
+
+    fn deref(&self) -> &Entity
+    {
+        &self.entity
+    }
+}
+
+impl<I, O> Clone for SystemCommandWith<I, O>
+{
+    fn clone(&self) -> Self
+    {
+        *self
+    }
+}
+
+impl<I, O> Copy for SystemCommandWith<I, O> {}
+
+impl<I, O> PartialEq for SystemCommandWith<I, O>
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        self.entity == other.entity
+    }
+}
+
+impl<I, O> Eq for SystemCommandWith<I, O> {}
+
+impl<I, O> Debug for SystemCommandWith<I, O>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        f.debug_tuple("SystemCommandWith").field(&self.entity).finish()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawns a system as a [`SystemCommandWith`].
+///
+/// Systems are not initialized until they are first run.
+pub(crate) fn spawn_typed_system_command<I, O, S, M>(world: &mut World, system: S) -> SystemCommandWith<I, O>
+where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+    S: IntoSystem<In<I>, O, M> + Send + Sync + 'static,
+{
+    let entity = world.spawn(SystemCommandIo::<I, O>::default()).id();
+    let callback = typed_system_command_callback(entity, system);
+    world.entity_mut(entity).insert(SystemCommandStorage::new(callback));
+
+    SystemCommandWith{ entity, _p: PhantomData }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Schedules a system to be spawned as a [`SystemCommandWith`].
+pub(crate) fn spawn_typed_system_command_deferred<I, O, S, M>(
+    commands : &mut Commands,
+    system   : S,
+) -> SystemCommandWith<I, O>
+where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+    S: IntoSystem<In<I>, O, M> + Send + Sync + 'static,
+{
+    let entity = commands.spawn_empty().id();
+    let callback = typed_system_command_callback(entity, system);
+    commands.entity(entity).insert((SystemCommandIo::<I, O>::default(), SystemCommandStorage::new(callback)));
+
+    SystemCommandWith{ entity, _p: PhantomData }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Runs a [`SystemCommandWith`] and returns its output.
+///
+/// Returns `None` if the system command doesn't exist or is already running.
+pub(crate) fn run_typed_system_command<I, O>(
+    world   : &mut World,
+    command : SystemCommandWith<I, O>,
+    input   : I,
+) -> Option<O>
+where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+{
+    // Set the input.
+    let Ok(mut entity_mut) = world.get_entity_mut(*command)
+    else
+    {
+        tracing::warn!(?command, "typed system command missing");
+        return None;
+    };
+    if !entity_mut.get::<SystemCommandStorage>().is_some_and(|storage| storage.has_callback())
+    {
+        tracing::warn!(?command, "ignoring recursive or missing typed system command");
+        return None;
+    }
+    let Some(mut io) = entity_mut.get_mut::<SystemCommandIo<I, O>>()
+    else
+    {
+        tracing::error!(?command, "typed system command io is missing");
+        return None;
+    };
+    io.input = Some(input);
+
+    // Run the command.
+    syscommand_runner(world, command.untyped(), SystemCommandSetup::default(), SystemCommandCleanup::default());
+
+    // Take the output.
+    // - The command may have despawned itself.
+    let mut io = world.get_mut::<SystemCommandIo<I, O>>(*command)?;
+    io.input = None;
+    io.output.take()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Runs a [`SystemCommandWith`] then passes its output to a continuation system.
+pub(crate) fn run_typed_system_command_then<I, O, S, M>(
+    world        : &mut World,
+    command      : SystemCommandWith<I, O>,
+    input        : I,
+    continuation : S,
+)
+where
+    I: Send + Sync + 'static,
+    O: Send + Sync + 'static,
+    S: IntoSystem<In<O>, (), M> + Send + Sync + 'static,
+{
+    let Some(output) = run_typed_system_command(world, command, input) else { return; };
+    run_continuation(world, continuation, output);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Caches continuation system commands by continuation type.
+///
+/// Only non-capturing continuations are cached, since a cached system would keep the captures of its first run.
+#[derive(Resource, Default)]
+pub(crate) struct ContinuationCache
+{
+    commands: HashMap<TypeId, Entity>,
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Spawns a continuation, runs it once, then despawns it.
+fn run_one_off_continuation<O, S, M>(world: &mut World, continuation: S, output: O)
+where
+    O: Send + Sync + 'static,
+    S: IntoSystem<In<O>, (), M> + Send + Sync + 'static,
+{
+    let continuation = spawn_typed_system_command(world, continuation);
+    run_typed_system_command(world, continuation, output);
+    if let Ok(entity_mut) = world.get_entity_mut(*continuation) { entity_mut.despawn(); }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Runs a continuation system as a system command.
+///
+/// Continuations that don't capture anything (e.g. function items) are spawned once and cached by type, so repeated
+/// runs reuse the same system and its `Local`s. Capturing continuations are spawned, initialized, and despawned on
+/// every run.
+pub(crate) fn run_continuation<O, S, M>(world: &mut World, continuation: S, output: O)
+where
+    O: Send + Sync + 'static,
+    S: IntoSystem<In<O>, (), M> + Send + Sync + 'static,
+{
+    if core::mem::size_of::<S>() != 0
+    {
+        run_one_off_continuation(world, continuation, output);
+        return;
+    }
+
+    // Look up the cached continuation.
+    // - If the cached continuation is already running then this is a recursive run, which gets a one-off system.
+    let cached = world.resource::<ContinuationCache>().commands.get(&TypeId::of::<S>()).copied();
+    let continuation = match cached.map(|entity| (entity, world.get::<SystemCommandStorage>(entity)))
+    {
+        Some((entity, Some(storage))) if storage.has_callback() => SystemCommandWith{ entity, _p: PhantomData },
+        Some((_, Some(_))) =>
+        {
+            run_one_off_continuation(world, continuation, output);
+            return;
+        }
+        _ =>
+        {
+            let continuation = spawn_typed_system_command(world, continuation);
+            world.resource_mut::<ContinuationCache>().commands.insert(TypeId::of::<S>(), *continuation);
+            continuation
+        }
+    };
+    run_typed_system_command(world, continuation, output);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -6,7 +6,7 @@ use bevy::prelude::*;
 use smallvec::SmallVec;
 
 //standard shortcuts
-use core::any::TypeId;
+use core::any::{Any, TypeId};
 use std::sync::Arc;
 
 //-------------------------------------------------------------------------------------------------------------------
@@ -33,9 +33,26 @@ pub fn schedule_removal_and_despawn_reactors(world: &mut World)
 
 //-------------------------------------------------------------------------------------------------------------------
 
+/// Queues reactors for changes to plain components (see [`changed()`], [`entity_changed()`], and [`added()`]).
+///
+/// This system is automatically run in `Last`. Use [`ReactAppExt::scan_component_changes_in`] to also run it in other
+/// schedules, or schedule it manually if you want to promptly detect changes.
+pub fn schedule_change_reactors(world: &mut World)
+{
+    let checkers = world.resource_mut::<ReactCache>().take_change_checkers();
+    for checker in checkers.iter()
+    {
+        checker.run(world);
+    }
+    world.resource_mut::<ReactCache>().return_change_checkers(checkers);
+    world.flush();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 /// The type of an entity reaction.
 //todo: switch to ComponentId when observers are integrated
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub(crate) enum EntityReactionType
 {
     /// A component was inserted.
@@ -44,24 +61,50 @@ pub(crate) enum EntityReactionType
     Mutation(TypeId),
     /// A component was removed.
     Removal(TypeId),
+    /// A plain component was added (detected with change detection).
+    Added(TypeId),
+    /// A plain component was changed (detected with change detection).
+    Changed(TypeId),
     /// An event was sent to this entity.
     Event(TypeId),
 }
 
+impl EntityReactionType
+{
+    /// Converts to the equivalent [`ReactorType`] targeting `entity`.
+    pub(crate) fn to_reactor_type(self, entity: Entity) -> ReactorType
+    {
+        match self
+        {
+            Self::Insertion(id) => ReactorType::EntityInsertion(entity, id),
+            Self::Mutation(id)  => ReactorType::EntityMutation(entity, id),
+            Self::Removal(id)   => ReactorType::EntityRemoval(entity, id),
+            Self::Added(id)     => ReactorType::EntityInsertion(entity, id),
+            Self::Changed(id)   => ReactorType::EntityChanged(entity, id),
+            Self::Event(id)     => ReactorType::EntityEvent(entity, id),
+        }
+    }
+}
+
 //-------------------------------------------------------------------------------------------------------------------
 
 /// Component that stores reactor handles that target a specific entity.
 #[derive(Component)]
 pub(crate) struct EntityReactors
 {
-    reactors: SmallVec<[(EntityReactionType, ReactorHandle); ENTITY_REACTORS_STATIC_SIZE]>,
+    reactors: SmallVec<[(EntityReactionType, ReactorEntry); ENTITY_REACTORS_STATIC_SIZE]>,
 }
 
 impl EntityReactors
 {
-    pub(crate) fn insert(&mut self, rtype: EntityReactionType, handle: ReactorHandle)
+    /// Inserts a reactor after all reactors with equal or higher priority.
+    pub(crate) fn insert(&mut self, rtype: EntityReactionType, entry: ReactorEntry)
     {
-        self.reactors.push((rtype, handle));
+        let pos = self.reactors
+            .iter()
+            .position(|(_, e)| e.priority() < entry.priority())
+            .unwrap_or(self.reactors.len());
+        self.reactors.insert(pos, (rtype, entry));
 
         if self.reactors.len() > ENTITY_REACTORS_WARNING_SIZE {
             warn_once!("more than {ENTITY_REACTORS_WARNING_SIZE} reactors were registered targeting an entity, \
@@ -73,36 +116,45 @@ impl EntityReactors
     pub(crate) fn remove(&mut self, rtype: EntityReactionType, reactor_id: SystemCommand)
     {
         self.reactors.drain_filter(
-                |(reaction_type, handle)|
+                |(reaction_type, entry)|
                 {
                     if *reaction_type != rtype { return false; }
-                    if handle.sys_command() != reactor_id { return false; }
+                    if entry.sys_command() != reactor_id { return false; }
                     true
                 }
             );
     }
 
-    pub(crate) fn count(&self, rtype: EntityReactionType) -> usize
+    pub(crate) fn iter_reactors(&self) -> impl Iterator<Item = SystemCommand> + '_
     {
-        self.iter_rtype(rtype).count()
+        self.reactors
+            .iter()
+            .map(|(_, entry)| entry.sys_command())
     }
 
-    pub(crate) fn iter_reactors(&self) -> impl Iterator<Item = SystemCommand> + '_
+    /// Iterates all reactor entries in priority order.
+    pub(crate) fn iter_entries(&self) -> impl Iterator<Item = (EntityReactionType, &ReactorEntry)> + '_
     {
         self.reactors
             .iter()
-            .map(|(_, handle)| handle.sys_command())
+            .map(|(rtype, entry)| (*rtype, entry))
     }
 
-    pub(crate) fn iter_rtype(&self, rtype: EntityReactionType) -> impl Iterator<Item = SystemCommand> + '_
+    /// Iterates reactors of type `rtype` whose trigger filters allow `data`, in priority order.
+    pub(crate) fn iter_allowed<'a>(
+        &'a self,
+        rtype: EntityReactionType,
+        data: Option<&'a dyn Any>
+    ) -> impl Iterator<Item = &'a ReactorEntry> + 'a
     {
         self.reactors
             .iter()
             .filter_map(
-                move |(reaction_type, handle)|
+                move |(reaction_type, entry)|
                 {
                     if *reaction_type != rtype { return None; }
-                    Some(handle.sys_command())
+                    if !entry.allows(data) { return None; }
+                    Some(entry)
                 }
             )
     }
@@ -126,14 +178,18 @@ pub enum ReactorType
     EntityInsertion(Entity, TypeId),
     EntityMutation(Entity, TypeId),
     EntityRemoval(Entity, TypeId),
+    EntityChanged(Entity, TypeId),
     EntityEvent(Entity, TypeId),
     AnyEntityEvent(TypeId),
     ComponentInsertion(TypeId),
     ComponentMutation(TypeId),
     ComponentRemoval(TypeId),
+    ComponentAdded(TypeId),
+    ComponentChanged(TypeId),
     ResourceMutation(TypeId),
     Broadcast(TypeId),
     Despawn(Entity),
+    Timer,
 }
 
 impl ReactorType
@@ -146,14 +202,41 @@ impl ReactorType
             Self::EntityInsertion(entity, _) |
             Self::EntityMutation(entity, _) |
             Self::EntityRemoval(entity, _) |
+            Self::EntityChanged(entity, _) |
             Self::EntityEvent(entity, _) |
             Self::Despawn(entity) => Some(entity),
             Self::AnyEntityEvent(_) |
             Self::ComponentInsertion(_) |
             Self::ComponentMutation(_) |
             Self::ComponentRemoval(_) |
+            Self::ComponentAdded(_) |
+            Self::ComponentChanged(_) |
             Self::ResourceMutation(_) |
-            Self::Broadcast(_) => None,
+            Self::Broadcast(_) |
+            Self::Timer => None,
+        }
+    }
+
+    /// Gets the id of the component, resource, or event type targeted by this reactor type.
+    pub fn get_type_id(&self) -> Option<TypeId>
+    {
+        match *self
+        {
+            Self::EntityInsertion(_, id) |
+            Self::EntityMutation(_, id) |
+            Self::EntityRemoval(_, id) |
+            Self::EntityChanged(_, id) |
+            Self::EntityEvent(_, id) |
+            Self::AnyEntityEvent(id) |
+            Self::ComponentInsertion(id) |
+            Self::ComponentMutation(id) |
+            Self::ComponentRemoval(id) |
+            Self::ComponentAdded(id) |
+            Self::ComponentChanged(id) |
+            Self::ResourceMutation(id) |
+            Self::Broadcast(id) => Some(id),
+            Self::Despawn(_) |
+            Self::Timer => None,
         }
     }
 }
@@ -183,6 +266,12 @@ impl RevokeToken
         }
     }
 
+    /// Makes a token for a one-off timer, such as a delayed event.
+    pub(crate) fn new_timer(id: SystemCommand) -> Self
+    {
+        Self{ reactors: Arc::from([ReactorType::Timer].as_slice()), id }
+    }
+
     /// Not efficient for very large numbers of reactors, which is unlikely to be an issue in practice.
     pub(crate) fn iter_unique_entities(&self) -> impl Iterator<Item = Entity> + '_
     {
@@ -226,3 +315,138 @@ impl ReactorHandle
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+type FilterFn = dyn Fn(&dyn Any) -> bool + Send + Sync + 'static;
+
+/// Type-erased filter attached to a reactor's trigger registration.
+///
+/// Filters are evaluated when reactions are scheduled, so reactors never run for trigger data they reject.
+///
+/// See [`FilterableTrigger::filter`].
+#[derive(Clone)]
+pub struct TriggerFilter(Arc<FilterFn>);
+
+impl TriggerFilter
+{
+    /// Makes a new filter for trigger data of type `T`.
+    pub(crate) fn new<T: 'static>(filter: impl Fn(&T) -> bool + Send + Sync + 'static) -> Self
+    {
+        Self(Arc::new(
+            move |data: &dyn Any|
+            {
+                let Some(data) = data.downcast_ref::<T>() else { return false; };
+                (filter)(data)
+            }
+        ))
+    }
+
+    /// Returns `true` if the trigger data passes the filter.
+    ///
+    /// Missing data is always rejected.
+    pub(crate) fn allows(&self, data: Option<&dyn Any>) -> bool
+    {
+        let Some(data) = data else { return false; };
+        (self.0)(data)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// The priority of a reactor, stored on the reactor's system command entity.
+///
+/// Reactors with higher priority run before reactors with lower priority when they react to the same trigger. See
+/// [`ReactCommands::with_priority`].
+#[derive(Component, Debug, Copy, Clone)]
+pub(crate) struct ReactorPriority(pub(crate) i32);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A reactor registered for a specific trigger.
+#[derive(Clone)]
+pub(crate) struct ReactorEntry
+{
+    handle: ReactorHandle,
+    filter: Option<TriggerFilter>,
+    priority: i32,
+    coalesce: bool,
+}
+
+impl ReactorEntry
+{
+    pub(crate) fn new(handle: ReactorHandle) -> Self
+    {
+        Self{ handle, filter: None, priority: 0, coalesce: false }
+    }
+
+    /// Marks the entry so its reactions are coalesced instead of running immediately.
+    pub(crate) fn coalesced(mut self) -> Self
+    {
+        self.coalesce = true;
+        self
+    }
+
+    pub(crate) fn with_filter(mut self, filter: TriggerFilter) -> Self
+    {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Sets the entry's priority from the reactor's [`ReactorPriority`] (if it has one).
+    pub(crate) fn prioritized(mut self, priorities: &Query<&ReactorPriority>) -> Self
+    {
+        if let Ok(priority) = priorities.get(*self.sys_command())
+        {
+            self.priority = priority.0;
+        }
+        self
+    }
+
+    pub(crate) fn sys_command(&self) -> SystemCommand
+    {
+        self.handle.sys_command()
+    }
+
+    pub(crate) fn priority(&self) -> i32
+    {
+        self.priority
+    }
+
+    pub(crate) fn is_coalesced(&self) -> bool
+    {
+        self.coalesce
+    }
+
+    pub(crate) fn is_filtered(&self) -> bool
+    {
+        self.filter.is_some()
+    }
+
+    pub(crate) fn handle(&self) -> &ReactorHandle
+    {
+        &self.handle
+    }
+
+    /// Returns `true` if the reactor should run for a trigger with the given data.
+    pub(crate) fn allows(&self, data: Option<&dyn Any>) -> bool
+    {
+        match &self.filter
+        {
+            Some(filter) => filter.allows(data),
+            None         => true,
+        }
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Inserts a reactor entry after all entries with equal or higher priority.
+pub(crate) fn insert_by_priority(entries: &mut Vec<ReactorEntry>, entry: ReactorEntry)
+{
+    let pos = entries
+        .iter()
+        .position(|e| e.priority() < entry.priority())
+        .unwrap_or(entries.len());
+    entries.insert(pos, entry);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -76,6 +76,10 @@ pub trait WorldReactor: Send + Sync + 'static
     type StartingTriggers: ReactionTriggerBundle;
     /// Triggers that can be added to the reactor with [`Reactor::add`].
     type Triggers: ReactionTriggerBundle;
+    /// Priority of the reactor relative to other reactors of the same triggers.
+    ///
+    /// Reactors with higher priority run first. See [`ReactCommands::with_priority`].
+    const PRIORITY: i32 = 0;
 
     /// Consumes `Self` and returns the reactor system.
     ///
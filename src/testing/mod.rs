@@ -0,0 +1,13 @@
+//! Helpers for testing reactive code. Requires the `testing` feature.
+//!
+//! See [`ReactTestApp`].
+
+//module tree
+mod react_spy;
+mod react_test_app;
+mod reaction_stepper;
+
+//API exports
+pub use react_spy::*;
+pub use react_test_app::*;
+pub(crate) use reaction_stepper::*;
@@ -0,0 +1,129 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+//standard shortcuts
+use core::any::{Any, TypeId};
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+type EventReader = fn(&World, Entity) -> Option<Box<dyn Any + Send + Sync>>;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn read_event<E: Clone + Send + Sync + 'static>(
+    world       : &World,
+    data_entity : Entity
+) -> Option<Box<dyn Any + Send + Sync>>
+{
+    let entity = world.get_entity(data_entity).ok()?;
+    if let Some(data) = entity.get::<BroadcastEventData<E>>()
+    {
+        return Some(Box::new(data.read().clone()));
+    }
+    let data = entity.get::<EntityEventData<E>>()?;
+    Some(Box::new(data.data().clone()))
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records a system command run in the [`ReactSpy`].
+///
+/// Must be called after the system command's setup so the event it reacts to (if any) can be found.
+pub(crate) fn spy_system_command(world: &mut World, command: SystemCommand, name: Option<&'static str>)
+{
+    if !world.contains_resource::<ReactSpy>() { return; }
+
+    let tracker = world.resource::<EventAccessTracker>();
+    let data_entity = tracker.is_reacting().then(|| tracker.data_entity());
+    let spy = world.resource::<ReactSpy>();
+    let event = data_entity
+        .and_then(|data_entity| spy.readers.values().find_map(|reader| (reader)(world, data_entity)));
+
+    world.resource_mut::<ReactSpy>().runs.push(SpiedRun{ command, name, event });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// A system command run recorded by the [`ReactSpy`].
+#[derive(Debug)]
+pub struct SpiedRun
+{
+    /// The system command that ran.
+    pub command: SystemCommand,
+    /// The name of the system command's system, if known.
+    pub name: Option<&'static str>,
+    /// The broadcast or entity event the system command reacted to, if its type is spied on.
+    event: Option<Box<dyn Any + Send + Sync>>,
+}
+
+impl SpiedRun
+{
+    /// Gets the broadcast or entity event the system command reacted to.
+    ///
+    /// Returns `None` if the system command did not react to an event of type `E`, or if `E` is not spied on (see
+    /// [`ReactSpy::spy_events`]).
+    pub fn event<E: 'static>(&self) -> Option<&E>
+    {
+        self.event.as_ref()?.downcast_ref::<E>()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Resource that records every [`SystemCommand`] run, including reactors. Requires the `testing` feature.
+///
+/// Runs are only recorded if this resource exists. It is added automatically by
+/// [`ReactTestApp`](crate::testing::ReactTestApp).
+#[derive(Resource, Default)]
+pub struct ReactSpy
+{
+    runs: Vec<SpiedRun>,
+    readers: HashMap<TypeId, EventReader>,
+}
+
+impl ReactSpy
+{
+    /// Records broadcasts and entity events of type `E` that system commands react to.
+    pub fn spy_events<E: Clone + Send + Sync + 'static>(&mut self)
+    {
+        self.readers.insert(TypeId::of::<E>(), read_event::<E>);
+    }
+
+    /// Iterates all recorded runs in the order they started.
+    pub fn runs(&self) -> impl Iterator<Item = &SpiedRun> + '_
+    {
+        self.runs.iter()
+    }
+
+    /// Counts the recorded runs of a system command.
+    pub fn num_runs(&self, command: SystemCommand) -> usize
+    {
+        self.runs.iter().filter(|run| run.command == command).count()
+    }
+
+    /// Counts the recorded runs of a system command that reacted to `event`.
+    ///
+    /// `E` must be spied on with [`Self::spy_events`].
+    pub fn num_runs_with<E: PartialEq + 'static>(&self, command: SystemCommand, event: &E) -> usize
+    {
+        self.runs
+            .iter()
+            .filter(|run| run.command == command && run.event::<E>() == Some(event))
+            .count()
+    }
+
+    /// Clears recorded runs.
+    pub fn clear(&mut self)
+    {
+        self.runs.clear();
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,368 @@
+//local shortcuts
+use crate::prelude::*;
+use crate::testing::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+use std::collections::VecDeque;
+use std::fmt::Debug;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+type TestInput = Box<dyn FnOnce(&mut World) + Send + 'static>;
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource)]
+struct CapturedBroadcasts<E: Send + Sync + 'static>(Vec<E>);
+
+impl<E: Send + Sync + 'static> Default for CapturedBroadcasts<E>
+{
+    fn default() -> Self { Self(Vec::default()) }
+}
+
+fn capture_broadcast<E: Clone + Send + Sync + 'static>(
+    event        : BroadcastEvent<E>,
+    mut captured : ResMut<CapturedBroadcasts<E>>
+){
+    captured.0.push(event.read().clone());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource)]
+struct CapturedEntityEvents<E: Send + Sync + 'static>(Vec<(Entity, E)>);
+
+impl<E: Send + Sync + 'static> Default for CapturedEntityEvents<E>
+{
+    fn default() -> Self { Self(Vec::default()) }
+}
+
+fn capture_entity_event<E: Clone + Send + Sync + 'static>(
+    event        : EntityEvent<E>,
+    mut captured : ResMut<CapturedEntityEvents<E>>
+){
+    let (entity, event) = event.read();
+    captured.0.push((entity, event.clone()));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Wrapper around an `App` for testing reactive code. Requires the `testing` feature.
+///
+/// - Spies on all [`SystemCommand`] runs with a [`ReactSpy`], so you can assert how many times a reactor ran and which
+///   events it reacted to.
+/// - Captures broadcasts and entity events of selected types into inspectable logs.
+/// - Queues inputs that can be applied one at a time. By default, applying an input runs its entire reaction cascade
+///   to completion.
+/// - Steps through reactions one at a time with [`Self::enable_reaction_stepping`]. While stepping is enabled,
+///   reactions are buffered instead of running, and [`Self::step_reaction`] runs the next buffered reaction.
+///
+/*
+```rust
+let mut app = ReactTestApp::new();
+app.capture_broadcasts::<MyEvent>();
+let reactor = app.react(|rc| rc.on_persistent(broadcast::<MyEvent>(), my_reactor));
+
+app.queue_react_input(|rc| rc.broadcast(MyEvent(1)));
+app.queue_react_input(|rc| rc.broadcast(MyEvent(2)));
+app.apply_next_input();
+app.assert_runs_with(reactor, &MyEvent(1), 1);
+
+app.enable_reaction_stepping();
+app.apply_next_input();
+assert_eq!(app.pending_reactors(), vec![reactor]);
+app.step_reaction();
+app.assert_runs(reactor, 2);
+assert_eq!(app.broadcasts::<MyEvent>(), &[MyEvent(1), MyEvent(2)]);
+```
+*/
+pub struct ReactTestApp
+{
+    app: App,
+    inputs: VecDeque<TestInput>,
+}
+
+impl ReactTestApp
+{
+    /// Makes a new test app with [`ReactPlugin`].
+    pub fn new() -> Self
+    {
+        let mut app = App::new();
+        app.add_plugins(ReactPlugin);
+        Self::from_app(app)
+    }
+
+    /// Makes a test app from an existing app.
+    ///
+    /// The app should already have [`ReactPlugin`].
+    pub fn from_app(mut app: App) -> Self
+    {
+        app.init_resource::<ReactSpy>();
+        Self{ app, inputs: VecDeque::default() }
+    }
+
+    /// Accesses the inner app.
+    pub fn app(&self) -> &App
+    {
+        &self.app
+    }
+
+    /// Mutably accesses the inner app.
+    pub fn app_mut(&mut self) -> &mut App
+    {
+        &mut self.app
+    }
+
+    /// Accesses the app's world.
+    pub fn world(&self) -> &World
+    {
+        self.app.world()
+    }
+
+    /// Mutably accesses the app's world.
+    pub fn world_mut(&mut self) -> &mut World
+    {
+        self.app.world_mut()
+    }
+
+    /// Unwraps the inner app.
+    pub fn into_app(self) -> App
+    {
+        self.app
+    }
+
+    /// Runs one app update.
+    pub fn update(&mut self) -> &mut Self
+    {
+        self.app.update();
+        self
+    }
+
+    /// Provides access to [`ReactCommands`]. Commands are applied immediately.
+    pub fn react<T>(&mut self, callback: impl FnOnce(&mut ReactCommands) -> T) -> T
+    {
+        self.app.world_mut().react(callback)
+    }
+
+    /// Captures broadcasts of type `E`. They can be read with [`Self::broadcasts`].
+    ///
+    /// The event type is also spied on with [`ReactSpy::spy_events`].
+    pub fn capture_broadcasts<E: Clone + Send + Sync + 'static>(&mut self) -> &mut Self
+    {
+        if self.world().contains_resource::<CapturedBroadcasts<E>>() { return self; }
+        self.app.init_resource::<CapturedBroadcasts<E>>();
+        self.spy_events::<E>();
+        self.react(|rc| rc.on_persistent(broadcast::<E>(), capture_broadcast::<E>));
+        self
+    }
+
+    /// Captures entity events of type `E`. They can be read with [`Self::entity_events`].
+    ///
+    /// The event type is also spied on with [`ReactSpy::spy_events`].
+    pub fn capture_entity_events<E: Clone + Send + Sync + 'static>(&mut self) -> &mut Self
+    {
+        if self.world().contains_resource::<CapturedEntityEvents<E>>() { return self; }
+        self.app.init_resource::<CapturedEntityEvents<E>>();
+        self.spy_events::<E>();
+        self.react(|rc| rc.on_persistent(any_entity_event::<E>(), capture_entity_event::<E>));
+        self
+    }
+
+    /// Gets captured broadcasts of type `E` in the order they were received.
+    ///
+    /// Panics if `E` is not captured with [`Self::capture_broadcasts`].
+    pub fn broadcasts<E: Send + Sync + 'static>(&self) -> &[E]
+    {
+        let Some(captured) = self.world().get_resource::<CapturedBroadcasts<E>>() else {
+            panic!("broadcasts of {} are not captured", std::any::type_name::<E>());
+        };
+        &captured.0
+    }
+
+    /// Gets captured entity events of type `E` and the entities they visited, in the order they were received.
+    ///
+    /// Panics if `E` is not captured with [`Self::capture_entity_events`].
+    pub fn entity_events<E: Send + Sync + 'static>(&self) -> &[(Entity, E)]
+    {
+        let Some(captured) = self.world().get_resource::<CapturedEntityEvents<E>>() else {
+            panic!("entity events of {} are not captured", std::any::type_name::<E>());
+        };
+        &captured.0
+    }
+
+    /// Clears captured broadcasts and entity events of type `E`.
+    pub fn clear_captured<E: Send + Sync + 'static>(&mut self)
+    {
+        if let Some(mut captured) = self.world_mut().get_resource_mut::<CapturedBroadcasts<E>>()
+        {
+            captured.0.clear();
+        }
+        if let Some(mut captured) = self.world_mut().get_resource_mut::<CapturedEntityEvents<E>>()
+        {
+            captured.0.clear();
+        }
+    }
+
+    /// Spies on broadcasts and entity events of type `E`. See [`Self::runs_with`].
+    pub fn spy_events<E: Clone + Send + Sync + 'static>(&mut self) -> &mut Self
+    {
+        self.world_mut().resource_mut::<ReactSpy>().spy_events::<E>();
+        self
+    }
+
+    /// Accesses the [`ReactSpy`].
+    pub fn spy(&self) -> &ReactSpy
+    {
+        self.world().resource::<ReactSpy>()
+    }
+
+    /// Counts the runs of a system command.
+    pub fn runs(&self, command: SystemCommand) -> usize
+    {
+        self.spy().num_runs(command)
+    }
+
+    /// Counts the runs of a system command that reacted to `event`.
+    ///
+    /// `E` must be spied on with [`Self::spy_events`] (or captured).
+    pub fn runs_with<E: PartialEq + 'static>(&self, command: SystemCommand, event: &E) -> usize
+    {
+        self.spy().num_runs_with(command, event)
+    }
+
+    /// Clears recorded system command runs.
+    pub fn clear_runs(&mut self)
+    {
+        self.world_mut().resource_mut::<ReactSpy>().clear();
+    }
+
+    /// Asserts that a system command ran `expected` times.
+    #[track_caller]
+    pub fn assert_runs(&self, command: SystemCommand, expected: usize)
+    {
+        let runs = self.runs(command);
+        assert_eq!(runs, expected, "system command {:?} ran {} times, expected {}", command, runs, expected);
+    }
+
+    /// Asserts that a system command ran `expected` times in reaction to `event`.
+    #[track_caller]
+    pub fn assert_runs_with<E: PartialEq + Debug + 'static>(&self, command: SystemCommand, event: &E, expected: usize)
+    {
+        let runs = self.runs_with(command, event);
+        assert_eq!(runs, expected, "system command {:?} ran {} times with {:?}, expected {}",
+            command, runs, event, expected);
+    }
+
+    /// Queues a command to be applied as an input by [`Self::apply_next_input`].
+    pub fn queue_input(&mut self, command: impl Command) -> &mut Self
+    {
+        self.inputs.push_back(Box::new(move |world: &mut World| command.apply(world)));
+        self
+    }
+
+    /// Queues a [`ReactCommands`] callback to be applied as an input by [`Self::apply_next_input`].
+    pub fn queue_react_input(&mut self, callback: impl FnOnce(&mut ReactCommands) + Send + 'static) -> &mut Self
+    {
+        self.inputs.push_back(Box::new(move |world: &mut World| world.react(callback)));
+        self
+    }
+
+    /// Gets the number of queued inputs.
+    pub fn num_queued_inputs(&self) -> usize
+    {
+        self.inputs.len()
+    }
+
+    /// Applies the next queued input.
+    ///
+    /// The input's entire reaction cascade runs before this returns, unless reaction stepping is enabled (see
+    /// [`Self::enable_reaction_stepping`]).
+    ///
+    /// Returns `false` if there are no queued inputs.
+    pub fn apply_next_input(&mut self) -> bool
+    {
+        let Some(input) = self.inputs.pop_front() else { return false; };
+        (input)(self.app.world_mut());
+        true
+    }
+
+    /// Applies all queued inputs in order.
+    pub fn apply_queued_inputs(&mut self) -> &mut Self
+    {
+        while self.apply_next_input() {}
+        self
+    }
+
+    /// Starts buffering reactions so they can be run one at a time with [`Self::step_reaction`].
+    ///
+    /// All reactions are buffered while stepping is enabled, including reactions triggered by app updates and by
+    /// other reactions. System commands and system events that are run directly are not buffered, but the reactions
+    /// they trigger are.
+    pub fn enable_reaction_stepping(&mut self) -> &mut Self
+    {
+        self.app.init_resource::<ReactionStepper>();
+        self
+    }
+
+    /// Runs all buffered reactions and stops buffering reactions.
+    pub fn disable_reaction_stepping(&mut self) -> &mut Self
+    {
+        self.apply_pending_reactions();
+        self.world_mut().remove_resource::<ReactionStepper>();
+        self
+    }
+
+    /// Returns `true` if reaction stepping is enabled.
+    pub fn is_reaction_stepping(&self) -> bool
+    {
+        self.world().contains_resource::<ReactionStepper>()
+    }
+
+    /// Gets the number of buffered reactions.
+    pub fn num_pending_reactions(&self) -> usize
+    {
+        self.world().get_resource::<ReactionStepper>().map(ReactionStepper::len).unwrap_or_default()
+    }
+
+    /// Gets the reactors of buffered reactions in the order they will run.
+    pub fn pending_reactors(&self) -> Vec<SystemCommand>
+    {
+        self.world()
+            .get_resource::<ReactionStepper>()
+            .map(|stepper| stepper.iter_reactors().collect())
+            .unwrap_or_default()
+    }
+
+    /// Runs the next buffered reaction.
+    ///
+    /// Reactions triggered while it runs are buffered ahead of older reactions, so stepping runs reactions in the same
+    /// order as they would run without stepping.
+    ///
+    /// Returns `false` if there are no buffered reactions.
+    pub fn step_reaction(&mut self) -> bool
+    {
+        step_reaction(self.world_mut())
+    }
+
+    /// Runs buffered reactions until there are none left, including reactions they trigger.
+    pub fn apply_pending_reactions(&mut self) -> &mut Self
+    {
+        while self.step_reaction() {}
+        self
+    }
+}
+
+impl Default for ReactTestApp
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,70 @@
+//local shortcuts
+use crate::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+use std::collections::VecDeque;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Buffers reaction commands so they can be run one at a time by [`ReactTestApp`](crate::testing::ReactTestApp).
+#[derive(Resource, Default)]
+pub(crate) struct ReactionStepper
+{
+    pending: VecDeque<ReactionCommand>,
+}
+
+impl ReactionStepper
+{
+    pub(crate) fn len(&self) -> usize
+    {
+        self.pending.len()
+    }
+
+    pub(crate) fn iter_reactors(&self) -> impl Iterator<Item = SystemCommand> + '_
+    {
+        self.pending.iter().filter_map(ReactionCommand::reactor)
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Buffers a reaction command in the [`ReactionStepper`] if it exists.
+///
+/// Returns the command if it should run immediately.
+pub(crate) fn intercept_reaction(world: &mut World, command: ReactionCommand) -> Option<ReactionCommand>
+{
+    // Coalesced reactions are only added to their batch, which doesn't run a reactor.
+    if command.reactor().is_none() { return Some(command); }
+    let Some(mut stepper) = world.get_resource_mut::<ReactionStepper>() else { return Some(command); };
+    stepper.pending.push_back(command);
+    None
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Runs the next reaction buffered in the [`ReactionStepper`].
+///
+/// Reactions buffered while it runs are moved ahead of older reactions, so reactions run in the same order as they
+/// would without stepping.
+///
+/// Returns `false` if there are no buffered reactions.
+pub(crate) fn step_reaction(world: &mut World) -> bool
+{
+    let Some(mut stepper) = world.get_resource_mut::<ReactionStepper>() else { return false; };
+    let Some(command) = stepper.pending.pop_front() else { return false; };
+    let num_older = stepper.pending.len();
+
+    command.run(world);
+
+    let Some(mut stepper) = world.get_resource_mut::<ReactionStepper>() else { return true; };
+    let mut triggered = stepper.pending.split_off(num_older);
+    triggered.append(&mut stepper.pending);
+    stepper.pending = triggered;
+    true
+}
+
+//-------------------------------------------------------------------------------------------------------------------
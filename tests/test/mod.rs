@@ -2,6 +2,8 @@
 mod common;
 mod ecs;
 mod react;
+#[cfg(feature = "testing")]
+mod testing;
 
 //API exports
 pub use common::*;
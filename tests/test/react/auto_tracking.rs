@@ -0,0 +1,135 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Copy, Clone)]
+struct TrackedEntities
+{
+    a: Entity,
+    b: Entity,
+}
+
+/// Reads entity `a` if the resource is zero, otherwise reads entity `b`.
+fn branching_reactor(
+    entities     : Res<TrackedEntities>,
+    res          : ReactRes<TestReactRes>,
+    components   : Reactive<TestComponent>,
+    mut recorder : ResMut<TestReactRecorder>,
+){
+    recorder.0 += 1;
+    let entity = if res.0 == 0 { entities.a } else { entities.b };
+    let _ = components.get(entity);
+}
+
+fn spawn_tracked_entities(world: &mut World)
+{
+    let a = world.spawn_empty().id();
+    let b = world.spawn_empty().id();
+    world.syscall((a, TestComponent(0)), insert_on_test_entity);
+    world.syscall((b, TestComponent(0)), insert_on_test_entity);
+    world.insert_resource(TrackedEntities{ a, b });
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+// auto-tracked reactors react to the state they read
+#[test]
+fn auto_tracked_reacts_to_reads()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes::default())
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+    spawn_tracked_entities(world);
+    let TrackedEntities{ a, b } = *world.resource::<TrackedEntities>();
+
+    // runs immediately
+    world.syscall((), |mut c: Commands| { c.react().on_auto_tracked(branching_reactor); });
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+
+    // reacts to the resource and entity a
+    world.syscall(0, update_react_res);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 2);
+    world.syscall((a, TestComponent(1)), update_test_entity);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 3);
+
+    // entity b is not read
+    world.syscall((b, TestComponent(1)), update_test_entity);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 3);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// auto-tracked reactors stop reacting to state they no longer read
+#[test]
+fn auto_tracked_follows_branches()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes::default())
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+    spawn_tracked_entities(world);
+    let TrackedEntities{ a, b } = *world.resource::<TrackedEntities>();
+    world.syscall((), |mut c: Commands| { c.react().on_auto_tracked(branching_reactor); });
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+
+    // switch branch
+    world.syscall(1, update_react_res);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 2);
+
+    // entity a is no longer read, entity b is
+    world.syscall((a, TestComponent(1)), update_test_entity);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 2);
+    world.syscall((b, TestComponent(1)), update_test_entity);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 3);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// revoked auto-tracked reactors no longer react
+#[test]
+fn auto_tracked_revoke()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes::default())
+        .init_resource::<TestReactRecorder>()
+        .insert_resource(SavedSystemCommand(None));
+    let world = app.world_mut();
+    spawn_tracked_entities(world);
+    let TrackedEntities{ a, .. } = *world.resource::<TrackedEntities>();
+    world.syscall((),
+        |mut c: Commands, mut saved: ResMut<SavedSystemCommand>|
+        {
+            saved.0 = Some(c.react().on_auto_tracked(branching_reactor));
+        }
+    );
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+
+    // revoke
+    let sys_command = world.resource::<SavedSystemCommand>().0.unwrap();
+    world.syscall(sys_command,
+        |In(sys_command): In<SystemCommand>, mut c: Commands| c.react().revoke_auto_tracked(sys_command)
+    );
+    assert!(world.get_entity(*sys_command).is_err());
+
+    world.syscall(1, update_react_res);
+    world.syscall((a, TestComponent(1)), update_test_entity);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
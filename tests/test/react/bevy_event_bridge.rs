@@ -0,0 +1,136 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Event, Clone)]
+struct BridgeEvent(usize);
+
+fn add_bridge_event_reactor(mut c: Commands) -> RevokeToken
+{
+    c.react().on_revokable(bevy_event::<BridgeEvent>(),
+        |event: BroadcastEvent<BridgeEvent>, mut recorder: ResMut<TestReactRecorder>|
+        {
+            recorder.0 += event.read().0;
+        }
+    )
+}
+
+fn count_bevy_events(world: &World) -> usize
+{
+    world.resource::<Events<BridgeEvent>>().iter_current_update_events().map(|e| e.0).sum()
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn bevy_event_to_broadcast()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .bridge_bevy_event::<BridgeEvent>()
+        .init_resource::<TestReactRecorder>();
+    app.world_mut().syscall((), add_bridge_event_reactor);
+
+    // send events (no reaction until the bridge runs)
+    app.world_mut().send_event(BridgeEvent(1));
+    app.world_mut().send_event(BridgeEvent(10));
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 0);
+
+    // forward events (reactions)
+    app.update();
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 11);
+
+    // events are only forwarded once
+    app.update();
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 11);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn bevy_event_bridge_custom_schedule()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .bridge_bevy_event_in::<BridgeEvent>(Update)
+        .init_resource::<TestReactRecorder>();
+    app.world_mut().syscall((), add_bridge_event_reactor);
+
+    // send events in an earlier schedule (reaction)
+    app.add_systems(PreUpdate, |mut writer: EventWriter<BridgeEvent>| { writer.send(BridgeEvent(3)); });
+    app.update();
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 3);
+    app.update();
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 6);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn broadcast_to_bevy_event()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .mirror_broadcast_to_bevy_event::<BridgeEvent>()
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+    world.syscall((), add_bridge_event_reactor);
+
+    // broadcast (reaction and bevy event)
+    world.broadcast(BridgeEvent(5));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 5);
+    assert_eq!(count_bevy_events(world), 5);
+
+    // broadcast with no reactors (bevy event)
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .mirror_broadcast_to_bevy_event::<BridgeEvent>();
+    let world = app.world_mut();
+    world.syscall((), |mut c: Commands| c.react().broadcast(BridgeEvent(7)));
+    assert_eq!(count_bevy_events(world), 7);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn bidirectional_bridge_no_echo()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .bridge_bevy_event::<BridgeEvent>()
+        .mirror_broadcast_to_bevy_event::<BridgeEvent>()
+        .init_resource::<TestReactRecorder>();
+    app.world_mut().syscall((), add_bridge_event_reactor);
+
+    // broadcast (reaction and bevy event)
+    app.world_mut().broadcast(BridgeEvent(2));
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 2);
+    assert_eq!(count_bevy_events(app.world()), 2);
+
+    // bridge doesn't forward the mirrored event back to reactors
+    app.update();
+    app.update();
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 2);
+
+    // bevy events are still forwarded, but not mirrored back to bevy events
+    app.world_mut().send_event(BridgeEvent(20));
+    app.update();
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 22);
+    assert_eq!(app.world().resource::<Events<BridgeEvent>>().len(), 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
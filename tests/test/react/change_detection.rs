@@ -0,0 +1,254 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component)]
+struct Health(usize);
+
+fn record_changed_health(
+    event        : ChangedEvent<Health>,
+    mut recorder : ResMut<TestReactRecorder>,
+    health       : Query<&Health>,
+){
+    recorder.0 = health.get(event.entity()).unwrap().0;
+}
+
+fn record_added_health(
+    event        : AddedEvent<Health>,
+    mut recorder : ResMut<TestReactRecorder>,
+    health       : Query<&Health>,
+){
+    recorder.0 = health.get(event.entity()).unwrap().0;
+}
+
+fn on_changed(mut c: Commands) -> RevokeToken
+{
+    c.react().on_revokable(changed::<Health>(), record_changed_health)
+}
+
+fn on_entity_changed(In(entity): In<Entity>, mut c: Commands) -> RevokeToken
+{
+    c.react().on_revokable(entity_changed::<Health>(entity), record_changed_health)
+}
+
+fn on_added(mut c: Commands) -> RevokeToken
+{
+    c.react().on_revokable(added::<Health>(), record_added_health)
+}
+
+fn set_health(world: &mut World, entity: Entity, health: usize)
+{
+    world.get_mut::<Health>(entity).unwrap().0 = health;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn component_changed()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // entities
+    let test_entity_a = world.spawn(Health(1)).id();
+    let test_entity_b = world.spawn(Health(2)).id();
+
+    // add reactor
+    world.syscall((), on_changed);
+
+    // scan (no reaction, the components were changed before the reactor was added)
+    schedule_change_reactors(world);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // change (no reaction until scanned)
+    set_health(world, test_entity_a, 10);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+    schedule_change_reactors(world);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 10);
+
+    // scan again (no reaction)
+    *world.resource_mut::<TestReactRecorder>() = TestReactRecorder::default();
+    schedule_change_reactors(world);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // change other entity (reaction)
+    set_health(world, test_entity_b, 20);
+    schedule_change_reactors(world);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 20);
+
+    // spawn (no reaction, additions are not changes)
+    *world.resource_mut::<TestReactRecorder>() = TestReactRecorder::default();
+    world.spawn(Health(30));
+    schedule_change_reactors(world);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn entity_changed_trigger()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // entities
+    let test_entity_a = world.spawn(Health(1)).id();
+    let test_entity_b = world.spawn(Health(2)).id();
+
+    // add reactor
+    world.syscall(test_entity_a, on_entity_changed);
+
+    // change other entity (no reaction)
+    set_health(world, test_entity_b, 20);
+    schedule_change_reactors(world);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // change target entity (reaction)
+    set_health(world, test_entity_a, 10);
+    schedule_change_reactors(world);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 10);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn component_added()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // entities
+    let test_entity = world.spawn(Health(1)).id();
+
+    // add reactors
+    world.syscall((), on_added);
+    world.syscall((), on_changed);
+
+    // scan (no reaction, the component was added before the reactor was added)
+    schedule_change_reactors(world);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // spawn (added reaction)
+    world.spawn(Health(5));
+    schedule_change_reactors(world);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 5);
+
+    // change (changed reaction)
+    set_health(world, test_entity, 7);
+    schedule_change_reactors(world);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 7);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn changed_filter()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // entity
+    let test_entity = world.spawn(Health(1)).id();
+
+    // add reactor
+    world.syscall((),
+        |mut c: Commands|
+        {
+            c.react().on(changed::<Health>().when(|health: &Health| health.0 == 0), infinitize_test_recorder);
+        }
+    );
+
+    // change (filtered out)
+    set_health(world, test_entity, 10);
+    schedule_change_reactors(world);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // change (reaction)
+    set_health(world, test_entity, 0);
+    schedule_change_reactors(world);
+    assert_eq!(world.resource::<TestReactRecorder>().0, usize::MAX);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn changes_scanned_in_schedules()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .scan_component_changes_in(PostUpdate)
+        .init_resource::<TestReactRecorder>();
+    let test_entity = app.world_mut().spawn(Health(1)).id();
+    app.world_mut().syscall((), on_changed);
+
+    // change in Update, scanned in PostUpdate
+    app.add_systems(Update, move |mut query: Query<&mut Health>| { query.get_mut(test_entity).unwrap().0 += 1; })
+        .add_systems(PostUpdate,
+            (|mut recorder: ResMut<TestReactRecorder>| { recorder.0 *= 10; })
+                .after(schedule_change_reactors)
+        );
+    app.update();
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 20);
+
+    // change in Update, scanned in PostUpdate (no extra reaction in Last)
+    app.update();
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 30);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn revoke_changed_reactor()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // entity
+    let test_entity = world.spawn(Health(1)).id();
+
+    // add reactors
+    let token_a = world.syscall((), on_changed);
+    let token_b = world.syscall(test_entity, on_entity_changed);
+
+    // change (reaction)
+    set_health(world, test_entity, 10);
+    schedule_change_reactors(world);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 10);
+
+    // revoke reactors
+    world.syscall(token_a, revoke_reactor);
+    world.syscall(token_b, revoke_reactor);
+
+    // change (no reaction)
+    set_health(world, test_entity, 20);
+    schedule_change_reactors(world);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 10);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
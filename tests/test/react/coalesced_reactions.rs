@@ -0,0 +1,157 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Records the coalesced reactions seen by each run of a reactor.
+#[derive(Resource, Default)]
+struct CoalescedRecorder
+{
+    runs: usize,
+    mutated: Vec<Entity>,
+    entities: Vec<Entity>,
+    resource_mutated: bool,
+}
+
+fn record_coalesced(event: CoalescedEvent, mut recorder: ResMut<CoalescedRecorder>)
+{
+    recorder.runs += 1;
+    recorder.mutated = event.mutated::<TestComponent>().collect();
+    recorder.entities = event.entities().collect();
+    recorder.resource_mutated = event.resource_mutated::<TestReactRes>();
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+// many mutations are delivered in one reaction per flush
+#[test]
+fn coalesced_mutations()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<CoalescedRecorder>();
+    let world = app.world_mut();
+
+    let entity_a = world.spawn_empty().id();
+    let entity_b = world.spawn_empty().id();
+    world.syscall((entity_a, TestComponent(0)), insert_on_test_entity);
+    world.syscall((entity_b, TestComponent(0)), insert_on_test_entity);
+    world.syscall((),
+        |mut c: Commands|
+        {
+            c.react().on(coalesce(mutation::<TestComponent>()), record_coalesced);
+        }
+    );
+
+    // mutate many times
+    for i in 0..50
+    {
+        world.syscall((entity_a, TestComponent(i)), update_test_entity);
+    }
+    world.syscall((entity_b, TestComponent(1)), update_test_entity);
+    world.syscall((entity_a, TestComponent(100)), update_test_entity);
+
+    // nothing runs until the flush
+    assert_eq!(world.resource::<CoalescedRecorder>().runs, 0);
+    world.syscall((), flush_coalesced_reactions);
+
+    let recorder = world.resource::<CoalescedRecorder>();
+    assert_eq!(recorder.runs, 1);
+    assert_eq!(recorder.mutated, vec![entity_a, entity_b]);
+    assert_eq!(recorder.entities, vec![entity_a, entity_b]);
+    assert!(!recorder.resource_mutated);
+
+    // flushing again does nothing
+    world.syscall((), flush_coalesced_reactions);
+    assert_eq!(world.resource::<CoalescedRecorder>().runs, 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// coalesced reactors don't affect normal reactors of the same trigger
+#[test]
+fn coalesced_entity_mutations_with_normal_reactor()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<CoalescedRecorder>()
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    let entity = world.spawn_empty().id();
+    world.syscall((entity, TestComponent(0)), insert_on_test_entity);
+    world.syscall(entity,
+        |In(entity): In<Entity>, mut c: Commands|
+        {
+            c.react().on(coalesce(entity_mutation::<TestComponent>(entity)), record_coalesced);
+            c.react().on(entity_mutation::<TestComponent>(entity),
+                move |world: &mut World| syscall(world, entity, update_test_recorder_with_component)
+            );
+        }
+    );
+
+    // the normal reactor runs immediately
+    world.syscall((entity, TestComponent(5)), update_test_entity);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 5);
+    world.syscall((entity, TestComponent(7)), update_test_entity);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 7);
+    assert_eq!(world.resource::<CoalescedRecorder>().runs, 0);
+
+    // the coalesced reactor runs once when the app updates
+    app.update();
+    let recorder = app.world().resource::<CoalescedRecorder>();
+    assert_eq!(recorder.runs, 1);
+    assert_eq!(recorder.mutated, vec![entity]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// resource mutations and component insertions can be coalesced together
+#[test]
+fn coalesced_resource_mutations()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes::default())
+        .init_resource::<CoalescedRecorder>();
+    let world = app.world_mut();
+
+    world.syscall((),
+        |mut c: Commands|
+        {
+            c.react().on(
+                (
+                    coalesce(resource_mutation::<TestReactRes>()),
+                    coalesce(insertion::<TestComponent>()),
+                ),
+                record_coalesced
+            );
+        }
+    );
+
+    // trigger
+    world.syscall(1, update_react_res);
+    world.syscall(2, update_react_res);
+    let entity = world.spawn_empty().id();
+    world.syscall((entity, TestComponent(0)), insert_on_test_entity);
+    world.syscall((), flush_coalesced_reactions);
+
+    let recorder = world.resource::<CoalescedRecorder>();
+    assert_eq!(recorder.runs, 1);
+    assert!(recorder.resource_mutated);
+    assert_eq!(recorder.mutated, vec![]);
+    assert_eq!(recorder.entities, vec![entity]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
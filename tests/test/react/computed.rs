@@ -0,0 +1,139 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq)]
+struct Doubled(usize);
+
+#[derive(Debug, PartialEq)]
+struct IsBig(bool);
+
+#[derive(Resource, Default)]
+struct ComputeCounts
+{
+    doubled: usize,
+    is_big: usize,
+    is_big_reactions: usize,
+}
+
+fn compute_doubled(res: ReactRes<TestReactRes>, mut counts: ResMut<ComputeCounts>) -> Doubled
+{
+    counts.doubled += 1;
+    Doubled(res.0 * 2)
+}
+
+fn compute_is_big(doubled: ReactRes<Computed<Doubled>>, mut counts: ResMut<ComputeCounts>) -> IsBig
+{
+    counts.is_big += 1;
+    IsBig(doubled.0 > 10)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+// computed values form a graph that only propagates changes
+#[test]
+fn computed_graph()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes::default())
+        .init_resource::<ComputeCounts>()
+        .add_computed(resource_mutation::<TestReactRes>(), compute_doubled)
+        .add_computed(resource_mutation::<Computed<Doubled>>(), compute_is_big)
+        .add_reactor(resource_mutation::<Computed<IsBig>>(),
+            |mut counts: ResMut<ComputeCounts>| { counts.is_big_reactions += 1; }
+        );
+    app.update();
+    let world = app.world_mut();
+
+    // initial values
+    assert_eq!(*world.react_resource::<Computed<Doubled>>().get(), Doubled(0));
+    assert_eq!(*world.react_resource::<Computed<IsBig>>().get(), IsBig(false));
+    assert_eq!(world.resource::<ComputeCounts>().doubled, 1);
+    assert_eq!(world.resource::<ComputeCounts>().is_big, 1);
+
+    // dependency changed, second computed value is unchanged
+    world.syscall(1, update_react_res);
+    assert_eq!(*world.react_resource::<Computed<Doubled>>().get(), Doubled(2));
+    assert_eq!(world.resource::<ComputeCounts>().doubled, 2);
+    assert_eq!(world.resource::<ComputeCounts>().is_big, 2);
+    assert_eq!(world.resource::<ComputeCounts>().is_big_reactions, 0);
+
+    // dependency mutated without changing, graph stops propagating
+    world.syscall(1, update_react_res);
+    assert_eq!(world.resource::<ComputeCounts>().doubled, 3);
+    assert_eq!(world.resource::<ComputeCounts>().is_big, 2);
+
+    // change propagates through the graph
+    world.syscall(6, update_react_res);
+    assert_eq!(*world.react_resource::<Computed<Doubled>>().get(), Doubled(12));
+    assert_eq!(*world.react_resource::<Computed<IsBig>>().get(), IsBig(true));
+    assert_eq!(world.resource::<ComputeCounts>().is_big, 3);
+    assert_eq!(world.resource::<ComputeCounts>().is_big_reactions, 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// computed values can depend on reactive components
+#[test]
+fn computed_from_components()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .add_computed(
+            (insertion::<TestComponent>(), mutation::<TestComponent>()),
+            |components: Query<&React<TestComponent>>| components.iter().map(|c| c.0).sum::<usize>()
+        );
+    app.update();
+    let world = app.world_mut();
+    assert_eq!(**world.react_resource::<Computed<usize>>(), 0);
+
+    // insert and mutate
+    let entity_a = world.spawn_empty().id();
+    let entity_b = world.spawn_empty().id();
+    world.syscall((entity_a, TestComponent(2)), insert_on_test_entity);
+    world.syscall((entity_b, TestComponent(3)), insert_on_test_entity);
+    assert_eq!(**world.react_resource::<Computed<usize>>(), 5);
+
+    world.syscall((entity_a, TestComponent(10)), update_test_entity);
+    assert_eq!(**world.react_resource::<Computed<usize>>(), 13);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// initial values are computed after dependencies added later in the app are available
+#[test]
+fn computed_late_dependencies()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<ComputeCounts>()
+        .add_computed(resource_mutation::<TestReactRes>(), compute_doubled)
+        .add_computed(resource_mutation::<Computed<Doubled>>(), compute_is_big)
+        .add_systems(Startup, |mut c: Commands| c.insert_react_resource(TestReactRes(3)));
+    assert!(!app.world().contains_react_resource::<Computed<Doubled>>());
+
+    // initial values
+    app.update();
+    let world = app.world_mut();
+    assert_eq!(*world.react_resource::<Computed<Doubled>>().get(), Doubled(6));
+    assert_eq!(*world.react_resource::<Computed<IsBig>>().get(), IsBig(false));
+
+    // dependency changed
+    world.syscall(6, update_react_res);
+    assert_eq!(*world.react_resource::<Computed<IsBig>>().get(), IsBig(true));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -380,6 +380,66 @@ fn component_insertion()
 
 //-------------------------------------------------------------------------------------------------------------------
 
+#[test]
+fn component_insertion_with_bevy_apis()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // add reactor
+    world.syscall((), on_insertion);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // spawn with bundle (reaction on next flush)
+    world.spawn((React::new(TestComponent(1)), Name::new("a")));
+    world.flush();
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+
+    // insert with world access (reaction on next flush)
+    let test_entity = world.spawn_empty().id();
+    world.entity_mut(test_entity).insert(React::new(TestComponent(2)));
+    world.flush();
+    assert_eq!(world.resource::<TestReactRecorder>().0, 2);
+
+    // spawn with commands (reaction)
+    world.syscall((), |mut c: Commands| { c.spawn(React::new(TestComponent(3))); });
+    assert_eq!(world.resource::<TestReactRecorder>().0, 3);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn mutation_after_bundle_insertion()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // entity
+    let test_entity = world.spawn(React::new(TestComponent(1))).id();
+
+    // add reactor
+    world.syscall(test_entity, on_entity_mutation);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // update (reaction)
+    world.syscall((test_entity, TestComponent(10)), update_test_entity);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 10);
+
+    // removal with world access (reaction after removal check)
+    world.syscall(test_entity, on_entity_removal);
+    world.entity_mut(test_entity).remove::<React<TestComponent>>();
+    schedule_removal_and_despawn_reactors(world);
+    assert_eq!(world.resource::<TestReactRecorder>().0, usize::MAX);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
 #[test]
 fn test_entity_muation()
 {
@@ -899,3 +959,182 @@ fn revoke_component_mutation_reactor()
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactComponent, Clone, PartialEq)]
+#[react(track_previous)]
+struct TrackedComponent(usize);
+
+#[derive(Resource, Default)]
+struct PreviousRecorder(Vec<Option<usize>>);
+
+fn record_previous(event: MutationEvent<TrackedComponent>, mut recorder: ResMut<PreviousRecorder>)
+{
+    recorder.0.push(event.previous().map(|c| c.0));
+}
+
+#[test]
+fn mutation_previous_value()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<PreviousRecorder>();
+    let world = app.world_mut();
+
+    // entity
+    let test_entity = world.spawn(React::new(TrackedComponent(1))).id();
+    world.flush();
+
+    // add reactors
+    world.syscall((),
+        move |mut c: Commands|
+        {
+            c.react().on(entity_mutation::<TrackedComponent>(test_entity), record_previous);
+            c.react().on(mutation::<TrackedComponent>(), record_previous);
+        }
+    );
+    let num_entities = world.entities().len();
+
+    // mutate twice (each mutation's reactors see their own previous value)
+    world.syscall((),
+        |mut c: Commands, mut components: ReactiveMut<TrackedComponent>|
+        {
+            components.single_mut(&mut c).1.0 = 2;
+            components.set_single_if_not_eq(&mut c, TrackedComponent(3));
+        }
+    );
+    assert_eq!(world.resource::<PreviousRecorder>().0, vec![Some(1), Some(1), Some(2), Some(2)]);
+    assert_eq!(world.entities().len(), num_entities);
+
+    // manual trigger (no previous value)
+    React::<TrackedComponent>::trigger_mutation(test_entity, world);
+    assert_eq!(world.resource::<PreviousRecorder>().0[4..], [None, None]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactComponent, Clone)]
+#[react(track_removed)]
+struct SlotComponent(usize);
+
+#[derive(Resource, Default)]
+struct RemovedRecorder(Vec<(Entity, Option<usize>)>);
+
+fn record_removed(event: RemovalEvent<SlotComponent>, mut recorder: ResMut<RemovedRecorder>)
+{
+    recorder.0.push((event.entity(), event.value().map(|c| c.0)));
+}
+
+#[test]
+fn removal_value()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<RemovedRecorder>();
+    let world = app.world_mut();
+
+    // entities
+    let test_entity_a = world.spawn(React::new(SlotComponent(1))).id();
+    let test_entity_b = world.spawn(React::new(SlotComponent(2))).id();
+    let test_entity_c = world.spawn(React::new(SlotComponent(3))).id();
+
+    // add reactors
+    world.syscall((),
+        move |mut c: Commands|
+        {
+            c.react().on(entity_removal::<SlotComponent>(test_entity_a), record_removed);
+            c.react().on(removal::<SlotComponent>(), record_removed);
+        }
+    );
+    let num_entities = world.entities().len();
+
+    // removal (both reactors see the value)
+    world.entity_mut(test_entity_a).remove::<React<SlotComponent>>();
+    schedule_removal_and_despawn_reactors(world);
+    assert_eq!(world.resource::<RemovedRecorder>().0, vec![(test_entity_a, Some(1)), (test_entity_a, Some(1))]);
+    assert_eq!(world.entities().len(), num_entities);
+
+    // removal by despawn
+    world.resource_mut::<RemovedRecorder>().0.clear();
+    world.despawn(test_entity_b);
+    schedule_removal_and_despawn_reactors(world);
+    assert_eq!(world.resource::<RemovedRecorder>().0, vec![(test_entity_b, Some(2))]);
+    assert_eq!(world.entities().len(), num_entities - 1);
+
+    // removed components can still be read
+    world.resource_mut::<RemovedRecorder>().0.clear();
+    let removed = world.entity_mut(test_entity_c).take::<React<SlotComponent>>().unwrap();
+    assert_eq!(removed.take().0, 3);
+    schedule_removal_and_despawn_reactors(world);
+    assert_eq!(world.resource::<RemovedRecorder>().0, vec![(test_entity_c, Some(3))]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn removal_value_batch()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<RemovedRecorder>();
+    let world = app.world_mut();
+
+    // entities
+    let test_entity_a = world.spawn(React::new(SlotComponent(1))).id();
+    let test_entity_b = world.spawn(React::new(SlotComponent(2))).id();
+    let test_entity_c = world.spawn(React::new(SlotComponent(3))).id();
+
+    // add reactor
+    world.syscall((),
+        move |mut c: Commands|
+        {
+            c.react().on(removal::<SlotComponent>(), record_removed);
+        }
+    );
+    let num_entities = world.entities().len();
+
+    // several removals detected in the same batch
+    world.despawn(test_entity_a);
+    world.despawn(test_entity_b);
+    world.entity_mut(test_entity_c).remove::<React<SlotComponent>>();
+    schedule_removal_and_despawn_reactors(world);
+    assert_eq!(
+        world.resource::<RemovedRecorder>().0,
+        vec![(test_entity_a, Some(1)), (test_entity_b, Some(2)), (test_entity_c, Some(3))]
+    );
+    assert_eq!(world.entities().len(), num_entities - 2);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn removal_value_untracked()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin);
+    let world = app.world_mut();
+    let test_entity = world.spawn(React::new(TestComponent(1))).id();
+
+    world.init_resource::<TestReactRecorder>();
+    world.syscall((),
+        move |mut c: Commands|
+        {
+            c.react().on(entity_removal::<TestComponent>(test_entity),
+                |event: RemovalEvent<TestComponent>, mut recorder: ResMut<TestReactRecorder>|
+                {
+                    recorder.0 = if event.value().is_some() { 1 } else { 2 };
+                }
+            );
+        }
+    );
+
+    // removal (no value, component is not `track_removed`)
+    world.entity_mut(test_entity).remove::<React<TestComponent>>();
+    schedule_removal_and_despawn_reactors(world);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 2);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
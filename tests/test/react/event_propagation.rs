@@ -0,0 +1,153 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct VisitRecorder(Vec<(Entity, Entity)>);
+
+/// Spawns `root -> middle -> leaf`.
+fn spawn_hierarchy(world: &mut World) -> (Entity, Entity, Entity)
+{
+    let leaf = world.spawn_empty().id();
+    let middle = world.spawn_empty().add_child(leaf).id();
+    let root = world.spawn_empty().add_child(middle).id();
+    (root, middle, leaf)
+}
+
+fn on_visit(In(entity): In<Entity>, mut c: Commands)
+{
+    c.react().on(entity_event::<()>(entity),
+        |event: EntityEvent<()>, mut recorder: ResMut<VisitRecorder>|
+        {
+            recorder.0.push((event.entity(), event.original_target()));
+        }
+    );
+}
+
+fn on_visit_stop(In(entity): In<Entity>, mut c: Commands)
+{
+    c.react().on(entity_event::<()>(entity), |event: EntityEvent<()>| event.stop_propagation());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn entity_event_bubble()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<VisitRecorder>();
+    let world = app.world_mut();
+    let (root, middle, leaf) = spawn_hierarchy(world);
+    world.syscall(root, on_visit);
+    world.syscall(middle, on_visit);
+    world.syscall(leaf, on_visit);
+    let num_entities = world.entities().len();
+
+    // bubble from leaf
+    world.propagate_entity_event(leaf, (), EventPropagation::Bubble);
+    assert_eq!(world.resource::<VisitRecorder>().0, vec![(leaf, leaf), (middle, leaf), (root, leaf)]);
+    assert_eq!(world.entities().len(), num_entities);
+
+    // bubble from middle
+    world.resource_mut::<VisitRecorder>().0.clear();
+    world.syscall((),
+        move |mut c: Commands| c.react().propagate_entity_event(middle, (), EventPropagation::Bubble)
+    );
+    assert_eq!(world.resource::<VisitRecorder>().0, vec![(middle, middle), (root, middle)]);
+    assert_eq!(world.entities().len(), num_entities);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn entity_event_tunnel()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<VisitRecorder>();
+    let world = app.world_mut();
+    let (root, middle, leaf) = spawn_hierarchy(world);
+    world.syscall(root, on_visit);
+    world.syscall(middle, on_visit);
+    world.syscall(leaf, on_visit);
+
+    // tunnel to leaf
+    world.propagate_entity_event(leaf, (), EventPropagation::Tunnel);
+    assert_eq!(world.resource::<VisitRecorder>().0, vec![(root, leaf), (middle, leaf), (leaf, leaf)]);
+
+    // target only
+    world.resource_mut::<VisitRecorder>().0.clear();
+    world.propagate_entity_event(leaf, (), EventPropagation::TargetOnly);
+    assert_eq!(world.resource::<VisitRecorder>().0, vec![(leaf, leaf)]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn entity_event_stop_propagation()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<VisitRecorder>();
+    let world = app.world_mut();
+    let (root, middle, leaf) = spawn_hierarchy(world);
+    world.syscall(root, on_visit);
+    world.syscall(middle, on_visit_stop);
+    world.syscall(middle, on_visit);
+    world.syscall(leaf, on_visit);
+    let num_entities = world.entities().len();
+
+    // bubble stops at middle (all of middle's reactors run)
+    world.propagate_entity_event(leaf, (), EventPropagation::Bubble);
+    assert_eq!(world.resource::<VisitRecorder>().0, vec![(leaf, leaf), (middle, leaf)]);
+    assert_eq!(world.entities().len(), num_entities);
+
+    // tunnel stops at middle
+    world.resource_mut::<VisitRecorder>().0.clear();
+    world.propagate_entity_event(leaf, (), EventPropagation::Tunnel);
+    assert_eq!(world.resource::<VisitRecorder>().0, vec![(root, leaf), (middle, leaf)]);
+    assert_eq!(world.entities().len(), num_entities);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn entity_event_propagation_any_entity_reactors()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<VisitRecorder>();
+    let world = app.world_mut();
+    let (root, middle, leaf) = spawn_hierarchy(world);
+    world.syscall(middle, on_visit);
+    world.react(|rc| rc.on_persistent(any_entity_event::<()>(),
+        |event: EntityEvent<()>, mut recorder: ResMut<VisitRecorder>|
+        {
+            recorder.0.push((event.entity(), event.entity()));
+        }
+    ));
+
+    // entity-agnostic reactors run once, at the first entity
+    world.propagate_entity_event(leaf, (), EventPropagation::Bubble);
+    assert_eq!(world.resource::<VisitRecorder>().0, vec![(leaf, leaf), (middle, leaf)]);
+
+    world.resource_mut::<VisitRecorder>().0.clear();
+    world.propagate_entity_event(leaf, (), EventPropagation::Tunnel);
+    assert_eq!(world.resource::<VisitRecorder>().0, vec![(root, root), (middle, leaf)]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -1,12 +1,34 @@
 //test modules
+mod auto_tracking;
+mod bevy_event_bridge;
+mod coalesced_reactions;
+mod computed;
+mod change_detection;
 mod entity_reactions;
 mod entity_world_reactor;
+mod event_propagation;
 mod event_reactions;
+mod named_system_commands;
 mod plugin;
+mod react_collections;
+mod react_errors;
+mod react_input_replay;
+mod react_inspector;
+mod react_tasks;
+mod reaction_cascades;
+mod reaction_limits;
 mod reaction_tree;
 mod reactor_combination;
 mod reactor_mode;
+mod reactor_priority;
+mod reactor_stats;
 mod resource_reactions;
+#[cfg(feature = "bevy_state")]
+mod state_reactions;
 mod system_commands;
 mod system_events;
+mod system_requests;
+mod timers;
+mod trigger_filters;
+mod typed_system_commands;
 mod world_reactor;
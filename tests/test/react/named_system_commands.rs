@@ -0,0 +1,126 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn add_to_recorder(mut recorder: ResMut<TestReactRecorder>)
+{
+    recorder.0 += 1;
+}
+
+fn run_named(In(name): In<&'static str>, mut c: Commands)
+{
+    c.run_named_command(name);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+// named system commands can be run by name
+#[test]
+fn run_named_system_commands()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>()
+        .add_named_command("ui/refresh", add_to_recorder)
+        .add_named_command(SysName::new::<TestComponent>("double"),
+            |mut recorder: ResMut<TestReactRecorder>| { recorder.0 *= 2; }
+        );
+    let world = app.world_mut();
+
+    // run by label
+    world.syscall("ui/refresh", run_named);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+
+    // run by sys name
+    world.syscall((),
+        |mut c: Commands| { c.run_named_command(SysName::new::<TestComponent>("double")); }
+    );
+    assert_eq!(world.resource::<TestReactRecorder>().0, 2);
+
+    // missing names are ignored
+    world.syscall("ui/missing", run_named);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 2);
+    assert_eq!(world.resource::<SystemCommandRegistry>().iter().count(), 2);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// duplicate names are rejected
+#[test]
+#[should_panic]
+fn duplicate_named_system_commands_panic()
+{
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>()
+        .add_named_command("ui/refresh", add_to_recorder)
+        .add_named_command("ui/refresh", add_to_recorder);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// duplicate names registered with commands keep the original command
+#[test]
+fn duplicate_named_system_commands_ignored()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>()
+        .add_named_command("ui/refresh", add_to_recorder);
+    let world = app.world_mut();
+    let original = world.resource::<SystemCommandRegistry>().get("ui/refresh").unwrap();
+
+    world.syscall((),
+        |mut c: Commands|
+        {
+            let command = c.spawn_system_command(|mut recorder: ResMut<TestReactRecorder>| { recorder.0 += 10; });
+            c.register_named_command("ui/refresh", command);
+        }
+    );
+    assert_eq!(world.resource::<SystemCommandRegistry>().get("ui/refresh"), Some(original));
+
+    world.syscall("ui/refresh", run_named);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// names are unregistered when the system command is despawned
+#[test]
+fn named_system_commands_unregister_on_despawn()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+    let signal = spawn_rc_system_command(app.world_mut(), add_to_recorder);
+    let command = SystemCommand(signal.entity());
+    app.register_named_command("ui/refresh", command)
+        .register_named_command("ui/refresh_alias", command);
+    assert_eq!(app.world().resource::<SystemCommandRegistry>().get("ui/refresh"), Some(command));
+
+    // auto-despawn
+    drop(signal);
+    app.update();
+    assert!(!app.world().resource::<SystemCommandRegistry>().contains("ui/refresh"));
+    assert!(!app.world().resource::<SystemCommandRegistry>().contains("ui/refresh_alias"));
+
+    // name can be reused
+    app.add_named_command("ui/refresh", add_to_recorder);
+    app.world_mut().syscall("ui/refresh", run_named);
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
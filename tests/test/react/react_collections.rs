@@ -0,0 +1,281 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct ElementRecorder
+{
+    inserted: Vec<usize>,
+    removed: Vec<usize>,
+    changed: Vec<usize>,
+    changed_one: usize,
+}
+
+#[derive(Resource, Default)]
+struct MapRecorder
+{
+    owner: Option<Entity>,
+    inserted: Vec<&'static str>,
+    changed: Vec<&'static str>,
+    removed: Vec<&'static str>,
+}
+
+#[derive(Resource, Default)]
+struct ScopeRecorder
+{
+    any: Vec<Option<Entity>>,
+    resource: Vec<Option<Entity>>,
+    entity: Vec<Option<Entity>>,
+    entity_changed: Vec<usize>,
+}
+
+type TestVec = ReactVec<usize>;
+type TestMap = ReactMap<&'static str, usize>;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+// vector element reactions
+#[test]
+fn react_vec_element_reactions()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestVec::default())
+        .init_resource::<ElementRecorder>();
+    let world = app.world_mut();
+
+    world.syscall((),
+        |mut c: Commands|
+        {
+            c.react().on(element_inserted::<TestVec>(),
+                |event: ElementEvent<TestVec>, mut recorder: ResMut<ElementRecorder>|
+                {
+                    assert_eq!(event.owner(), None);
+                    recorder.inserted.extend_from_slice(event.keys());
+                }
+            );
+            c.react().on(element_removed::<TestVec>(),
+                |event: ElementEvent<TestVec>, mut recorder: ResMut<ElementRecorder>|
+                {
+                    recorder.removed.extend_from_slice(event.keys());
+                }
+            );
+            c.react().on(any_element_changed::<TestVec>(),
+                |event: ElementEvent<TestVec>, mut recorder: ResMut<ElementRecorder>|
+                {
+                    recorder.changed.extend_from_slice(event.keys());
+                }
+            );
+            c.react().on(element_changed::<TestVec>(1),
+                |mut recorder: ResMut<ElementRecorder>|
+                {
+                    recorder.changed_one += 1;
+                }
+            );
+        }
+    );
+
+    // insert
+    world.syscall((),
+        |mut c: Commands, mut list: ReactResMut<TestVec>|
+        {
+            list.get_noreact().push(&mut c, 10);
+            list.get_noreact().extend(&mut c, [20, 30]);
+        }
+    );
+    assert_eq!(world.resource::<ElementRecorder>().inserted, vec![0, 1, 2]);
+
+    // change
+    world.syscall((),
+        |mut c: Commands, mut list: ReactResMut<TestVec>|
+        {
+            *list.get_noreact().get_mut(&mut c, 1).unwrap() += 1;
+            *list.get_noreact().get_mut(&mut c, 2).unwrap() += 1;
+            assert!(list.get_noreact().get_mut(&mut c, 5).is_none());
+        }
+    );
+    assert_eq!(world.resource::<ElementRecorder>().changed, vec![1, 2]);
+    assert_eq!(world.resource::<ElementRecorder>().changed_one, 1);
+
+    // remove
+    world.syscall((),
+        |mut c: Commands, mut list: ReactResMut<TestVec>|
+        {
+            assert_eq!(list.get_noreact().remove(&mut c, 0), 10);
+            assert_eq!(list.get_noreact().pop(&mut c), Some(31));
+        }
+    );
+    assert_eq!(world.resource::<ElementRecorder>().removed, vec![0, 1]);
+    assert_eq!(**world.react_resource::<TestVec>(), vec![21]);
+    assert_eq!(world.resource::<ElementRecorder>().inserted, vec![0, 1, 2]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// map element reactions on a component collection
+#[test]
+fn react_map_element_reactions()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<MapRecorder>();
+    let world = app.world_mut();
+
+    world.syscall((),
+        |mut c: Commands|
+        {
+            c.react().on(element_inserted::<TestMap>(),
+                |event: ElementEvent<TestMap>, mut recorder: ResMut<MapRecorder>|
+                {
+                    recorder.owner = event.owner();
+                    recorder.inserted.extend_from_slice(event.keys());
+                }
+            );
+            c.react().on(element_changed::<TestMap>("a"),
+                |event: ElementEvent<TestMap>, mut recorder: ResMut<MapRecorder>|
+                {
+                    recorder.changed.extend_from_slice(event.keys());
+                }
+            );
+            c.react().on(element_removed::<TestMap>(),
+                |event: ElementEvent<TestMap>, mut recorder: ResMut<MapRecorder>|
+                {
+                    recorder.removed.extend_from_slice(event.keys());
+                }
+            );
+        }
+    );
+    let entity = world.spawn(React::new(TestMap::default())).id();
+    world.flush();
+
+    // insert, then replace
+    world.syscall(entity,
+        |In(entity): In<Entity>, mut c: Commands, mut maps: ReactiveMut<TestMap>|
+        {
+            let map = maps.get_noreact(entity).unwrap();
+            map.insert(&mut c, "a", 1);
+            map.insert(&mut c, "b", 2);
+            map.insert(&mut c, "a", 3);
+            *map.get_mut(&mut c, &"b").unwrap() += 1;
+        }
+    );
+    assert_eq!(world.resource::<MapRecorder>().owner, Some(entity));
+    assert_eq!(world.resource::<MapRecorder>().inserted, vec!["a", "b"]);
+    assert_eq!(world.resource::<MapRecorder>().changed, vec!["a"]);
+
+    // remove
+    world.syscall(entity,
+        |In(entity): In<Entity>, mut c: Commands, mut maps: ReactiveMut<TestMap>|
+        {
+            let map = maps.get_noreact(entity).unwrap();
+            assert_eq!(map.remove(&mut c, &"a"), Some(3));
+            assert_eq!(map.remove(&mut c, &"a"), None);
+            assert_eq!(map.get(&"b"), Some(&3));
+        }
+    );
+    assert_eq!(world.resource::<MapRecorder>().removed, vec!["a"]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn push_to_component(In((entity, value)): In<(Entity, usize)>, mut c: Commands, mut lists: Query<&mut React<TestVec>>)
+{
+    lists.get_mut(entity).unwrap().get_noreact().push(&mut c, value);
+}
+
+// element triggers scoped to resources or entities
+#[test]
+fn react_collection_trigger_scopes()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestVec::default())
+        .init_resource::<ScopeRecorder>();
+    let world = app.world_mut();
+    let entity_a = world.spawn(React::new(TestVec::default())).id();
+    let entity_b = world.spawn(React::new(TestVec::default())).id();
+    world.flush();
+
+    world.syscall(entity_a,
+        |In(entity_a): In<Entity>, mut c: Commands|
+        {
+            c.react().on(element_inserted::<TestVec>(),
+                |event: ElementEvent<TestVec>, mut recorder: ResMut<ScopeRecorder>|
+                {
+                    recorder.any.push(event.owner());
+                }
+            );
+            c.react().on(resource_element_inserted::<TestVec>(),
+                |event: ElementEvent<TestVec>, mut recorder: ResMut<ScopeRecorder>|
+                {
+                    recorder.resource.push(event.owner());
+                }
+            );
+            c.react().on(entity_element_inserted::<TestVec>(entity_a),
+                |event: ElementEvent<TestVec>, mut recorder: ResMut<ScopeRecorder>|
+                {
+                    let event = event.read();
+                    assert_eq!(event.change(), ElementChange::Inserted);
+                    recorder.entity.push(event.owner());
+                }
+            );
+            c.react().on(entity_element_changed::<TestVec>(entity_a, 0),
+                |event: ElementEvent<TestVec>, mut recorder: ResMut<ScopeRecorder>|
+                {
+                    recorder.entity_changed.extend_from_slice(event.read().keys());
+                }
+            );
+        }
+    );
+
+    // resource
+    world.syscall((),
+        |mut c: Commands, mut list: ReactResMut<TestVec>|
+        {
+            list.get_noreact().push(&mut c, 1);
+        }
+    );
+
+    // entities
+    world.syscall((entity_a, entity_b),
+        |In((entity_a, entity_b)): In<(Entity, Entity)>, mut c: Commands, mut lists: ReactiveMut<TestVec>|
+        {
+            lists.get_noreact(entity_b).unwrap().push(&mut c, 2);
+            lists.get_noreact(entity_a).unwrap().push(&mut c, 3);
+            *lists.get_noreact(entity_b).unwrap().get_mut(&mut c, 0).unwrap() += 1;
+            *lists.get_noreact(entity_a).unwrap().get_mut(&mut c, 0).unwrap() += 1;
+        }
+    );
+
+    let recorder = world.resource::<ScopeRecorder>();
+    assert_eq!(recorder.any, vec![None, Some(entity_b), Some(entity_a)]);
+    assert_eq!(recorder.resource, vec![None]);
+    assert_eq!(recorder.entity, vec![Some(entity_a)]);
+    assert_eq!(recorder.entity_changed, vec![0]);
+
+    // collections accessed directly on the component
+    world.syscall((entity_b, 4), push_to_component);
+    assert_eq!(world.resource::<ScopeRecorder>().any.last(), Some(&Some(entity_b)));
+    assert_eq!(world.resource::<ScopeRecorder>().resource.len(), 1);
+
+    // collections are re-bound when moved to another entity
+    let list = world.entity_mut(entity_b).take::<React<TestVec>>().unwrap().take();
+    let entity_c = world.spawn(React::new(list)).id();
+    world.flush();
+    world.syscall((entity_c, 5), push_to_component);
+    assert_eq!(world.resource::<ScopeRecorder>().any.last(), Some(&Some(entity_c)));
+    assert_eq!(world.resource::<ScopeRecorder>().resource.len(), 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,140 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct ErrorLog(Vec<(SystemCommand, ReactionKind, String)>);
+
+fn fail_on_odd(event: BroadcastEvent<IntEvent>, mut c: Commands) -> Result<(), String>
+{
+    let n = event.read().0;
+    // Commands are applied even if the reactor fails.
+    c.react().trigger_resource_mutation::<TestReactRes>();
+    if n % 2 == 1 { return Err(format!("odd event {n}")); }
+    Ok(())
+}
+
+fn setup_app(app: &mut App)
+{
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes::default())
+        .init_resource::<TestReactRecorder>()
+        .init_resource::<ErrorLog>()
+        .insert_resource(ReactErrorHandler::new(
+            |world: &mut World, error: ReactorError|
+            {
+                let entry = (error.reactor, error.kind, error.error.to_string());
+                world.resource_mut::<ErrorLog>().0.push(entry);
+            }
+        ))
+        .react(|rc| rc.on_persistent(resource_mutation::<TestReactRes>(),
+            |mut recorder: ResMut<TestReactRecorder>| { recorder.0 += 1; }
+        ));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+// errors from fallible reactors are passed to the error handler
+#[test]
+fn fallible_reactor_errors()
+{
+    // setup
+    let mut app = App::new();
+    setup_app(&mut app);
+    let world = app.world_mut();
+    let reactor = world.spawn_fallible_system_command(fail_on_odd);
+    world.react(|rc| rc.with(broadcast::<IntEvent>(), reactor, ReactorMode::Persistent));
+
+    // success
+    world.broadcast(IntEvent(2));
+    assert!(world.resource::<ErrorLog>().0.is_empty());
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+
+    // failure
+    world.broadcast(IntEvent(3));
+    assert_eq!(world.resource::<ErrorLog>().0, vec![(reactor, ReactionKind::Broadcast, String::from("odd event 3"))]);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 2);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// fallible system commands can be run directly
+#[test]
+fn fallible_system_command_errors()
+{
+    // setup
+    let mut app = App::new();
+    setup_app(&mut app);
+    let world = app.world_mut();
+    let command = world.spawn_fallible_system_command(
+        || -> Result<(), std::io::Error> { Err(std::io::Error::other("io")) }
+    );
+
+    command.apply(world);
+    command.apply(world);
+    let log = &world.resource::<ErrorLog>().0;
+    assert_eq!(log.len(), 2);
+    assert_eq!(log[0], (command, ReactionKind::SystemCommand, String::from("io")));
+
+    // the default handler logs errors
+    world.insert_resource(ReactErrorHandler::default());
+    command.apply(world);
+    assert_eq!(world.resource::<ErrorLog>().0.len(), 2);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// one-off fallible reactors registered with on_fallible
+#[test]
+fn on_fallible_reactor_errors()
+{
+    // setup
+    let mut app = App::new();
+    setup_app(&mut app);
+    let world = app.world_mut();
+    world.react(|rc| rc.on_fallible(broadcast::<IntEvent>(), fail_on_odd));
+
+    // failure
+    world.broadcast(IntEvent(1));
+    let log = &world.resource::<ErrorLog>().0;
+    assert_eq!(log.len(), 1);
+    assert_eq!((log[0].1, log[0].2.as_str()), (ReactionKind::Broadcast, "odd event 1"));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// deferred recursive reactors report the reaction kind that triggered them
+#[test]
+fn recursive_fallible_reactor_errors()
+{
+    // setup
+    let mut app = App::new();
+    setup_app(&mut app);
+    let world = app.world_mut();
+    let reactor = world.spawn_fallible_system_command(
+        |event: BroadcastEvent<IntEvent>, mut c: Commands| -> Result<(), String>
+        {
+            let n = event.read().0;
+            if n == 0 { c.react().broadcast(IntEvent(1)); return Ok(()); }
+            Err(format!("event {n}"))
+        }
+    );
+    world.react(|rc| rc.with(broadcast::<IntEvent>(), reactor, ReactorMode::Persistent));
+
+    // the recursive run is deferred until the first run finishes
+    world.broadcast(IntEvent(0));
+    assert_eq!(world.resource::<ErrorLog>().0, vec![(reactor, ReactionKind::Broadcast, String::from("event 1"))]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
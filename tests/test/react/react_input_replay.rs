@@ -0,0 +1,289 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+use std::path::PathBuf;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct InputLog(Vec<(&'static str, usize)>);
+
+fn encode_int_event(event: &IntEvent) -> String
+{
+    event.0.to_string()
+}
+
+fn decode_int_event(payload: &str) -> Option<IntEvent>
+{
+    payload.parse().ok().map(IntEvent)
+}
+
+fn encode_test_component(component: &TestComponent) -> String
+{
+    component.0.to_string()
+}
+
+fn decode_test_component(payload: &str) -> Option<TestComponent>
+{
+    payload.parse().ok().map(TestComponent)
+}
+
+fn recording_path(name: &str) -> PathBuf
+{
+    std::env::temp_dir().join(format!("bevy_cobweb_{name}_{}.txt", std::process::id()))
+}
+
+/// Registers reactors that log their inputs. Returns the target entity and a system event receiver.
+fn setup_world(world: &mut World) -> (Entity, SystemCommand)
+{
+    let target = world.spawn_empty().id();
+    let receiver = world.spawn_system_command(
+        |mut event: SystemEvent<IntEvent>, mut log: ResMut<InputLog>|
+        {
+            log.0.push(("system_event", event.take().unwrap().0));
+        }
+    );
+    world.syscall(target,
+        |In(target): In<Entity>, mut c: Commands|
+        {
+            c.react().on_persistent(broadcast::<IntEvent>(),
+                move |mut c: Commands, event: BroadcastEvent<IntEvent>, mut log: ResMut<InputLog>|
+                {
+                    let n = event.read().0;
+                    log.0.push(("broadcast", n));
+                    // Inputs sent by reactors are not recorded.
+                    c.react().entity_event(target, IntEvent(n + 100));
+                }
+            );
+            c.react().on_persistent(entity_event::<IntEvent>(target),
+                |event: EntityEvent<IntEvent>, mut log: ResMut<InputLog>|
+                {
+                    log.0.push(("entity_event", event.read().1.0));
+                }
+            );
+            let triggers = (entity_insertion::<TestComponent>(target), entity_mutation::<TestComponent>(target));
+            c.react().on_persistent(triggers,
+                move |components: Reactive<TestComponent>, mut log: ResMut<InputLog>|
+                {
+                    log.0.push(("component", components.get(target).unwrap().0));
+                }
+            );
+        }
+    );
+    (target, receiver)
+}
+
+fn make_app() -> App
+{
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<InputLog>()
+        .register_recorded_event::<IntEvent>(encode_int_event, decode_int_event)
+        .register_recorded_component::<TestComponent>(encode_test_component, decode_test_component);
+    app
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+// inputs are recorded to a file and replayed in a fresh world
+#[test]
+fn record_and_replay_react_inputs()
+{
+    let path = recording_path("replay");
+
+    // record
+    let mut app = make_app();
+    app.add_plugins(ReactInputRecorderPlugin::new(path.clone()));
+    let (target, receiver) = setup_world(app.world_mut());
+    app.update();
+
+    app.world_mut().broadcast(IntEvent(1));
+    app.update();
+
+    app.world_mut().entity_event(target, IntEvent(2));
+    app.world_mut().react(|rc| rc.insert(target, TestComponent(3)));
+    app.update();
+    app.update();
+
+    let world = app.world_mut();
+    world.get_mut::<React<TestComponent>>(target).unwrap().get_noreact().0 = 4;
+    React::<TestComponent>::trigger_mutation(target, world);
+    world.send_system_event(receiver, IntEvent(5));
+    app.update();
+
+    let expected = vec![
+        ("broadcast", 1),
+        ("entity_event", 101),
+        ("entity_event", 2),
+        ("component", 3),
+        ("component", 4),
+        ("system_event", 5),
+    ];
+    assert_eq!(app.world().resource::<InputLog>().0, expected);
+
+    // check the recording
+    let recording = std::fs::read_to_string(&path).unwrap();
+    let inputs: Vec<_> = recording.lines().map(|line| RecordedReactInput::from_line(line).unwrap()).collect();
+    let summary: Vec<_> = inputs.iter().map(|input| (input.frame, input.kind, input.payload.as_str())).collect();
+    assert_eq!(summary, vec![
+        (1, ReactInputKind::Broadcast, "1"),
+        (2, ReactInputKind::EntityEvent, "2"),
+        (2, ReactInputKind::Insertion, "3"),
+        (4, ReactInputKind::Mutation, "4"),
+        (4, ReactInputKind::SystemEvent, "5"),
+    ]);
+    assert_eq!(inputs[1].target, Some(target));
+    assert_eq!(inputs[4].target, Some(receiver.0));
+
+    // replay
+    let mut app = make_app();
+    app.add_plugins(ReactInputReplayerPlugin::new(path.clone()));
+    let (replay_target, replay_receiver) = setup_world(app.world_mut());
+    assert_eq!((replay_target, replay_receiver), (target, receiver));
+
+    app.update();
+    app.update();
+    assert_eq!(app.world().resource::<InputLog>().0, expected[..2]);
+    assert!(!app.world().resource::<ReactInputReplayer>().is_finished());
+
+    app.update();
+    app.update();
+    app.update();
+    assert!(app.world().resource::<ReactInputReplayer>().is_finished());
+    assert_eq!(app.world().resource::<InputLog>().0, expected);
+    assert_eq!(app.world().get::<React<TestComponent>>(target).unwrap().0, 4);
+
+    let _ = std::fs::remove_file(path);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// paused recorders and unregistered types don't record inputs
+#[test]
+fn react_input_recorder_filters()
+{
+    let path = recording_path("filters");
+
+    let mut app = make_app();
+    app.add_plugins(ReactInputRecorderPlugin::new(path.clone()));
+    app.update();
+
+    app.world_mut().resource_mut::<ReactInputRecorder>().pause();
+    app.world_mut().broadcast(IntEvent(1));
+    app.world_mut().resource_mut::<ReactInputRecorder>().resume();
+    app.world_mut().broadcast(());
+    app.world_mut().broadcast(IntEvent(2));
+    app.update();
+
+    let recording = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(recording.lines().count(), 1);
+    let input = RecordedReactInput::from_line(recording.lines().next().unwrap()).unwrap();
+    assert_eq!(input.payload, "2");
+
+    let _ = std::fs::remove_file(path);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// recorded inputs round-trip through the line format
+#[test]
+fn recorded_react_input_lines()
+{
+    let input = RecordedReactInput{
+        frame: 7,
+        kind: ReactInputKind::EntityEvent,
+        type_name: String::from("my_crate::MyEvent"),
+        target: Some(Entity::from_raw(3)),
+        payload: String::from("{\"a\":\"b\tc\\nd\"}\nnext"),
+    };
+    let line = input.to_line();
+    assert!(!line.contains('\n'));
+    assert_eq!(RecordedReactInput::from_line(&line), Some(input));
+    assert_eq!(RecordedReactInput::from_line("not a line"), None);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "reflect_recording")]
+#[derive(Reflect, Debug, Clone, PartialEq)]
+struct Damage
+{
+    amount: u32,
+    source: String,
+}
+
+#[cfg(feature = "reflect_recording")]
+#[derive(ReactComponent, Reflect, Debug, PartialEq)]
+struct Health(u32);
+
+#[cfg(feature = "reflect_recording")]
+#[derive(Resource, Default)]
+struct ReflectLog(Vec<String>);
+
+#[cfg(feature = "reflect_recording")]
+fn make_reflect_app() -> (App, Entity)
+{
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<ReflectLog>()
+        .register_recorded_reflect_event::<Damage>()
+        .register_recorded_reflect_component::<Health>();
+    let target = app.world_mut().spawn_empty().id();
+    app.react(|rc| rc.on_persistent(broadcast::<Damage>(),
+            |event: BroadcastEvent<Damage>, mut log: ResMut<ReflectLog>|
+            {
+                let damage = event.read();
+                log.0.push(format!("{} {}", damage.amount, damage.source));
+            }
+        ))
+        .react(|rc| rc.on_persistent(entity_insertion::<Health>(target),
+            move |health: Reactive<Health>, mut log: ResMut<ReflectLog>|
+            {
+                log.0.push(format!("health {}", health.get(target).unwrap().0));
+            }
+        ));
+    (app, target)
+}
+
+// reflect types are recorded as RON and replayed in a fresh world
+#[cfg(feature = "reflect_recording")]
+#[test]
+fn record_and_replay_reflect_inputs()
+{
+    let path = recording_path("reflect");
+
+    // record
+    let (mut app, target) = make_reflect_app();
+    app.add_plugins(ReactInputRecorderPlugin::new(path.clone()));
+    app.world_mut().broadcast(Damage{ amount: 3, source: String::from("fire\tpit") });
+    app.world_mut().react(|rc| rc.insert(target, Health(10)));
+    app.update();
+
+    let expected = vec![String::from("3 fire\tpit"), String::from("health 10")];
+    assert_eq!(app.world().resource::<ReflectLog>().0, expected);
+
+    let recording = std::fs::read_to_string(&path).unwrap();
+    let inputs: Vec<_> = recording.lines().map(|line| RecordedReactInput::from_line(line).unwrap()).collect();
+    assert_eq!(inputs.len(), 2);
+    assert_eq!(inputs[0].payload, "(amount:3,source:\"fire\\tpit\")");
+    assert_eq!(inputs[1].payload, "(10)");
+
+    // replay
+    let (mut app, replay_target) = make_reflect_app();
+    app.add_plugins(ReactInputReplayerPlugin::new(path.clone()));
+    assert_eq!(replay_target, target);
+    app.update();
+    assert_eq!(app.world().resource::<ReflectLog>().0, expected);
+    assert_eq!(*app.world().get::<React<Health>>(target).unwrap().get(), Health(10));
+
+    let _ = std::fs::remove_file(path);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,141 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+use core::any::TypeId;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn register_reactors(In(entity): In<Entity>, mut c: Commands) -> (SystemCommand, RevokeToken, SystemCommand)
+{
+    let persistent = c.react().on_persistent(resource_mutation::<TestReactRes>(), || {});
+    let token = c.react().with_priority(5).on_revokable(
+        (entity_mutation::<TestComponent>(entity), despawn(entity)),
+        || {}
+    );
+    let coalesced = c.react().on_persistent(coalesce(mutation::<TestComponent>()), || {});
+    c.react().on_persistent(broadcast::<IntEvent>().filter(|event: &IntEvent| event.0 > 1), || {});
+    (persistent, token, coalesced)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+// registered reactors can be listed by reactor type
+#[test]
+fn inspect_reactors_by_type()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes::default());
+    let world = app.world_mut();
+    let entity = world.spawn_empty().id();
+    let (persistent, token, coalesced) = world.syscall(entity, register_reactors);
+    let revokable = SystemCommand::from(token.clone());
+
+    world.syscall((persistent, revokable, coalesced, entity),
+        |
+            In((persistent, revokable, coalesced, entity)): In<(SystemCommand, SystemCommand, SystemCommand, Entity)>,
+            inspector: ReactInspector
+        |
+        {
+            // resource reactor
+            let reactors = inspector.reactors_of(&ReactorType::ResourceMutation(TypeId::of::<TestReactRes>()));
+            assert_eq!(reactors.len(), 1);
+            assert_eq!(reactors[0].sys_command, persistent);
+            assert_eq!(reactors[0].mode, ReactorMode::Persistent);
+            assert_eq!(reactors[0].type_name, Some(std::any::type_name::<TestReactRes>()));
+
+            // entity reactor
+            let reactors = inspector.reactors_of(&ReactorType::EntityMutation(entity, TypeId::of::<TestComponent>()));
+            assert_eq!(reactors.len(), 1);
+            assert_eq!(reactors[0].sys_command, revokable);
+            assert_eq!(reactors[0].mode, ReactorMode::Revokable);
+            assert_eq!(reactors[0].priority, 5);
+            assert_eq!(reactors[0].type_name, Some(std::any::type_name::<TestComponent>()));
+
+            // coalesced reactor
+            let reactors = inspector.reactors_of(&ReactorType::ComponentMutation(TypeId::of::<TestComponent>()));
+            assert_eq!(reactors.len(), 1);
+            assert_eq!(reactors[0].sys_command, coalesced);
+            assert!(reactors[0].coalesced);
+            assert!(!reactors[0].filtered);
+
+            // filtered reactor
+            let reactors = inspector.reactors_of(&ReactorType::Broadcast(TypeId::of::<IntEvent>()));
+            assert_eq!(reactors.len(), 1);
+            assert!(reactors[0].filtered);
+            assert_eq!(inspector.type_name(TypeId::of::<IntEvent>()), Some(std::any::type_name::<IntEvent>()));
+
+            // all reactors, including the despawn reactor
+            assert_eq!(inspector.all_reactors().len(), 5);
+        }
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// reactors targeting an entity can be listed, and are removed when revoked
+#[test]
+fn inspect_entity_reactors()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes::default());
+    let world = app.world_mut();
+    let entity = world.spawn_empty().id();
+    let (_, token, _) = world.syscall(entity, register_reactors);
+    let revokable = SystemCommand::from(token.clone());
+
+    world.syscall((revokable, entity),
+        |In((revokable, entity)): In<(SystemCommand, Entity)>, inspector: ReactInspector|
+        {
+            let reactors = inspector.entity_reactors(entity);
+            assert_eq!(reactors.len(), 2);
+            assert!(reactors.iter().all(|info| info.sys_command == revokable));
+            assert!(reactors.iter().any(|info| info.reactor_type == ReactorType::Despawn(entity)));
+            assert_eq!(inspector.entities_with_reactors().collect::<Vec<_>>(), vec![entity]);
+        }
+    );
+
+    // revoke
+    world.syscall(token, revoke_reactor);
+    world.syscall(entity,
+        |In(entity): In<Entity>, inspector: ReactInspector|
+        {
+            assert_eq!(inspector.entity_reactors(entity).len(), 0);
+        }
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// tracked removals are listed with their type names
+#[test]
+fn inspect_tracked_removals()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin);
+    let world = app.world_mut();
+    world.syscall((), |mut c: Commands| c.react().on(removal::<TestComponent>(), || {}));
+
+    world.syscall((),
+        |inspector: ReactInspector|
+        {
+            assert_eq!(
+                inspector.tracked_removals(),
+                vec![(TypeId::of::<TestComponent>(), Some(std::any::type_name::<TestComponent>()))]
+            );
+        }
+    );
+}
+
+//-------------------------------------------------------------------------------------------------------------------
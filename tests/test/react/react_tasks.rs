@@ -0,0 +1,160 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct Steps(Vec<usize>);
+
+fn push_step(world: &AsyncWorld, step: usize) -> impl Future<Output = ()> + Send + 'static
+{
+    world.with(move |w: &mut World| w.resource_mut::<Steps>().0.push(step))
+}
+
+/// Future that is woken from outside the world.
+#[derive(Clone, Default)]
+struct ExternalSignal(Arc<Mutex<(bool, Option<Waker>)>>);
+
+impl ExternalSignal
+{
+    fn signal(&self)
+    {
+        let mut inner = self.0.lock().unwrap();
+        inner.0 = true;
+        if let Some(waker) = inner.1.take() { waker.wake(); }
+    }
+}
+
+impl Future for ExternalSignal
+{
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()>
+    {
+        let mut inner = self.0.lock().unwrap();
+        if inner.0 { return Poll::Ready(()); }
+        inner.1 = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+// tasks run sequentially, resuming when the reactions they wait for run
+#[test]
+fn async_task_waits_for_reactions()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<Steps>();
+    let world = app.world_mut();
+    let entity = world.spawn_empty().id();
+
+    world.syscall((),
+        move |mut c: Commands|
+        {
+            c.react().spawn_async(move |world: AsyncWorld| async move {
+                push_step(&world, 0).await;
+                world.wait_for(broadcast::<()>()).await;
+                push_step(&world, 1).await;
+                let data = world.wait_for_with(
+                    entity_event::<IntEvent>(entity),
+                    |event: EntityEvent<IntEvent>| event.read().1.0
+                ).await;
+                push_step(&world, data).await;
+            });
+        }
+    );
+    assert_eq!(world.resource::<Steps>().0, vec![0]);
+
+    // unrelated event
+    world.syscall((entity, 10), send_entity_event);
+    assert_eq!(world.resource::<Steps>().0, vec![0]);
+
+    // resume
+    world.broadcast(());
+    assert_eq!(world.resource::<Steps>().0, vec![0, 1]);
+
+    // read event data
+    world.syscall((entity, 20), send_entity_event);
+    assert_eq!(world.resource::<Steps>().0, vec![0, 1, 20]);
+
+    // finished
+    world.broadcast(());
+    world.syscall((entity, 30), send_entity_event);
+    assert_eq!(world.resource::<Steps>().0, vec![0, 1, 20]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// tasks can trigger the reactions they wait for
+#[test]
+fn async_task_triggers_own_reaction()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<Steps>();
+    let world = app.world_mut();
+
+    world.syscall((),
+        |mut c: Commands|
+        {
+            c.react().spawn_async(|world: AsyncWorld| async move {
+                let waiter = world.with(|w: &mut World| w.react(|rc| rc.wait_for(broadcast::<()>()))).await;
+                world.with(|w: &mut World| w.broadcast(())).await;
+                waiter.await;
+                push_step(&world, 0).await;
+            });
+        }
+    );
+    assert_eq!(world.resource::<Steps>().0, vec![0]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// tasks woken by other sources are polled in Last
+#[test]
+fn async_task_polled_in_last()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<Steps>();
+    let signal = ExternalSignal::default();
+    let signal_clone = signal.clone();
+
+    app.world_mut().syscall((),
+        move |mut c: Commands|
+        {
+            let signal = signal_clone.clone();
+            c.react().spawn_async(move |world: AsyncWorld| async move {
+                signal.await;
+                push_step(&world, 0).await;
+            });
+        }
+    );
+    app.update();
+    assert_eq!(app.world().resource::<Steps>().0, Vec::<usize>::default());
+
+    // wake
+    signal.signal();
+    assert_eq!(app.world().resource::<Steps>().0, Vec::<usize>::default());
+    app.update();
+    assert_eq!(app.world().resource::<Steps>().0, vec![0]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,165 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource)]
+struct Target(Entity);
+
+fn on_broadcast(mut c: Commands, mut res: ReactResMut<TestReactRes>)
+{
+    res.get_mut(&mut c).0 += 1;
+}
+
+fn on_resource(mut c: Commands, target: Res<Target>)
+{
+    c.react().entity_event(target.0, IntEvent(1));
+}
+
+fn on_entity_event(mut recorder: ResMut<TestReactRecorder>)
+{
+    recorder.0 += 1;
+}
+
+fn setup_chain(world: &mut World) -> Entity
+{
+    let target = world.spawn_empty().id();
+    world.insert_resource(Target(target));
+    world.syscall(target,
+        |In(target): In<Entity>, mut c: Commands|
+        {
+            c.react().on_persistent(broadcast::<()>(), on_broadcast);
+            c.react().on_persistent(resource_mutation::<TestReactRes>(), on_resource);
+            c.react().on_persistent(entity_event::<IntEvent>(target), on_entity_event);
+        }
+    );
+    target
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+// cascades are recorded as trees
+#[test]
+fn record_reaction_cascade()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes::default())
+        .init_resource::<TestReactRecorder>()
+        .enable_cascade_recorder(10);
+    let world = app.world_mut();
+    let target = setup_chain(world);
+    world.resource_mut::<CascadeRecorder>().clear();
+
+    // broadcast -> resource mutation -> entity event
+    let sender = world.spawn_system_command(|mut c: Commands| c.react().broadcast(()));
+    sender.apply(world);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+
+    // check the tree
+    let recorder = world.resource::<CascadeRecorder>();
+    assert_eq!(recorder.cascades().count(), 1);
+    let cascade = recorder.latest().unwrap();
+    assert_eq!(cascade.num_nodes(), 4);
+    assert_eq!(cascade.depth(), 3);
+
+    let root = cascade.root();
+    assert_eq!(root.kind, ReactionKind::SystemCommand);
+    assert_eq!(root.reactor, sender);
+    assert_eq!(root.depth, 0);
+
+    let kinds: Vec<_> = cascade.nodes().iter().map(|node| (node.kind, node.depth, node.parent)).collect();
+    assert_eq!(kinds, vec![
+        (ReactionKind::SystemCommand, 0, None),
+        (ReactionKind::Broadcast, 1, Some(0)),
+        (ReactionKind::ResourceMutation, 2, Some(1)),
+        (ReactionKind::EntityEvent, 3, Some(2)),
+    ]);
+    assert_eq!(cascade.nodes()[3].source, Some(target));
+    assert!(cascade.nodes()[3].name.unwrap().ends_with("on_entity_event"));
+    assert_eq!(cascade.children(2).count(), 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// only the last N cascades are kept
+#[test]
+fn cascade_recorder_keeps_last_n()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>()
+        .enable_cascade_recorder(2);
+    let world = app.world_mut();
+    let first = world.spawn_system_command(|| {});
+    let second = world.spawn_system_command(|| {});
+    let third = world.spawn_system_command(|| {});
+
+    first.apply(world);
+    second.apply(world);
+    third.apply(world);
+
+    let recorder = world.resource::<CascadeRecorder>();
+    let roots: Vec<_> = recorder.cascades().map(|cascade| cascade.root().reactor).collect();
+    assert_eq!(roots, vec![second, third]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// cascades can be exported as DOT and JSON
+#[test]
+fn cascade_recorder_export()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes::default())
+        .init_resource::<TestReactRecorder>()
+        .enable_cascade_recorder(10);
+    let world = app.world_mut();
+    let target = setup_chain(world);
+    world.resource_mut::<CascadeRecorder>().clear();
+    world.syscall((), |mut c: Commands| c.react().broadcast(()));
+
+    // the broadcast reactor is the root cause
+    let recorder = world.resource::<CascadeRecorder>();
+
+    // dot
+    let dot = recorder.to_dot();
+    assert!(dot.starts_with("digraph cascades {"));
+    assert!(dot.contains("subgraph cluster_0"));
+    assert!(dot.contains("c0_0 -> c0_1;"));
+    assert!(dot.contains("c0_1 -> c0_2;"));
+    assert!(dot.contains(&format!("source: {target}")));
+
+    // json
+    let json = recorder.to_json();
+    assert!(json.starts_with("[{\"nodes\":[{\"id\":0,\"kind\":\"Broadcast\""));
+    assert!(json.contains("\"kind\":\"EntityEvent\""));
+    assert!(json.contains(&format!("\"source\":\"{target}\"")));
+    assert!(json.contains("\"parent\":1,\"children\":[]"));
+
+    // files
+    let dir = std::env::temp_dir();
+    let dot_path = dir.join(format!("bevy_cobweb_cascades_{}.dot", std::process::id()));
+    let json_path = dir.join(format!("bevy_cobweb_cascades_{}.json", std::process::id()));
+    recorder.export_dot(&dot_path).unwrap();
+    recorder.export_json(&json_path).unwrap();
+    assert_eq!(std::fs::read_to_string(&dot_path).unwrap(), dot);
+    assert_eq!(std::fs::read_to_string(&json_path).unwrap(), json);
+    let _ = std::fs::remove_file(dot_path);
+    let _ = std::fs::remove_file(json_path);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,123 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct DetectedLoops(Vec<ReactionLoopDetected>);
+
+fn record_loops(event: BroadcastEvent<ReactionLoopDetected>, mut loops: ResMut<DetectedLoops>)
+{
+    loops.0.push(event.read().clone());
+}
+
+fn ping_pong(In((source, target)): In<(Entity, Entity)>, mut c: Commands)
+{
+    c.react().on_persistent(entity_mutation::<TestComponent>(source),
+        move |mut c: Commands, mut components: ReactiveMut<TestComponent>|
+        {
+            components.get_mut(&mut c, target).unwrap().0 += 1;
+        }
+    );
+}
+
+fn fan_out(In(target): In<Entity>, mut c: Commands)
+{
+    for i in 0..20
+    {
+        c.react().entity_event(target, IntEvent(i));
+    }
+}
+
+fn setup_app(app: &mut App)
+{
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>()
+        .init_resource::<DetectedLoops>()
+        .react(|rc| rc.on_persistent(broadcast::<ReactionLoopDetected>(), record_loops));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+// reactors that mutate each other are aborted at the depth limit
+#[test]
+fn reaction_loop_depth_limit()
+{
+    // setup
+    let mut app = App::new();
+    setup_app(&mut app);
+    let world = app.world_mut();
+    let a = world.spawn_empty().id();
+    let b = world.spawn_empty().id();
+    world.syscall((a, TestComponent(0)), insert_on_test_entity);
+    world.syscall((b, TestComponent(0)), insert_on_test_entity);
+    world.syscall((a, b), ping_pong);
+    world.syscall((b, a), ping_pong);
+
+    // trigger the loop
+    world.syscall((a, TestComponent(1)), update_test_entity);
+
+    // the loop was aborted and reported
+    let loops = &world.resource::<DetectedLoops>().0;
+    assert_eq!(loops.len(), 1);
+    let limits = *world.resource::<ReactionLimits>();
+    assert_eq!(loops[0].depth, limits.max_depth);
+    assert_eq!(loops[0].chain.len(), limits.max_depth + 1);
+    let a_val = world.get::<React<TestComponent>>(a).unwrap().0;
+    let b_val = world.get::<React<TestComponent>>(b).unwrap().0;
+    assert!(a_val + b_val <= 2 * limits.max_depth);
+
+    // later flushes run normally
+    world.syscall(a,
+        |In(a): In<Entity>, mut c: Commands| c.react().on(entity_event::<IntEvent>(a), infinitize_test_recorder)
+    );
+    world.syscall((a, 0), send_entity_event);
+    assert_eq!(world.resource::<TestReactRecorder>().0, usize::MAX);
+    assert_eq!(world.resource::<DetectedLoops>().0.len(), 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// the number of reactions per flush is limited
+#[test]
+fn reaction_loop_total_limit()
+{
+    // setup
+    let mut app = App::new();
+    setup_app(&mut app);
+    app.insert_resource(ReactionLimits{ max_depth: 64, max_reactions: 10 });
+    let world = app.world_mut();
+    let target = world.spawn_empty().id();
+    world.syscall(target,
+        |In(target): In<Entity>, mut c: Commands|
+        {
+            c.react().on_persistent(entity_event::<IntEvent>(target),
+                |mut recorder: ResMut<TestReactRecorder>| { recorder.0 += 1; }
+            );
+        }
+    );
+    let sender = world.spawn_system_command(
+        move |mut c: Commands| { c.syscall(target, fan_out); }
+    );
+
+    // run: the sender counts as one reaction
+    sender.apply(world);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 9);
+
+    let loops = &world.resource::<DetectedLoops>().0;
+    assert_eq!(loops.len(), 1);
+    assert_eq!(loops[0].reactions, 10);
+    assert_eq!(loops[0].depth, 1);
+    assert_eq!(loops[0].chain[0].0, sender);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
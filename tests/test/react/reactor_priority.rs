@@ -0,0 +1,138 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct OrderRecorder(Vec<u32>);
+
+fn record(id: u32) -> impl FnMut(ResMut<OrderRecorder>) + Send + Sync + 'static
+{
+    move |mut recorder: ResMut<OrderRecorder>| recorder.0.push(id)
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+/// Reactor with a high priority.
+struct UrgentReactor;
+
+impl WorldReactor for UrgentReactor
+{
+    type StartingTriggers = BroadcastTrigger<()>;
+    type Triggers = ();
+    const PRIORITY: i32 = 100;
+
+    fn reactor(self) -> SystemCommandCallback
+    {
+        SystemCommandCallback::new(record(100))
+    }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+// reactors run in priority order regardless of registration order
+#[test]
+fn mutation_reactors_run_by_priority()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<OrderRecorder>();
+    let world = app.world_mut();
+
+    world.syscall((),
+        |mut c: Commands|
+        {
+            c.react().on(mutation::<TestComponent>(), record(0));
+            c.react().with_priority(-5).on(mutation::<TestComponent>(), record(1));
+            c.react().with_priority(5).on(mutation::<TestComponent>(), record(2));
+            c.react().on(mutation::<TestComponent>(), record(3));
+        }
+    );
+
+    // mutate
+    let entity = world.spawn_empty().id();
+    world.syscall(entity, |In(entity): In<Entity>, mut c: Commands| { c.react().insert(entity, TestComponent(0)); });
+    world.syscall(entity,
+        |In(entity): In<Entity>, mut c: Commands, mut q: Query<&mut React<TestComponent>>|
+        {
+            q.get_mut(entity).unwrap().get_mut(&mut c).0 = 1;
+        }
+    );
+
+    // high priority first, equal priorities in registration order
+    assert_eq!(world.resource::<OrderRecorder>().0, vec![2, 0, 3, 1]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// entity-specific and entity-agnostic reactors are ordered together
+#[test]
+fn entity_and_agnostic_reactors_merge_by_priority()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<OrderRecorder>();
+    let world = app.world_mut();
+    let entity = world.spawn_empty().id();
+    world.syscall(entity, |In(entity): In<Entity>, mut c: Commands| { c.react().insert(entity, TestComponent(0)); });
+
+    world.syscall(entity,
+        |In(entity): In<Entity>, mut c: Commands|
+        {
+            c.react().with_priority(1).on(mutation::<TestComponent>(), record(0));
+            c.react().with_priority(3).on(entity_mutation::<TestComponent>(entity), record(1));
+            c.react().with_priority(2).on(mutation::<TestComponent>(), record(2));
+            c.react().on(entity_mutation::<TestComponent>(entity), record(3));
+        }
+    );
+
+    // mutate
+    world.syscall(entity,
+        |In(entity): In<Entity>, mut c: Commands, mut q: Query<&mut React<TestComponent>>|
+        {
+            q.get_mut(entity).unwrap().get_mut(&mut c).0 = 1;
+        }
+    );
+
+    assert_eq!(world.resource::<OrderRecorder>().0, vec![1, 2, 0, 3]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// broadcast reactors and world reactors respect priorities
+#[test]
+fn broadcast_reactors_run_by_priority()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<OrderRecorder>()
+        .add_world_reactor_with(UrgentReactor, broadcast::<()>());
+    let world = app.world_mut();
+
+    world.syscall((),
+        |mut c: Commands|
+        {
+            c.react().on(broadcast::<()>(), record(0));
+            c.react().with_priority(200).on(broadcast::<()>(), record(1));
+        }
+    );
+
+    // broadcast
+    world.syscall((), |mut c: Commands| { c.react().broadcast(()); });
+
+    assert_eq!(world.resource::<OrderRecorder>().0, vec![1, 100, 0]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
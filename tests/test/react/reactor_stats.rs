@@ -0,0 +1,197 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+use std::time::Duration;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn add_to_recorder(mut recorder: ResMut<TestReactRecorder>)
+{
+    recorder.0 += 1;
+}
+
+fn slow_reactor()
+{
+    std::thread::sleep(Duration::from_millis(10));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+// system command invocations are counted and labeled
+#[test]
+fn reactor_stats_count_invocations()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>()
+        .enable_reactor_stats(ReactorStatsMode::Cumulative);
+    let world = app.world_mut();
+    let command = world.spawn_system_command(add_to_recorder);
+
+    // run
+    world.send_system_event(command, ());
+    command.apply(world);
+    command.apply(world);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 3);
+
+    let stats = world.resource::<ReactorStats>();
+    let stat = stats.get(command).unwrap();
+    assert_eq!(stat.invocations, 3);
+    assert!(stat.name.unwrap().ends_with("add_to_recorder"));
+    assert!(stat.max_time <= stat.total_time);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// time spent in nested reactions is attributed to the nested reactors
+#[test]
+fn reactor_stats_exclusive_time()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes::default())
+        .enable_reactor_stats(ReactorStatsMode::Cumulative);
+    let world = app.world_mut();
+    let slow = world.syscall((),
+        |mut c: Commands| c.react().on_persistent(resource_mutation::<TestReactRes>(), slow_reactor)
+    );
+    let trigger = world.spawn_system_command(
+        |mut c: Commands, mut res: ReactResMut<TestReactRes>| { res.get_mut(&mut c).0 += 1; }
+    );
+
+    // run
+    trigger.apply(world);
+
+    let stats = world.resource::<ReactorStats>();
+    let slow_time = stats.get(slow).unwrap().total_time;
+    let trigger_time = stats.get(trigger).unwrap().total_time;
+    assert!(slow_time >= Duration::from_millis(10));
+    assert!(trigger_time < slow_time);
+    assert_eq!(stats.sorted_by_total_time()[0].0, slow);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// stats can be reset each frame
+#[test]
+fn reactor_stats_reset_per_frame()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>()
+        .enable_reactor_stats(ReactorStatsMode::PerFrame);
+    let command = app.world_mut().spawn_system_command(add_to_recorder);
+
+    // run
+    command.apply(app.world_mut());
+    assert_eq!(app.world().resource::<ReactorStats>().get(command).unwrap().invocations, 1);
+
+    // next frame
+    app.update();
+    assert!(app.world().resource::<ReactorStats>().get(command).is_none());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// stats of despawned system commands are removed
+#[test]
+fn reactor_stats_prune_despawned()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>()
+        .enable_reactor_stats(ReactorStatsMode::Cumulative);
+    let world = app.world_mut();
+    let persistent = world.spawn_system_command(add_to_recorder);
+    world.react(|rc| rc.once(broadcast::<()>(), add_to_recorder));
+    world.react(|rc| rc.once(broadcast::<()>(), add_to_recorder));
+
+    // run
+    persistent.apply(world);
+    world.broadcast(());
+    assert_eq!(world.resource::<TestReactRecorder>().0, 3);
+    assert_eq!(world.resource::<ReactorStats>().iter().count(), 3);
+
+    // the one-off reactors were despawned
+    app.update();
+    let stats = app.world().resource::<ReactorStats>();
+    assert_eq!(stats.iter().map(|(sys_command, _)| sys_command).collect::<Vec<_>>(), vec![persistent]);
+    assert_eq!(stats.get(persistent).unwrap().invocations, 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// stats are not recorded unless enabled
+#[test]
+fn reactor_stats_disabled()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+    let command = world.spawn_system_command(add_to_recorder);
+
+    command.apply(world);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 1);
+    assert!(!world.contains_resource::<ReactorStats>());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// stats are recorded as bevy diagnostics
+#[cfg(feature = "bevy_diagnostic")]
+#[test]
+fn reactor_stats_diagnostics()
+{
+    use bevy::diagnostic::DiagnosticsStore;
+
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<DiagnosticsStore>()
+        .init_resource::<TestReactRecorder>()
+        .enable_reactor_stats(ReactorStatsMode::Cumulative);
+    let command = app.world_mut().spawn_system_command(add_to_recorder);
+    app.add_systems(Update, move |mut c: Commands| c.queue(command));
+
+    // run
+    app.update();
+    app.update();
+
+    let store = app.world().resource::<DiagnosticsStore>();
+    let count = store
+        .iter()
+        .find(|d| d.path().as_str().contains("add_to_recorder") && d.path().as_str().ends_with("/count"))
+        .unwrap();
+    assert_eq!(count.value(), Some(1.0));
+    assert_eq!(count.history_len(), 2);
+    assert_eq!(app.world().resource::<ReactorStats>().get(command).unwrap().invocations, 2);
+
+    // system commands running the same system share diagnostics
+    app.world_mut().react(|rc| rc.once(broadcast::<()>(), add_to_recorder));
+    app.world_mut().react(|rc| rc.once(broadcast::<()>(), add_to_recorder));
+    app.world_mut().broadcast(());
+    app.update();
+
+    let store = app.world().resource::<DiagnosticsStore>();
+    let counts: Vec<_> = store
+        .iter()
+        .filter(|d| d.path().as_str().contains("add_to_recorder") && d.path().as_str().ends_with("/count"))
+        .collect();
+    assert_eq!(counts.len(), 1);
+    assert_eq!(counts[0].value(), Some(3.0));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
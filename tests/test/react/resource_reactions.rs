@@ -104,3 +104,71 @@ fn revoke_once_reactor()
 }
 
 //-------------------------------------------------------------------------------------------------------------------
+
+#[derive(ReactResource, Clone, PartialEq)]
+#[react(track_previous)]
+struct TrackedReactRes(usize);
+
+#[derive(Resource, Default)]
+struct PreviousRecorder(Vec<Option<usize>>);
+
+#[test]
+fn resource_mutation_previous_value()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TrackedReactRes(1))
+        .init_resource::<PreviousRecorder>();
+    let world = app.world_mut();
+
+    // add reactors
+    world.syscall((),
+        |mut c: Commands|
+        {
+            for _ in 0..2
+            {
+                c.react().on(resource_mutation::<TrackedReactRes>(),
+                    |event: ResourceMutationEvent<TrackedReactRes>, mut recorder: ResMut<PreviousRecorder>|
+                    {
+                        recorder.0.push(event.previous().map(|r| r.0));
+                    }
+                );
+            }
+        }
+    );
+    let num_entities = world.entities().len();
+
+    // mutate twice (each mutation's reactors see their own previous value)
+    world.syscall((),
+        |mut c: Commands, mut res: ReactResMut<TrackedReactRes>|
+        {
+            res.get_mut(&mut c).0 = 2;
+            res.set_if_neq(&mut c, TrackedReactRes(3));
+        }
+    );
+    assert_eq!(world.resource::<PreviousRecorder>().0, vec![Some(1), Some(1), Some(2), Some(2)]);
+    assert_eq!(world.entities().len(), num_entities);
+
+    // manual trigger (no previous value)
+    world.trigger_resource_mutation::<TrackedReactRes>();
+    assert_eq!(world.resource::<PreviousRecorder>().0[4..], [None, None]);
+
+    // untracked resources don't have previous values
+    world.syscall((),
+        |mut c: Commands|
+        {
+            c.react().on(resource_mutation::<TestReactRes>(),
+                |event: ResourceMutationEvent<TestReactRes>, mut recorder: ResMut<PreviousRecorder>|
+                {
+                    recorder.0.push(event.previous().map(|r| r.0));
+                }
+            );
+        }
+    );
+    world.insert_react_resource(TestReactRes::default());
+    world.syscall(5, update_react_res);
+    assert_eq!(world.resource::<PreviousRecorder>().0[6..], [None]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
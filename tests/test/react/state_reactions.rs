@@ -0,0 +1,155 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(States, Default, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+enum TestState
+{
+    #[default]
+    A,
+    B,
+    C,
+}
+
+fn test_app() -> App
+{
+    let mut app = App::new();
+    app.add_plugins((StatesPlugin, ReactPlugin))
+        .init_state::<TestState>()
+        .init_resource::<TestReactRecorder>();
+    app.update();
+    app
+}
+
+fn set_state(app: &mut App, state: TestState)
+{
+    app.world_mut().resource_mut::<NextState<TestState>>().set(state);
+    app.update();
+}
+
+fn count_reactions(mut recorder: ResMut<TestReactRecorder>)
+{
+    recorder.0 += 1;
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn state_enter_exit()
+{
+    // setup
+    let mut app = test_app();
+    let world = app.world_mut();
+
+    // add reactors
+    world.react(|rc| rc.on_persistent(state_enter(TestState::B), count_reactions));
+    world.react(|rc| rc.on_persistent(state_exit(TestState::B),
+        |mut recorder: ResMut<TestReactRecorder>| { recorder.0 += 10; }
+    ));
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 0);
+
+    // enter B (reaction)
+    set_state(&mut app, TestState::B);
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 1);
+
+    // identity transition (no reaction)
+    set_state(&mut app, TestState::B);
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 1);
+
+    // exit B (reaction)
+    set_state(&mut app, TestState::C);
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 11);
+
+    // enter and exit other states (no reaction)
+    set_state(&mut app, TestState::A);
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 11);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn state_transition_reader()
+{
+    // setup
+    let mut app = test_app();
+    let world = app.world_mut();
+
+    // add reactor
+    world.react(|rc| rc.on_persistent(state_transition::<TestState>(),
+        |transition: StateTransitionReader<TestState>, mut recorder: ResMut<TestReactRecorder>|
+        {
+            assert_eq!(transition.from(), Some(&TestState::A));
+            assert_eq!(transition.to(), Some(&TestState::C));
+            recorder.0 += 1;
+        }
+    ));
+
+    // transition (reaction)
+    set_state(&mut app, TestState::C);
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn state_trigger_in_bundle()
+{
+    // setup
+    let mut app = test_app();
+    let world = app.world_mut();
+
+    // add reactor
+    let token = world.react(|rc| rc.on_revokable((state_enter(TestState::B), broadcast::<()>()), count_reactions));
+
+    // broadcast (reaction)
+    world.broadcast(());
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 1);
+
+    // enter B (reaction)
+    set_state(&mut app, TestState::B);
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 2);
+
+    // revoke
+    app.world_mut().react(|rc| rc.revoke(token));
+
+    // enter B again (no reaction)
+    set_state(&mut app, TestState::A);
+    set_state(&mut app, TestState::B);
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 2);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn state_reactor_registered_in_on_enter()
+{
+    // setup
+    let mut app = test_app();
+    app.add_systems(OnEnter(TestState::B),
+        |mut c: Commands|
+        {
+            c.react().on(state_enter(TestState::C), count_reactions);
+        }
+    );
+
+    // enter B (registers reactor)
+    set_state(&mut app, TestState::B);
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 0);
+
+    // enter C (reaction)
+    set_state(&mut app, TestState::C);
+    assert_eq!(app.world().resource::<TestReactRecorder>().0, 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
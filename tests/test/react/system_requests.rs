@@ -0,0 +1,138 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Component)]
+struct Equippable;
+
+#[derive(Resource, Default)]
+struct Responses(Vec<bool>);
+
+fn can_equip(mut request: SystemRequest<Entity, bool>, items: Query<(), With<Equippable>>)
+{
+    let Some(item) = request.take() else { return; };
+    request.respond(items.contains(item));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+// requests sent from the world return the response
+#[test]
+fn system_request_from_world()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin);
+    let world = app.world_mut();
+    let command = world.spawn_system_command(can_equip);
+    let sword = world.spawn(Equippable).id();
+    let rock = world.spawn_empty().id();
+    let num_entities = world.entities().len();
+
+    // respond
+    assert_eq!(world.send_system_request::<Entity, bool>(command, sword), Some(true));
+    assert_eq!(world.send_system_request::<Entity, bool>(command, rock), Some(false));
+
+    // request type mismatch
+    assert_eq!(world.send_system_request::<Entity, usize>(command, sword), None);
+    assert_eq!(world.send_system_request::<usize, bool>(command, 0), None);
+
+    // request data is cleaned up
+    assert_eq!(world.entities().len(), num_entities);
+
+    // missing system
+    world.despawn(*command);
+    assert_eq!(world.send_system_request::<Entity, bool>(command, sword), None);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// requests sent with commands deliver the response to a callback
+#[test]
+fn system_request_with_callback()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<Responses>();
+    let world = app.world_mut();
+    let command = world.spawn_system_command(can_equip);
+    let sword = world.spawn(Equippable).id();
+    let rock = world.spawn_empty().id();
+
+    world.syscall((command, sword, rock),
+        |In((command, sword, rock)): In<(SystemCommand, Entity, Entity)>, mut c: Commands|
+        {
+            c.send_system_request(command, sword,
+                |In(response): In<bool>, mut responses: ResMut<Responses>| responses.0.push(response)
+            );
+            c.send_system_request(command, rock,
+                |In(response): In<bool>, mut responses: ResMut<Responses>| responses.0.push(response)
+            );
+
+            // no response, the callback won't run
+            c.send_system_request(command, 0usize,
+                |In(_): In<bool>, mut responses: ResMut<Responses>| responses.0.push(true)
+            );
+        }
+    );
+    assert_eq!(world.resource::<Responses>().0, vec![true, false]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource)]
+struct RecursiveTarget(SystemCommand);
+
+#[derive(Resource, Default)]
+struct RecursiveLog
+{
+    requests: Vec<Option<usize>>,
+    responses: Vec<usize>,
+}
+
+fn request_self(
+    mut c       : Commands,
+    mut request : SystemRequest<usize, usize>,
+    target      : Res<RecursiveTarget>,
+    mut log     : ResMut<RecursiveLog>
+){
+    let value = request.take();
+    log.requests.push(value);
+    let Some(value) = value else { return; };
+    c.send_system_request(target.0, value + 1,
+        |In(response): In<usize>, mut log: ResMut<RecursiveLog>| log.responses.push(response)
+    );
+    request.respond(value);
+}
+
+// requests sent to a system command that is already running are dropped
+#[test]
+fn system_request_to_running_command()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<RecursiveLog>();
+    let world = app.world_mut();
+    let command = world.spawn_system_command(request_self);
+    world.insert_resource(RecursiveTarget(command));
+    let num_entities = world.entities().len();
+
+    // the inner request is not delivered, and the system command doesn't run again without a request
+    assert_eq!(world.send_system_request::<usize, usize>(command, 1), Some(1));
+    assert_eq!(world.resource::<RecursiveLog>().requests, vec![Some(1)]);
+    assert!(world.resource::<RecursiveLog>().responses.is_empty());
+    assert_eq!(world.entities().len(), num_entities);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,219 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+use bevy::time::{TimePlugin, TimeUpdateStrategy};
+
+//standard shortcuts
+use std::time::Duration;
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+const FRAME_TIME: Duration = Duration::from_millis(100);
+
+fn test_app() -> App
+{
+    let mut app = App::new();
+    app.add_plugins((TimePlugin, ReactPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME_TIME))
+        .init_resource::<TestReactRecorder>();
+    app.update();
+    app
+}
+
+fn count_reactions(mut recorder: ResMut<TestReactRecorder>)
+{
+    recorder.0 += 1;
+}
+
+fn recorder(app: &App) -> usize
+{
+    app.world().resource::<TestReactRecorder>().0
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn after_frames_trigger()
+{
+    // setup
+    let mut app = test_app();
+
+    // add reactor
+    app.world_mut().react(|rc| rc.on(after_frames(3), count_reactions));
+
+    // frames 1-2 (no reaction)
+    app.update();
+    app.update();
+    assert_eq!(recorder(&app), 0);
+
+    // frame 3 (reaction)
+    app.update();
+    assert_eq!(recorder(&app), 1);
+
+    // later frames (no reaction)
+    app.update();
+    app.update();
+    assert_eq!(recorder(&app), 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn after_duration_trigger()
+{
+    // setup
+    let mut app = test_app();
+
+    // add reactor
+    app.world_mut().react(|rc| rc.on(after_duration(FRAME_TIME * 2), count_reactions));
+
+    // first frame (no reaction)
+    app.update();
+    assert_eq!(recorder(&app), 0);
+
+    // duration elapsed (reaction)
+    app.update();
+    assert_eq!(recorder(&app), 1);
+
+    // later frames (no reaction)
+    app.update();
+    app.update();
+    assert_eq!(recorder(&app), 1);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn every_trigger()
+{
+    // setup
+    let mut app = test_app();
+
+    // add reactor
+    let token = app.world_mut().react(|rc| rc.on_revokable(every(FRAME_TIME * 2), count_reactions));
+
+    // run frames (reaction every other frame)
+    for _ in 0..6 { app.update(); }
+    assert_eq!(recorder(&app), 3);
+
+    // revoke reactor
+    app.world_mut().react(|rc| rc.revoke(token));
+
+    // run frames (no reactions)
+    for _ in 0..6 { app.update(); }
+    assert_eq!(recorder(&app), 3);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn revoke_timer_reactor()
+{
+    // setup
+    let mut app = test_app();
+
+    // add reactor
+    let token = app.world_mut().react(|rc| rc.on_revokable((after_frames(1), broadcast::<()>()), count_reactions));
+
+    // revoke before the timer expires
+    app.world_mut().react(|rc| rc.revoke(token));
+    app.update();
+    app.update();
+    assert_eq!(recorder(&app), 0);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn delayed_events()
+{
+    // setup
+    let mut app = test_app();
+    let test_entity = app.world_mut().spawn_empty().id();
+
+    // add reactors
+    app.world_mut().syscall((),
+        |mut c: Commands|
+        {
+            c.react().on_persistent(broadcast::<IntEvent>(), update_test_recorder_with_broadcast);
+        }
+    );
+    app.world_mut().syscall((),
+        move |mut c: Commands|
+        {
+            c.react().on_persistent(entity_event::<IntEvent>(test_entity), update_test_recorder_with_entity_event);
+        }
+    );
+
+    // send delayed events (no immediate reaction)
+    app.world_mut().react(|rc| rc.broadcast_delayed(IntEvent(1), FRAME_TIME));
+    app.world_mut().react(|rc| rc.entity_event_delayed(test_entity, IntEvent(2), FRAME_TIME * 2));
+    assert_eq!(recorder(&app), 0);
+
+    // broadcast
+    app.update();
+    assert_eq!(recorder(&app), 1);
+
+    // entity event
+    app.update();
+    assert_eq!(recorder(&app), 2);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn revoke_delayed_events()
+{
+    // setup
+    let mut app = test_app();
+    let test_entity = app.world_mut().spawn_empty().id();
+    app.world_mut().react(|rc| rc.on_persistent(broadcast::<IntEvent>(), update_test_recorder_with_broadcast));
+    app.world_mut().react(|rc| {
+        rc.on_persistent(entity_event::<IntEvent>(test_entity), update_test_recorder_with_entity_event)
+    });
+    let num_entities = app.world().entities().len();
+
+    // send delayed events
+    let broadcast_token = app.world_mut().react(|rc| rc.broadcast_delayed(IntEvent(1), FRAME_TIME));
+    let entity_event_token = app.world_mut().react(|rc| rc.entity_event_delayed(test_entity, IntEvent(2), FRAME_TIME));
+    app.world_mut().react(|rc| rc.broadcast_delayed(IntEvent(3), FRAME_TIME));
+
+    // revoke before expiry
+    app.world_mut().react(|rc| rc.revoke(broadcast_token));
+    app.world_mut().react(|rc| rc.revoke(entity_event_token));
+    assert_eq!(app.world().entities().len(), num_entities + 1);
+
+    // only the remaining event is sent
+    app.update();
+    assert_eq!(recorder(&app), 3);
+    app.update();
+    assert_eq!(recorder(&app), 3);
+    assert_eq!(app.world().entities().len(), num_entities);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn timer_reactor_cleanup()
+{
+    // setup
+    let mut app = test_app();
+
+    // add reactor
+    let num_entities = app.world().entities().len();
+    app.world_mut().react(|rc| rc.on(after_frames(1), count_reactions));
+    assert!(app.world().entities().len() > num_entities);
+
+    // reactor runs then is cleaned up
+    app.update();
+    app.update();
+    assert_eq!(recorder(&app), 1);
+    assert_eq!(app.world().entities().len(), num_entities);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,305 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+fn on_broadcast_filtered(mut c: Commands) -> RevokeToken
+{
+    c.react().on_revokable(
+        broadcast::<IntEvent>().filter(|event: &IntEvent| event.0 > 10),
+        update_test_recorder_with_broadcast
+    )
+}
+
+fn on_entity_event_filtered(In(entity): In<Entity>, mut c: Commands) -> RevokeToken
+{
+    c.react().on_revokable(
+        entity_event::<IntEvent>(entity).filter(|event: &IntEvent| event.0 > 3),
+        update_test_recorder_with_entity_event
+    )
+}
+
+fn on_any_entity_event_filtered(mut c: Commands) -> RevokeToken
+{
+    c.react().on_revokable(
+        any_entity_event::<IntEvent>().filter(|event: &IntEvent| event.0 > 5),
+        update_test_recorder_with_entity_event
+    )
+}
+
+fn on_entity_mutation_filtered(In(entity): In<Entity>, mut c: Commands) -> RevokeToken
+{
+    c.react().on_revokable(
+        entity_mutation::<TestComponent>(entity).when(|component: &TestComponent| component.0 == 0),
+        infinitize_test_recorder
+    )
+}
+
+fn on_mutation_filtered(mut c: Commands) -> RevokeToken
+{
+    c.react().on_revokable(
+        mutation::<TestComponent>().when(|component: &TestComponent| component.0 >= 100),
+        update_test_recorder_on_mutation
+    )
+}
+
+fn on_insertion_filtered(mut c: Commands) -> RevokeToken
+{
+    c.react().on_revokable(
+        insertion::<TestComponent>().when(|component: &TestComponent| component.0 >= 100),
+        update_test_recorder_on_insertion
+    )
+}
+
+fn on_resource_mutation_filtered(mut c: Commands) -> RevokeToken
+{
+    c.react().on_revokable(
+        resource_mutation::<TestReactRes>().when(|res: &TestReactRes| res.0 > 5),
+        update_test_recorder_with_resource
+    )
+}
+
+fn on_broadcast_filtered_or_resource(mut c: Commands) -> RevokeToken
+{
+    c.react().on_revokable(
+        (
+            broadcast::<IntEvent>().filter(|event: &IntEvent| event.0 > 10),
+            resource_mutation::<TestReactRes>(),
+        ),
+        update_test_recorder_with_broadcast_and_resource
+    )
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn broadcast_filter()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // add reactor
+    world.syscall((), on_broadcast_filtered);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // send event (filtered out)
+    world.syscall(5, send_broadcast);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // send event (reaction)
+    world.syscall(20, send_broadcast);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 20);
+
+    // send event (filtered out)
+    world.syscall(10, send_broadcast);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 20);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn filtered_broadcast_doesnt_leak_event_data()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // add reactor
+    world.syscall((), on_broadcast_filtered);
+    let num_entities = world.entities().len();
+
+    // send events (filtered out)
+    world.syscall(1, send_broadcast);
+    world.syscall(2, send_broadcast);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // send event (reaction)
+    world.syscall(30, send_broadcast);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 30);
+
+    // no event data entities remain
+    assert_eq!(world.entities().len(), num_entities);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn entity_event_filter()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+    let test_entity = world.spawn_empty().id();
+
+    // add reactor
+    world.syscall(test_entity, on_entity_event_filtered);
+
+    // send event (filtered out)
+    world.syscall((test_entity, 3), send_entity_event);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // send event (reaction)
+    world.syscall((test_entity, 4), send_entity_event);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 4);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn any_entity_event_filter()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+    let test_entity_a = world.spawn_empty().id();
+    let test_entity_b = world.spawn_empty().id();
+
+    // add reactor
+    world.syscall((), on_any_entity_event_filtered);
+
+    // send events (filtered out)
+    world.syscall((test_entity_a, 3), send_entity_event);
+    world.syscall((test_entity_b, 5), send_entity_event);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // send event (reaction)
+    world.syscall((test_entity_b, 8), send_entity_event);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 8);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn entity_mutation_filter()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+    let test_entity = world.spawn_empty().id();
+
+    // add reactor
+    world.syscall((test_entity, TestComponent(10)), insert_on_test_entity);
+    world.syscall(test_entity, on_entity_mutation_filtered);
+
+    // update (filtered out)
+    world.syscall((test_entity, TestComponent(5)), update_test_entity);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // update (reaction)
+    world.syscall((test_entity, TestComponent(0)), update_test_entity);
+    assert_eq!(world.resource::<TestReactRecorder>().0, usize::MAX);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn component_insertion_and_mutation_filters()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+    let test_entity = world.spawn_empty().id();
+
+    // add reactors
+    world.syscall((), on_insertion_filtered);
+    world.syscall((), on_mutation_filtered);
+
+    // insert (filtered out)
+    world.syscall((test_entity, TestComponent(1)), insert_on_test_entity);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // insert (reaction)
+    world.syscall((test_entity, TestComponent(100)), insert_on_test_entity);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 100);
+
+    // update (filtered out)
+    world.syscall((test_entity, TestComponent(2)), update_test_entity);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 100);
+
+    // update (reaction)
+    world.syscall((test_entity, TestComponent(200)), update_test_entity);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 200);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn resource_mutation_filter()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes::default())
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // add reactor
+    world.syscall((), on_resource_mutation_filtered);
+
+    // update resource (filtered out)
+    world.syscall(5, update_react_res);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // update resource (reaction)
+    world.syscall(6, update_react_res);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 6);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+#[test]
+fn filter_in_trigger_bundle()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes::default())
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    // add reactor
+    let token = world.syscall((), on_broadcast_filtered_or_resource);
+
+    // send event (filtered out)
+    world.syscall(1, send_broadcast);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 0);
+
+    // update resource (reaction, unfiltered)
+    world.syscall(2, update_react_res);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 2);
+
+    // send event (reaction)
+    world.syscall(11, send_broadcast);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 13);
+
+    // revoke reactor
+    world.syscall(token, revoke_reactor);
+
+    // send event (no reaction)
+    world.syscall(11, send_broadcast);
+    assert_eq!(world.resource::<TestReactRecorder>().0, 13);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,177 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Resource, Default)]
+struct Responses(Vec<usize>);
+
+#[derive(Resource)]
+struct SavedTypedCommand(SystemCommandWith<usize, Option<usize>>);
+
+#[derive(Resource)]
+struct SavedAddCommand(SystemCommandWith<usize, usize>);
+
+//-------------------------------------------------------------------------------------------------------------------
+
+fn count_responses(In(response): In<usize>, mut count: Local<usize>, mut responses: ResMut<Responses>)
+{
+    *count += 1;
+    responses.0.push(response * 100 + *count);
+}
+
+fn chain_responses(In(response): In<usize>, mut c: Commands, command: Res<SavedAddCommand>, mut responses: ResMut<Responses>)
+{
+    responses.0.push(response);
+    if response < 3 { c.run_system_command_then(command.0, response, chain_responses); }
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+// typed system commands take input and return output
+#[test]
+fn typed_system_command_output()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+
+    let command = world.spawn_system_command_with(
+        |In(val): In<usize>, mut recorder: ResMut<TestReactRecorder>| -> usize
+        {
+            recorder.0 += val;
+            recorder.0 * 2
+        }
+    );
+
+    // run
+    assert_eq!(world.run_system_command_with(command, 1), Some(2));
+    assert_eq!(world.run_system_command_with(command, 2), Some(6));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 3);
+
+    // missing
+    world.despawn(*command);
+    assert_eq!(world.run_system_command_with(command, 2), None);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// outputs are delivered to continuations
+#[test]
+fn typed_system_command_continuation()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .init_resource::<Responses>();
+    let world = app.world_mut();
+
+    world.syscall((),
+        |mut c: Commands|
+        {
+            let command = c.spawn_system_command_with(|In(val): In<usize>| val + 10);
+            c.run_system_command_then(command, 1,
+                |In(response): In<usize>, mut responses: ResMut<Responses>| responses.0.push(response)
+            );
+            c.run_system_command_then(command, 2,
+                |In(response): In<usize>, mut responses: ResMut<Responses>| responses.0.push(response)
+            );
+        }
+    );
+    assert_eq!(world.resource::<Responses>().0, vec![11, 12]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// typed system commands react like other system commands
+#[test]
+fn typed_system_command_reactions()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .insert_react_resource(TestReactRes::default())
+        .init_resource::<TestReactRecorder>();
+    let world = app.world_mut();
+    world.react(|rc| rc.on(resource_mutation::<TestReactRes>(), update_test_recorder_with_resource));
+
+    let command = world.spawn_system_command_with(
+        |In(val): In<usize>, mut c: Commands, mut res: ReactResMut<TestReactRes>| -> usize
+        {
+            res.get_mut(&mut c).0 = val;
+            val
+        }
+    );
+
+    // reactions run before the output is returned
+    assert_eq!(world.run_system_command_with(command, 5), Some(5));
+    assert_eq!(world.resource::<TestReactRecorder>().0, 5);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// recursive calls to a typed system command return None
+#[test]
+fn typed_system_command_recursion()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin);
+    let world = app.world_mut();
+
+    let command = world.spawn_system_command_with(
+        |In(val): In<usize>, world: &mut World| -> Option<usize>
+        {
+            let command = world.resource::<SavedTypedCommand>().0;
+            world.run_system_command_with(command, val).flatten()
+        }
+    );
+    world.insert_resource(SavedTypedCommand(command));
+
+    assert_eq!(world.run_system_command_with(command, 1), Some(None));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// non-capturing continuations are cached
+#[test]
+fn typed_system_command_cached_continuation()
+{
+    // setup
+    let mut app = App::new();
+    app.add_plugins(ReactPlugin)
+        .enable_reactor_stats(ReactorStatsMode::Cumulative)
+        .init_resource::<Responses>();
+    let world = app.world_mut();
+
+    let command = world.spawn_system_command_with(|In(val): In<usize>| val + 1);
+    world.insert_resource(SavedAddCommand(command));
+    world.syscall((), move |mut c: Commands| c.run_system_command_then(command, 0, count_responses));
+    let num_entities = world.entities().len();
+    let num_stats = world.resource::<ReactorStats>().iter().count();
+
+    // repeated runs reuse the continuation's system
+    world.syscall((), move |mut c: Commands| c.run_system_command_then(command, 1, count_responses));
+    world.syscall((), move |mut c: Commands| c.run_system_command_then(command, 2, count_responses));
+    assert_eq!(world.resource::<Responses>().0, vec![101, 202, 303]);
+    assert_eq!(world.entities().len(), num_entities);
+    assert_eq!(world.resource::<ReactorStats>().iter().count(), num_stats);
+
+    // a continuation that re-runs itself falls back to a one-off system
+    world.resource_mut::<Responses>().0.clear();
+    world.syscall((), move |mut c: Commands| c.run_system_command_then(command, 0, chain_responses));
+    assert_eq!(world.resource::<Responses>().0, vec![1, 2, 3]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
@@ -0,0 +1,2 @@
+//module tree
+mod react_test_app;
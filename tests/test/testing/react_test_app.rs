@@ -0,0 +1,150 @@
+//local shortcuts
+use bevy_cobweb::prelude::*;
+use bevy_cobweb::testing::*;
+use crate::*;
+
+//third-party shortcuts
+use bevy::prelude::*;
+
+//standard shortcuts
+
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Debug, PartialEq)]
+struct Ping(usize);
+
+#[derive(Resource)]
+struct Target(Entity);
+
+fn forward_ping(mut c: Commands, event: BroadcastEvent<Ping>, target: Res<Target>)
+{
+    c.react().entity_event(target.0, IntEvent(event.read().0 * 10));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+//-------------------------------------------------------------------------------------------------------------------
+
+// reactor runs are counted per event
+#[test]
+fn test_app_counts_reactor_runs()
+{
+    let mut app = ReactTestApp::new();
+    app.capture_broadcasts::<Ping>();
+    let target = app.world_mut().spawn_empty().id();
+    app.world_mut().insert_resource(Target(target));
+    let reactor = app.react(|rc| rc.on_persistent(broadcast::<Ping>(), forward_ping));
+
+    app.react(|rc| rc.broadcast(Ping(1)));
+    app.react(|rc| rc.broadcast(Ping(2)));
+    app.react(|rc| rc.broadcast(Ping(2)));
+
+    app.assert_runs(reactor, 3);
+    app.assert_runs_with(reactor, &Ping(1), 1);
+    app.assert_runs_with(reactor, &Ping(2), 2);
+    app.assert_runs_with(reactor, &Ping(3), 0);
+    assert_eq!(app.broadcasts::<Ping>(), &[Ping(1), Ping(2), Ping(2)]);
+
+    app.clear_runs();
+    app.clear_captured::<Ping>();
+    app.assert_runs(reactor, 0);
+    assert!(app.broadcasts::<Ping>().is_empty());
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// entity events are captured with the entities they visit
+#[test]
+fn test_app_captures_entity_events()
+{
+    let mut app = ReactTestApp::new();
+    app.capture_broadcasts::<Ping>().capture_entity_events::<IntEvent>();
+    let target = app.world_mut().spawn_empty().id();
+    app.world_mut().insert_resource(Target(target));
+    app.react(|rc| rc.on_persistent(broadcast::<Ping>(), forward_ping));
+
+    app.react(|rc| rc.broadcast(Ping(4)));
+
+    let events: Vec<_> = app.entity_events::<IntEvent>().iter().map(|(e, event)| (*e, event.0)).collect();
+    assert_eq!(events, vec![(target, 40)]);
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// queued inputs are applied one at a time
+#[test]
+fn test_app_inputs()
+{
+    let mut app = ReactTestApp::new();
+    app.spy_events::<Ping>();
+    let target = app.world_mut().spawn_empty().id();
+    app.world_mut().insert_resource(Target(target));
+    let reactor = app.react(|rc| rc.on_persistent(broadcast::<Ping>(), forward_ping));
+    let spy = app.world_mut().spawn_system_command(|| {});
+
+    app.queue_react_input(|rc| rc.broadcast(Ping(1)))
+        .queue_input(spy)
+        .queue_react_input(|rc| rc.broadcast(Ping(2)));
+    assert_eq!(app.num_queued_inputs(), 3);
+
+    assert!(app.apply_next_input());
+    app.assert_runs(reactor, 1);
+    app.assert_runs(spy, 0);
+
+    assert!(app.apply_next_input());
+    app.assert_runs(spy, 1);
+    app.assert_runs_with(reactor, &Ping(2), 0);
+
+    app.apply_queued_inputs();
+    assert!(!app.apply_next_input());
+    app.assert_runs_with(reactor, &Ping(2), 1);
+    let names: Vec<_> = app.spy().runs().filter_map(|run| run.name).collect();
+    assert!(names[0].ends_with("forward_ping"));
+}
+
+//-------------------------------------------------------------------------------------------------------------------
+
+// buffered reactions are stepped one at a time in their normal order
+#[test]
+fn test_app_reaction_stepping()
+{
+    let mut app = ReactTestApp::new();
+    let target = app.world_mut().spawn_empty().id();
+    app.world_mut().insert_resource(Target(target));
+    let forwarder = app.react(|rc| rc.on_persistent(broadcast::<Ping>(), forward_ping));
+    let receiver = app.react(|rc| rc.on_persistent(entity_event::<IntEvent>(target), || {}));
+    let listener = app.react(|rc| rc.on_persistent(broadcast::<Ping>(), || {}));
+
+    // reactions are buffered
+    app.enable_reaction_stepping();
+    app.queue_react_input(|rc| rc.broadcast(Ping(1)));
+    app.apply_next_input();
+    assert_eq!(app.pending_reactors(), vec![forwarder, listener]);
+    app.assert_runs(forwarder, 0);
+
+    // reactions triggered by a stepped reaction run before older reactions
+    assert!(app.step_reaction());
+    app.assert_runs(forwarder, 1);
+    assert_eq!(app.pending_reactors(), vec![receiver, listener]);
+    assert!(app.step_reaction());
+    app.assert_runs(receiver, 1);
+    assert_eq!(app.num_pending_reactions(), 1);
+    assert!(app.step_reaction());
+    app.assert_runs(listener, 1);
+    assert!(!app.step_reaction());
+
+    let order: Vec<_> = app.spy().runs().map(|run| run.command).collect();
+    assert_eq!(order, vec![forwarder, receiver, listener]);
+
+    // disabling stepping runs the remaining reactions
+    app.react(|rc| rc.broadcast(Ping(2)));
+    assert_eq!(app.num_pending_reactions(), 2);
+    app.disable_reaction_stepping();
+    assert!(!app.is_reaction_stepping());
+    app.assert_runs(receiver, 2);
+    app.react(|rc| rc.broadcast(Ping(3)));
+    app.assert_runs(receiver, 3);
+}
+
+//-------------------------------------------------------------------------------------------------------------------